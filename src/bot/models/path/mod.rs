@@ -1,5 +1,9 @@
+pub mod optimizer;
 pub mod registry;
+pub mod simulation;
+pub use optimizer::*;
 pub use registry::*;
+pub use simulation::*;
 
 // use crate::models::{pool::Pool, token::Token};
 // use alloy_primitives::{Address, U256};
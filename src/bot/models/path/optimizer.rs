@@ -0,0 +1,234 @@
+use crate::bot::models::pool::PoolRegistry;
+use alloy::primitives::U256;
+
+use super::registry::PoolDirection;
+use super::simulation::{simulate_route, RouteQuote};
+
+/// Maximum number of golden-section iterations of a search
+const MAX_ITERATIONS: usize = 64;
+
+/// Search stops once the bracket is narrower than `max_amount_in / AMOUNT_RESOLUTION`
+const AMOUNT_RESOLUTION: f64 = 1_000_000.0;
+
+/// 1 / golden ratio
+const INV_PHI: f64 = 0.618_033_988_749_895;
+
+/// Profit-maximizing input size of a cross-chain route
+#[derive(Debug, Clone)]
+pub struct OptimalAmount {
+    pub amount_in: U256,
+    /// Profit returned by the objective for `amount_in`
+    pub profit: f64,
+    pub quote: RouteQuote,
+    /// Number of route simulations run by the search
+    pub evaluations: usize,
+}
+
+/// Find the input size that maximizes the profit of a cross-chain route
+///
+/// Runs a golden-section search on `(0, max_amount_in]`, which assumes the profit is unimodal
+/// in the input size (true for constant product and concentrated liquidity pools). Sizes the
+/// pools cannot quote are treated as unprofitable.
+///
+/// # Arguments
+/// * `source_registry` - Pool registry of the source chain
+/// * `source_path` - Pool path on the source chain, starting at the anchor token
+/// * `target_registry` - Pool registry of the target chain
+/// * `target_path` - Pool path on the target chain, starting at the anchor token
+/// * `bridge_decimals` - Decimals of the bridged token on the source and target chain
+/// * `max_amount_in` - Upper bound of the search, raw amount of the source anchor token
+/// * `profit` - Objective to maximize, e.g. the USD delta of a quote
+///
+/// # Returns
+/// * `Some(OptimalAmount)` - Best size found, its profit may be negative
+/// * `None` - No size in the range could be quoted
+pub async fn find_optimal_amount<F>(
+    source_registry: &PoolRegistry,
+    source_path: &[PoolDirection],
+    target_registry: &PoolRegistry,
+    target_path: &[PoolDirection],
+    bridge_decimals: (u8, u8),
+    max_amount_in: U256,
+    profit: F,
+) -> Option<OptimalAmount>
+where
+    F: Fn(&RouteQuote) -> f64,
+{
+    let mut best: Option<(f64, RouteQuote)> = None;
+    let mut evaluations = 0;
+    let mut evaluate = async |amount: f64| -> f64 {
+        let amount_in = to_amount(amount);
+        if amount_in.is_zero() {
+            return f64::NEG_INFINITY;
+        }
+
+        evaluations += 1;
+        let quote = match simulate_route(
+            source_registry,
+            source_path,
+            target_registry,
+            target_path,
+            bridge_decimals,
+            amount_in,
+        )
+        .await
+        {
+            Ok(quote) => quote,
+            Err(_) => return f64::NEG_INFINITY,
+        };
+
+        let value = profit(&quote);
+        if best
+            .as_ref()
+            .is_none_or(|(best_value, _)| value > *best_value)
+        {
+            best = Some((value, quote));
+        }
+        value
+    };
+
+    let max = f64::from(max_amount_in);
+    let tolerance = (max / AMOUNT_RESOLUTION).max(1.0);
+    let (mut low, mut high) = (0.0, max);
+    let mut left = high - INV_PHI * (high - low);
+    let mut right = low + INV_PHI * (high - low);
+    let mut left_value = evaluate(left).await;
+    let mut right_value = evaluate(right).await;
+
+    for _ in 0..MAX_ITERATIONS {
+        if high - low <= tolerance {
+            break;
+        }
+
+        if left_value < right_value {
+            low = left;
+            left = right;
+            left_value = right_value;
+            right = low + INV_PHI * (high - low);
+            right_value = evaluate(right).await;
+        } else {
+            high = right;
+            right = left;
+            right_value = left_value;
+            left = high - INV_PHI * (high - low);
+            left_value = evaluate(left).await;
+        }
+    }
+
+    // The optimum may sit on the upper bound
+    evaluate(max).await;
+
+    best.filter(|(value, _)| value.is_finite())
+        .map(|(profit, quote)| OptimalAmount {
+            amount_in: quote.amount_in,
+            profit,
+            quote,
+            evaluations,
+        })
+}
+
+fn to_amount(amount: f64) -> U256 {
+    U256::from(amount.max(0.0).round() as u128)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::models::pool::mock::MockPool;
+    use crate::bot::providers::create_provider;
+    use alloy::primitives::Address;
+
+    async fn registry(network_id: u64, pool: MockPool) -> PoolRegistry {
+        let registry = PoolRegistry::new(
+            create_provider(vec!["http://localhost:8545".to_string()]),
+            network_id,
+        );
+        registry.add_pool(Box::new(pool)).await;
+        registry
+    }
+
+    #[tokio::test]
+    async fn test_find_optimal_amount() {
+        let anchor = Address::repeat_byte(1);
+        let bridged = Address::repeat_byte(2);
+        let reserve = U256::from(10).pow(U256::from(21));
+
+        // Bridged token trades 2:1 on the source chain and 1:1 on the target chain
+        let source = registry(
+            1,
+            MockPool::new_v2(
+                Address::repeat_byte(10),
+                anchor,
+                bridged,
+                reserve,
+                reserve * U256::from(2),
+            ),
+        )
+        .await;
+        let target = registry(
+            2,
+            MockPool::new_v2(Address::repeat_byte(20), anchor, bridged, reserve, reserve),
+        )
+        .await;
+        let path = |pool| {
+            vec![PoolDirection {
+                pool,
+                token_in: anchor,
+                token_out: bridged,
+            }]
+        };
+        let source_path = path(Address::repeat_byte(10));
+        let target_path = path(Address::repeat_byte(20));
+        let anchor_profit =
+            |quote: &RouteQuote| f64::from(quote.amount_out) - f64::from(quote.amount_in);
+
+        let max_amount_in = reserve;
+        let optimal = find_optimal_amount(
+            &source,
+            &source_path,
+            &target,
+            &target_path,
+            (18, 18),
+            max_amount_in,
+            anchor_profit,
+        )
+        .await
+        .unwrap();
+
+        assert!(optimal.profit > 0.0);
+        assert!(optimal.amount_in > U256::ZERO);
+        assert!(optimal.amount_in < max_amount_in);
+
+        // Sizes around the optimum are less profitable
+        for amount_in in [
+            optimal.amount_in * U256::from(9) / U256::from(10),
+            optimal.amount_in * U256::from(11) / U256::from(10),
+        ] {
+            let quote = simulate_route(
+                &source,
+                &source_path,
+                &target,
+                &target_path,
+                (18, 18),
+                amount_in,
+            )
+            .await
+            .unwrap();
+            assert!(anchor_profit(&quote) < optimal.profit);
+        }
+
+        // A tight bound caps the size
+        let capped = find_optimal_amount(
+            &source,
+            &source_path,
+            &target,
+            &target_path,
+            (18, 18),
+            U256::from(10).pow(U256::from(18)),
+            anchor_profit,
+        )
+        .await
+        .unwrap();
+        assert_eq!(capped.amount_in, U256::from(10).pow(U256::from(18)));
+    }
+}
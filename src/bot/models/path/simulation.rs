@@ -0,0 +1,284 @@
+use crate::bot::models::pool::base::PoolInterface;
+use crate::bot::models::pool::PoolRegistry;
+use alloy::primitives::{Address, U256};
+use anyhow::{anyhow, Result};
+
+use super::registry::{PoolDirection, PoolPath};
+
+/// Divisor used to size the probe swap that approximates the spot price of a hop
+const SPOT_PROBE_DIVISOR: u64 = 10_000;
+
+/// Quote of a single swap along a path
+#[derive(Debug, Clone)]
+pub struct HopQuote {
+    pub pool: Address,
+    pub token_in: Address,
+    pub token_out: Address,
+    pub amount_in: U256,
+    pub amount_out: U256,
+    /// Price impact as a fraction (0.01 = 1%), fee excluded
+    pub price_impact: f64,
+}
+
+/// Quote of a cross-chain route: forward on the source chain, back on the target chain
+#[derive(Debug, Clone)]
+pub struct RouteQuote {
+    pub source_hops: Vec<HopQuote>,
+    pub target_hops: Vec<HopQuote>,
+    /// Anchor token amount sent into the source chain path
+    pub amount_in: U256,
+    /// Source path output after rescaling to the decimals of the target chain token
+    pub bridged_amount: U256,
+    /// Anchor token amount received at the end of the target chain path
+    pub amount_out: U256,
+}
+
+/// Reverse a pool path so it is walked from its last token back to its first
+pub fn reverse_path(path: &[PoolDirection]) -> PoolPath {
+    path.iter()
+        .rev()
+        .map(|direction| PoolDirection {
+            pool: direction.pool,
+            token_in: direction.token_out,
+            token_out: direction.token_in,
+        })
+        .collect()
+}
+
+/// Rescale a raw amount between two tokens with different decimals (1:1 in human units)
+pub fn rescale_amount(amount: U256, from_decimals: u8, to_decimals: u8) -> U256 {
+    if from_decimals > to_decimals {
+        amount / U256::from(10).pow(U256::from(from_decimals - to_decimals))
+    } else {
+        amount.saturating_mul(U256::from(10).pow(U256::from(to_decimals - from_decimals)))
+    }
+}
+
+/// Price impact of a swap, measured against a small probe swap on the same pool
+///
+/// Both swaps pay the pool fee, so the result only reflects the slippage caused by the size.
+pub fn price_impact(
+    pool: &dyn PoolInterface,
+    token_in: &Address,
+    amount_in: U256,
+    amount_out: U256,
+) -> f64 {
+    if amount_in.is_zero() {
+        return 0.0;
+    }
+
+    let probe_in = (amount_in / U256::from(SPOT_PROBE_DIVISOR)).max(U256::from(1));
+    let probe_out = match pool.calculate_output(token_in, probe_in) {
+        Ok(probe_out) if !probe_out.is_zero() => probe_out,
+        _ => return 0.0,
+    };
+
+    let spot_price = f64::from(probe_out) / f64::from(probe_in);
+    let execution_price = f64::from(amount_out) / f64::from(amount_in);
+    (1.0 - execution_price / spot_price).max(0.0)
+}
+
+/// Chain `calculate_output` hop by hop along a path
+///
+/// # Arguments
+/// * `registry` - Pool registry holding every pool of the path
+/// * `path` - Pool path to walk, starting with the token of `amount_in`
+/// * `amount_in` - Raw amount of the first token_in
+///
+/// # Returns
+/// * `Ok(Vec<HopQuote>)` - One quote per hop, the last amount_out is the path output
+/// * `Err(anyhow::Error)` - Error if a pool is missing or cannot quote the swap
+pub async fn simulate_path(
+    registry: &PoolRegistry,
+    path: &[PoolDirection],
+    amount_in: U256,
+) -> Result<Vec<HopQuote>> {
+    let mut hops = Vec::with_capacity(path.len());
+    let mut amount = amount_in;
+
+    for direction in path {
+        let pool = registry
+            .get_pool(&direction.pool)
+            .await
+            .ok_or_else(|| anyhow!("Pool {} not found in registry", direction.pool))?;
+        let pool = pool.read().await;
+
+        let amount_out = pool
+            .calculate_output(&direction.token_in, amount)
+            .map_err(|e| anyhow!("Failed to quote pool {}: {}", direction.pool, e))?;
+        let impact = price_impact(&**pool, &direction.token_in, amount, amount_out);
+
+        hops.push(HopQuote {
+            pool: direction.pool,
+            token_in: direction.token_in,
+            token_out: direction.token_out,
+            amount_in: amount,
+            amount_out,
+            price_impact: impact,
+        });
+        amount = amount_out;
+    }
+
+    Ok(hops)
+}
+
+/// Simulate a cross-chain route for a given anchor token amount
+///
+/// Both paths start at their chain's anchor token. The source path is walked forward, its
+/// output is bridged 1:1 in human units, and the target path is walked in reverse back to
+/// the anchor token of the target chain.
+///
+/// # Arguments
+/// * `source_registry` - Pool registry of the source chain
+/// * `source_path` - Pool path on the source chain, starting at the anchor token
+/// * `target_registry` - Pool registry of the target chain
+/// * `target_path` - Pool path on the target chain, starting at the anchor token
+/// * `bridge_decimals` - Decimals of the bridged token on the source and target chain
+/// * `amount_in` - Raw amount of the source anchor token
+pub async fn simulate_route(
+    source_registry: &PoolRegistry,
+    source_path: &[PoolDirection],
+    target_registry: &PoolRegistry,
+    target_path: &[PoolDirection],
+    bridge_decimals: (u8, u8),
+    amount_in: U256,
+) -> Result<RouteQuote> {
+    let source_hops = simulate_path(source_registry, source_path, amount_in).await?;
+    let source_out = source_hops
+        .last()
+        .map(|hop| hop.amount_out)
+        .unwrap_or(amount_in);
+
+    let bridged_amount = rescale_amount(source_out, bridge_decimals.0, bridge_decimals.1);
+    let target_hops =
+        simulate_path(target_registry, &reverse_path(target_path), bridged_amount).await?;
+    let amount_out = target_hops
+        .last()
+        .map(|hop| hop.amount_out)
+        .unwrap_or(bridged_amount);
+
+    Ok(RouteQuote {
+        source_hops,
+        target_hops,
+        amount_in,
+        bridged_amount,
+        amount_out,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::models::pool::mock::MockPool;
+    use crate::bot::providers::create_provider;
+
+    fn registry(network_id: u64) -> PoolRegistry {
+        PoolRegistry::new(
+            create_provider(vec!["http://localhost:8545".to_string()]),
+            network_id,
+        )
+    }
+
+    #[test]
+    fn test_reverse_path() {
+        let path = vec![
+            PoolDirection {
+                pool: Address::repeat_byte(10),
+                token_in: Address::repeat_byte(1),
+                token_out: Address::repeat_byte(2),
+            },
+            PoolDirection {
+                pool: Address::repeat_byte(11),
+                token_in: Address::repeat_byte(2),
+                token_out: Address::repeat_byte(3),
+            },
+        ];
+
+        let reversed = reverse_path(&path);
+        assert_eq!(reversed.len(), 2);
+        assert_eq!(reversed[0].pool, Address::repeat_byte(11));
+        assert_eq!(reversed[0].token_in, Address::repeat_byte(3));
+        assert_eq!(reversed[1].token_out, Address::repeat_byte(1));
+    }
+
+    #[test]
+    fn test_rescale_amount() {
+        let one_e18 = U256::from(10).pow(U256::from(18));
+        assert_eq!(rescale_amount(one_e18, 18, 6), U256::from(1_000_000));
+        assert_eq!(rescale_amount(U256::from(1_000_000), 6, 18), one_e18);
+        assert_eq!(rescale_amount(U256::from(42), 8, 8), U256::from(42));
+    }
+
+    #[tokio::test]
+    async fn test_simulate_route() {
+        let anchor = Address::repeat_byte(1);
+        let bridged = Address::repeat_byte(2);
+        let reserve = U256::from(10).pow(U256::from(21));
+
+        // Bridged token is cheaper on the source chain than on the target chain
+        let source = registry(1);
+        source
+            .add_pool(Box::new(MockPool::new_v2(
+                Address::repeat_byte(10),
+                anchor,
+                bridged,
+                reserve,
+                reserve * U256::from(2),
+            )))
+            .await;
+        let target = registry(2);
+        target
+            .add_pool(Box::new(MockPool::new_v2(
+                Address::repeat_byte(20),
+                anchor,
+                bridged,
+                reserve,
+                reserve,
+            )))
+            .await;
+
+        let source_path = vec![PoolDirection {
+            pool: Address::repeat_byte(10),
+            token_in: anchor,
+            token_out: bridged,
+        }];
+        let target_path = vec![PoolDirection {
+            pool: Address::repeat_byte(20),
+            token_in: anchor,
+            token_out: bridged,
+        }];
+
+        let amount_in = U256::from(10).pow(U256::from(18));
+        let quote = simulate_route(
+            &source,
+            &source_path,
+            &target,
+            &target_path,
+            (18, 18),
+            amount_in,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(quote.source_hops.len(), 1);
+        assert_eq!(quote.target_hops.len(), 1);
+        assert_eq!(quote.target_hops[0].token_in, bridged);
+        assert_eq!(quote.target_hops[0].token_out, anchor);
+        assert_eq!(quote.bridged_amount, quote.source_hops[0].amount_out);
+        assert!(quote.amount_out > amount_in);
+        assert!(quote.source_hops[0].price_impact > 0.0);
+        assert!(quote.source_hops[0].price_impact < 0.01);
+
+        // A pool missing from the registry fails the simulation
+        assert!(simulate_route(
+            &source,
+            &target_path,
+            &source,
+            &target_path,
+            (18, 18),
+            amount_in
+        )
+        .await
+        .is_err());
+    }
+}
@@ -83,6 +83,24 @@ impl MultichainProfitTokenRegistry {
         Ok(())
     }
 
+    /// Register an already configured profit token registry, its price updater is not started
+    pub async fn add_profit_token_registry(
+        &self,
+        chain_id: u64,
+        profit_token_registry: ProfitTokenRegistry,
+    ) {
+        self.profit_token_registries
+            .write()
+            .await
+            .insert(chain_id, profit_token_registry);
+    }
+
+    pub async fn get_price(&self, chain_id: u64, token: Address) -> Option<f64> {
+        let registries = self.profit_token_registries.read().await;
+        let registry = registries.get(&chain_id)?;
+        registry.get_price(&token).await
+    }
+
     pub async fn add_token(&self, chain_id: u64, token: Address) {
         let config = ProfitToken {
             address: token,
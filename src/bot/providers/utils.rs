@@ -1,11 +1,12 @@
 use alloy::eips::BlockNumberOrTag;
 use alloy::primitives::{Address, FixedBytes};
-use alloy::providers::{DynProvider, Provider, ProviderBuilder};
+use alloy::providers::{DynProvider, MulticallError, Provider, ProviderBuilder};
 use alloy::rpc::client::RpcClient;
 use alloy::rpc::types::{Filter, Log};
 use alloy::transports::http::Http;
 use alloy::transports::layers::FallbackLayer;
-use anyhow::Result;
+use alloy::transports::TransportError;
+use anyhow::{anyhow, Result};
 use std::num::NonZeroUsize;
 use std::sync::Arc;
 use tower::ServiceBuilder;
@@ -27,16 +28,46 @@ pub async fn fetch_events(
     Ok(events)
 }
 
+/// Whether an error comes from failing to reach the RPC rather than from an answer of the node,
+/// such as a revert, that says something about the call itself
+pub fn is_rpc_failure(e: &anyhow::Error) -> bool {
+    // Contract and multicall errors wrap transport errors transparently, they are not sources
+    e.chain()
+        .filter_map(|cause| {
+            cause
+                .downcast_ref::<TransportError>()
+                .or(match cause.downcast_ref::<alloy::contract::Error>() {
+                    Some(alloy::contract::Error::TransportError(e)) => Some(e),
+                    _ => None,
+                })
+                .or(match cause.downcast_ref::<MulticallError>() {
+                    Some(MulticallError::TransportError(e)) => Some(e),
+                    _ => None,
+                })
+        })
+        .any(|e| !e.is_error_resp())
+}
+
 pub fn create_provider(rpcs: Vec<String>) -> DynProvider {
-    let rpc_len = rpcs.len();
-    let fallback_layer =
-        FallbackLayer::default().with_active_transport_count(NonZeroUsize::new(rpc_len).unwrap());
+    try_create_provider(&rpcs).unwrap()
+}
+
+/// Create a provider failing over between the given RPC URLs
+/// Returns an error instead of panicking when no URL is given or one is not a valid URL
+pub fn try_create_provider(rpcs: &[String]) -> Result<DynProvider> {
+    let rpc_len = NonZeroUsize::new(rpcs.len()).ok_or_else(|| anyhow!("No RPC URL configured"))?;
+    let fallback_layer = FallbackLayer::default().with_active_transport_count(rpc_len);
 
     // Define your list of transports to use
     let transports = rpcs
         .iter()
-        .map(|url| Http::new(url.parse().unwrap()))
-        .collect::<Vec<_>>();
+        .map(|url| {
+            let url = url
+                .parse()
+                .map_err(|e| anyhow!("Invalid RPC URL '{}': {}", url, e))?;
+            Ok(Http::new(url))
+        })
+        .collect::<Result<Vec<_>>>()?;
 
     // Apply the FallbackLayer to the transports
     let transport = ServiceBuilder::new()
@@ -44,5 +75,27 @@ pub fn create_provider(rpcs: Vec<String>) -> DynProvider {
         .service(transports);
     let client = RpcClient::builder().transport(transport, false);
     let provider = ProviderBuilder::new().connect_client(client.clone());
-    provider.clone().erased()
+    Ok(provider.clone().erased())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::transports::TransportErrorKind;
+
+    #[test]
+    fn test_is_rpc_failure() {
+        let unreachable = anyhow::Error::from(alloy::contract::Error::TransportError(
+            TransportErrorKind::custom_str("connection refused"),
+        ));
+        assert!(is_rpc_failure(&unreachable.context("fetching the pool")));
+
+        let reverted: TransportError = TransportError::ErrorResp(
+            serde_json::from_str(r#"{ "code": 3, "message": "execution reverted" }"#).unwrap(),
+        );
+        assert!(!is_rpc_failure(&anyhow::Error::from(reverted)));
+        let multicall = MulticallError::TransportError(TransportErrorKind::backend_gone());
+        assert!(is_rpc_failure(&anyhow::Error::from(multicall)));
+        assert!(!is_rpc_failure(&anyhow!("Failed to get reserves")));
+    }
 }
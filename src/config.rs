@@ -1,6 +1,8 @@
 use anyhow::{anyhow, Result};
 use log::{error, info};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
 use std::fs;
 use std::path::Path;
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -9,6 +11,14 @@ pub struct Config {
     pub database: MongoDbConfig,
     pub cors: CorsConfig,
     pub telegram: TelegramConfig,
+    #[serde(default)]
+    pub notifications: NotificationsConfig,
+    #[serde(default)]
+    pub auth: AuthConfig,
+    #[serde(default)]
+    pub prices: PricesConfig,
+    #[serde(default)]
+    pub rpc_health: RpcHealthConfig,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -44,6 +54,179 @@ pub struct TelegramConfig {
     pub error_thread_id: Option<u64>,
 }
 
+/// Access level required by a route, `public` needs no API key
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Access {
+    Public,
+    Read,
+    Write,
+    HardDelete,
+    Admin,
+}
+
+/// Access policy of the `/api/v1` routes
+///
+/// `rules` are tried in order and the first match wins. Otherwise `/hard` routes need
+/// `hard_delete`, GET/HEAD/OPTIONS need `read` and every other method needs `write`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AuthConfig {
+    #[serde(default = "AuthConfig::default_read")]
+    pub read: Access,
+    #[serde(default = "AuthConfig::default_write")]
+    pub write: Access,
+    #[serde(default = "AuthConfig::default_hard_delete")]
+    pub hard_delete: Access,
+    #[serde(default)]
+    pub rules: Vec<AuthRule>,
+}
+
+impl AuthConfig {
+    fn default_read() -> Access {
+        Access::Public
+    }
+
+    fn default_write() -> Access {
+        Access::Write
+    }
+
+    fn default_hard_delete() -> Access {
+        Access::HardDelete
+    }
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            read: Self::default_read(),
+            write: Self::default_write(),
+            hard_delete: Self::default_hard_delete(),
+            rules: Vec::new(),
+        }
+    }
+}
+
+/// Access override for the routes matching `path`
+/// `path` is relative to `/api/v1`, `*` matches one segment and a trailing `**` any rest
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AuthRule {
+    pub path: String,
+    #[serde(default)]
+    pub methods: Vec<String>, // all methods if empty
+    pub access: Access,
+}
+
+/// Settings shared by all notification sinks
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct NotificationsConfig {
+    #[serde(default)]
+    pub min_profit_usd: Option<f64>, // opportunities below this are not notified
+    #[serde(default)]
+    pub dashboard_url: Option<String>, // base URL of the dashboard, for links in messages
+    #[serde(default)]
+    pub webhooks: Vec<WebhookConfig>,
+}
+
+/// Format of the payload posted to a webhook
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookKind {
+    Json, // the notification itself, as JSON
+    Slack,
+    Discord,
+}
+
+/// Webhook sink, its `Debug` leaves out the URL path and the header values, which carry secrets
+#[derive(Serialize, Deserialize, Clone)]
+pub struct WebhookConfig {
+    pub kind: WebhookKind,
+    pub url: String,
+    #[serde(default)]
+    pub headers: HashMap<String, String>, // extra headers, e.g. Authorization
+}
+
+impl WebhookConfig {
+    /// Scheme and host of the URL, Slack and Discord put the secret of a webhook in its path
+    pub fn redacted_url(&self) -> String {
+        match url::Url::parse(&self.url) {
+            Ok(url) => format!(
+                "{}://{}/…",
+                url.scheme(),
+                url.host_str().unwrap_or_default()
+            ),
+            Err(_) => "<invalid URL>".to_string(),
+        }
+    }
+}
+
+impl fmt::Debug for WebhookConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut headers: Vec<&String> = self.headers.keys().collect();
+        headers.sort();
+        f.debug_struct("WebhookConfig")
+            .field("kind", &self.kind)
+            .field("url", &self.redacted_url())
+            .field("headers", &headers)
+            .finish()
+    }
+}
+
+/// Background refresh of the profit token prices
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PricesConfig {
+    #[serde(default = "PricesConfig::default_refresh_interval_secs")]
+    pub refresh_interval_secs: u64,
+}
+
+impl PricesConfig {
+    fn default_refresh_interval_secs() -> u64 {
+        300
+    }
+}
+
+impl Default for PricesConfig {
+    fn default() -> Self {
+        Self {
+            refresh_interval_secs: Self::default_refresh_interval_secs(),
+        }
+    }
+}
+
+/// Background health checks of the RPC endpoints of the networks
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RpcHealthConfig {
+    #[serde(default = "RpcHealthConfig::default_check_interval_secs")]
+    pub check_interval_secs: u64,
+    #[serde(default = "RpcHealthConfig::default_timeout_secs")]
+    pub timeout_secs: u64, // an endpoint slower than this fails the check
+    #[serde(default = "RpcHealthConfig::default_window")]
+    pub window: usize, // number of recent checks the error rate is computed over
+}
+
+impl RpcHealthConfig {
+    fn default_check_interval_secs() -> u64 {
+        60
+    }
+
+    fn default_timeout_secs() -> u64 {
+        5
+    }
+
+    fn default_window() -> usize {
+        20
+    }
+}
+
+impl Default for RpcHealthConfig {
+    fn default() -> Self {
+        Self {
+            check_interval_secs: Self::default_check_interval_secs(),
+            timeout_secs: Self::default_timeout_secs(),
+            window: Self::default_window(),
+        }
+    }
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
@@ -81,6 +264,10 @@ impl Default for Config {
                 opp_thread_id: None,
                 error_thread_id: None,
             },
+            notifications: NotificationsConfig::default(),
+            auth: AuthConfig::default(),
+            prices: PricesConfig::default(),
+            rpc_health: RpcHealthConfig::default(),
         }
     }
 }
@@ -209,4 +396,45 @@ mod tests {
         assert!(toml_string.contains("8081"));
         assert!(toml_string.contains("mongodb://localhost:27017"));
     }
+
+    #[test]
+    fn test_notifications_config() {
+        let mut toml_string = toml::to_string_pretty(&Config::default())
+            .unwrap()
+            .replace("webhooks = []\n", "");
+        toml_string.push_str(
+            r#"
+[[notifications.webhooks]]
+kind = "discord"
+url = "https://discord.com/api/webhooks/1"
+
+[[notifications.webhooks]]
+kind = "json"
+url = "https://alerts.example.com"
+headers = { Authorization = "Bearer token" }
+"#,
+        );
+
+        let config: Config = toml::from_str(&toml_string).unwrap();
+        let webhooks = &config.notifications.webhooks;
+        assert_eq!(webhooks.len(), 2);
+        assert_eq!(webhooks[0].kind, WebhookKind::Discord);
+        assert!(webhooks[0].headers.is_empty());
+        assert_eq!(webhooks[1].kind, WebhookKind::Json);
+        assert_eq!(webhooks[1].headers["Authorization"], "Bearer token");
+    }
+
+    #[test]
+    fn test_webhook_config_debug_hides_secrets() {
+        let webhook = WebhookConfig {
+            kind: WebhookKind::Slack,
+            url: "https://hooks.slack.com/services/T000/B000/secret".to_string(),
+            headers: HashMap::from([("Authorization".to_string(), "Bearer token".to_string())]),
+        };
+
+        let debug = format!("{:?}", webhook);
+        assert!(debug.contains("https://hooks.slack.com/…"));
+        assert!(debug.contains("Authorization"));
+        assert!(!debug.contains("secret") && !debug.contains("Bearer"));
+    }
 }
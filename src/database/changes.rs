@@ -0,0 +1,264 @@
+use anyhow::Result;
+use mongodb::bson::{doc, oid::ObjectId};
+use mongodb::{ClientSession, Database};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::database::models::{AuditAction, AuditEntity, Config, Network, Path, Pool, Token};
+use crate::database::mongodb::inserted_before_error;
+
+/// Records inserted or soft-deleted per write when applying changes
+const WRITE_BATCH_SIZE: usize = 1_000;
+
+/// Configuration record that planned changes are applied to, see `apply_changes`
+pub trait Record: Serialize + DeserializeOwned + Clone + Send + Sync + Unpin {
+    const COLLECTION: &'static str;
+    const ENTITY: AuditEntity;
+
+    fn id(&self) -> Option<ObjectId>;
+    /// Id of the record in the audit log
+    fn audit_id(&self) -> String;
+}
+
+impl Record for Network {
+    const COLLECTION: &'static str = "networks";
+    const ENTITY: AuditEntity = AuditEntity::Network;
+
+    fn id(&self) -> Option<ObjectId> {
+        self.id
+    }
+
+    fn audit_id(&self) -> String {
+        self.chain_id.to_string()
+    }
+}
+
+impl Record for Token {
+    const COLLECTION: &'static str = "tokens";
+    const ENTITY: AuditEntity = AuditEntity::Token;
+
+    fn id(&self) -> Option<ObjectId> {
+        self.id
+    }
+
+    fn audit_id(&self) -> String {
+        format!("{}:{}", self.network_id, self.address)
+    }
+}
+
+impl Record for Pool {
+    const COLLECTION: &'static str = "pools";
+    const ENTITY: AuditEntity = AuditEntity::Pool;
+
+    fn id(&self) -> Option<ObjectId> {
+        self.id
+    }
+
+    fn audit_id(&self) -> String {
+        self.id.map(|oid| oid.to_hex()).unwrap_or_default()
+    }
+}
+
+impl Record for Path {
+    const COLLECTION: &'static str = "paths";
+    const ENTITY: AuditEntity = AuditEntity::Path;
+
+    fn id(&self) -> Option<ObjectId> {
+        self.id
+    }
+
+    fn audit_id(&self) -> String {
+        self.id.map(|oid| oid.to_hex()).unwrap_or_default()
+    }
+}
+
+impl Record for Config {
+    const COLLECTION: &'static str = "configs";
+    const ENTITY: AuditEntity = AuditEntity::Config;
+
+    fn id(&self) -> Option<ObjectId> {
+        self.id
+    }
+
+    fn audit_id(&self) -> String {
+        self.id.map(|oid| oid.to_hex()).unwrap_or_default()
+    }
+}
+
+/// Soft-deleted record, whose id and timestamps are set when planning a change to it
+pub trait Stamped: Record {
+    fn created_at(&self) -> u64;
+    fn deleted_at(&self) -> Option<u64>;
+    /// Set the id and timestamps of the record
+    fn stamp(
+        &mut self,
+        id: Option<ObjectId>,
+        created_at: u64,
+        updated_at: u64,
+        deleted_at: Option<u64>,
+    );
+}
+
+impl Stamped for Network {
+    fn created_at(&self) -> u64 {
+        self.created_at
+    }
+
+    fn deleted_at(&self) -> Option<u64> {
+        self.deleted_at
+    }
+
+    fn stamp(
+        &mut self,
+        id: Option<ObjectId>,
+        created_at: u64,
+        updated_at: u64,
+        deleted_at: Option<u64>,
+    ) {
+        self.id = id;
+        self.created_at = created_at;
+        self.updated_at = updated_at;
+        self.deleted_at = deleted_at;
+    }
+}
+
+impl Stamped for Token {
+    fn created_at(&self) -> u64 {
+        self.created_at
+    }
+
+    fn deleted_at(&self) -> Option<u64> {
+        self.deleted_at
+    }
+
+    fn stamp(
+        &mut self,
+        id: Option<ObjectId>,
+        created_at: u64,
+        updated_at: u64,
+        deleted_at: Option<u64>,
+    ) {
+        self.id = id;
+        self.created_at = created_at;
+        self.updated_at = updated_at;
+        self.deleted_at = deleted_at;
+    }
+}
+
+impl Stamped for Pool {
+    fn created_at(&self) -> u64 {
+        self.created_at
+    }
+
+    fn deleted_at(&self) -> Option<u64> {
+        self.deleted_at
+    }
+
+    fn stamp(
+        &mut self,
+        id: Option<ObjectId>,
+        created_at: u64,
+        updated_at: u64,
+        deleted_at: Option<u64>,
+    ) {
+        self.id = id;
+        self.created_at = created_at;
+        self.updated_at = updated_at;
+        self.deleted_at = deleted_at;
+    }
+}
+
+impl Stamped for Path {
+    fn created_at(&self) -> u64 {
+        self.created_at
+    }
+
+    fn deleted_at(&self) -> Option<u64> {
+        self.deleted_at
+    }
+
+    fn stamp(
+        &mut self,
+        id: Option<ObjectId>,
+        created_at: u64,
+        updated_at: u64,
+        deleted_at: Option<u64>,
+    ) {
+        self.id = id;
+        self.created_at = created_at;
+        self.updated_at = updated_at;
+        self.deleted_at = deleted_at;
+    }
+}
+
+/// Planned change to one record, `after` is the whole record once changed, with its id
+pub struct RecordChange<T> {
+    pub action: AuditAction,
+    pub before: Option<T>,
+    pub after: T,
+}
+
+/// Make planned changes to one kind of record, in order, counting in `applied` the changes made
+///
+/// Creates are inserted and deletes are set (`deleted_at` and `updated_at` to `now`) in
+/// batches, other changes replace the record one by one. A failure stops at the change that
+/// failed, the changes before it are made unless the session is in a transaction that is then
+/// aborted.
+pub async fn apply_changes<T: Record>(
+    db: &Database,
+    session: &mut ClientSession,
+    changes: &[RecordChange<T>],
+    now: u64,
+    applied: &mut usize,
+) -> Result<()> {
+    let collection = db.collection::<T>(T::COLLECTION);
+    let mut rest = changes;
+
+    while let Some(first) = rest.first() {
+        let run = rest
+            .iter()
+            .take_while(|change| change.action == first.action)
+            .count();
+        let (changes, next) = rest.split_at(run);
+        rest = next;
+
+        match first.action {
+            AuditAction::Create => {
+                for batch in changes.chunks(WRITE_BATCH_SIZE) {
+                    collection
+                        .insert_many(batch.iter().map(|change| &change.after))
+                        .session(&mut *session)
+                        .await
+                        .inspect_err(|e| *applied += inserted_before_error(e))?;
+                    *applied += batch.len();
+                }
+            }
+            AuditAction::Delete => {
+                for batch in changes.chunks(WRITE_BATCH_SIZE) {
+                    let ids: Vec<ObjectId> = batch
+                        .iter()
+                        .filter_map(|change| change.after.id())
+                        .collect();
+                    collection
+                        .update_many(
+                            doc! { "_id": { "$in": ids } },
+                            doc! { "$set": { "deleted_at": now as i64, "updated_at": now as i64 } },
+                        )
+                        .session(&mut *session)
+                        .await?;
+                    *applied += batch.len();
+                }
+            }
+            _ => {
+                for change in changes {
+                    collection
+                        .replace_one(doc! { "_id": change.after.id() }, &change.after)
+                        .session(&mut *session)
+                        .await?;
+                    *applied += 1;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,290 @@
+use crate::database::models::utils::address_to_string;
+use crate::database::mongodb::is_duplicate_key_error;
+use alloy::primitives::Address;
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use futures::{future::BoxFuture, TryStreamExt};
+use log::{info, warn};
+use mongodb::{
+    bson::{doc, oid::ObjectId, Document},
+    options::IndexOptions,
+    Database as MongoDatabase, IndexModel,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+/// Collection recording the applied migrations
+const MIGRATIONS_COLLECTION: &str = "schema_migrations";
+
+/// A versioned data migration, applied once and recorded in `schema_migrations`
+///
+/// Migrations must be idempotent: a migration interrupted before being recorded is run
+/// again on the next start.
+pub struct Migration {
+    pub version: u32,
+    pub name: &'static str,
+    run: for<'a> fn(&'a MongoDatabase) -> BoxFuture<'a, Result<()>>,
+}
+
+/// Record of an applied migration
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AppliedMigration {
+    pub version: u32,
+    pub name: String,
+    pub applied_at: u64,
+}
+
+/// Data migrations in the order they are applied
+/// Append new migrations with the next version, never renumber or remove one
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "normalize_pool_and_token_addresses",
+        run: normalize_addresses,
+    },
+    Migration {
+        version: 2,
+        name: "remove_duplicate_networks_pools_tokens",
+        run: remove_duplicates,
+    },
+    Migration {
+        version: 3,
+        name: "set_price_network_of_known_chains",
+        run: set_price_networks,
+    },
+];
+
+/// GeckoTerminal network slugs of well-known chains, by chain id
+const KNOWN_PRICE_NETWORKS: &[(u64, &str)] = &[
+    (1, "eth"),
+    (10, "optimism"),
+    (56, "bsc"),
+    (100, "xdai"),
+    (137, "polygon_pos"),
+    (324, "zksync"),
+    (8453, "base"),
+    (42161, "arbitrum"),
+    (43114, "avax"),
+    (59144, "linea"),
+];
+
+/// Apply the migrations that are not recorded in `schema_migrations` yet, in version order
+/// Returns the versions applied
+pub async fn run_migrations(database: &MongoDatabase) -> Result<Vec<u32>> {
+    let collection = database.collection::<AppliedMigration>(MIGRATIONS_COLLECTION);
+
+    // Unique index on version, a migration is recorded once
+    let version_index = IndexModel::builder()
+        .keys(doc! { "version": 1 })
+        .options(IndexOptions::builder().unique(true).build())
+        .build();
+    collection
+        .create_index(version_index)
+        .await
+        .map_err(|e| anyhow!("Failed to create schema migration index: {}", e))?;
+
+    let applied: HashSet<u32> = collection
+        .find(doc! {})
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?
+        .into_iter()
+        .map(|migration| migration.version)
+        .collect();
+
+    let mut versions = Vec::new();
+    for migration in pending(MIGRATIONS, &applied) {
+        info!(
+            "Applying migration {} ({})",
+            migration.version, migration.name
+        );
+        (migration.run)(database).await.map_err(|e| {
+            anyhow!(
+                "Migration {} ({}) failed: {}",
+                migration.version,
+                migration.name,
+                e
+            )
+        })?;
+
+        let record = AppliedMigration {
+            version: migration.version,
+            name: migration.name.to_string(),
+            applied_at: Utc::now().timestamp() as u64,
+        };
+        if let Err(e) = collection.insert_one(record).await {
+            // Another instance applied it concurrently
+            if !is_duplicate_key_error(&e) {
+                return Err(e.into());
+            }
+        }
+        versions.push(migration.version);
+    }
+
+    if versions.is_empty() {
+        info!("Database schema is up to date");
+    } else {
+        info!("Applied {} migration(s)", versions.len());
+    }
+    Ok(versions)
+}
+
+/// Migrations of `migrations` whose version is not in `applied`, in version order
+fn pending<'a>(migrations: &'a [Migration], applied: &HashSet<u32>) -> Vec<&'a Migration> {
+    let mut pending: Vec<&Migration> = migrations
+        .iter()
+        .filter(|migration| !applied.contains(&migration.version))
+        .collect();
+    pending.sort_by_key(|migration| migration.version);
+    pending
+}
+
+/// Rewrite pool and token addresses in the lowercase form used for lookups
+///
+/// Pools created through the API used to keep the checksummed address of the request, which
+/// the unique `(network_id, address)` index does not match against the indexer's lowercase
+/// copy. When both exist, the lowercase copy is kept.
+fn normalize_addresses(database: &MongoDatabase) -> BoxFuture<'_, Result<()>> {
+    Box::pin(async move {
+        for name in ["pools", "tokens"] {
+            let collection = database.collection::<Document>(name);
+            let documents: Vec<Document> = collection
+                .find(doc! { "address": { "$regex": "[A-F]" } })
+                .await?
+                .try_collect()
+                .await?;
+
+            let (mut normalized, mut removed) = (0, 0);
+            for document in documents {
+                let id = document.get_object_id("_id")?;
+                let address_str = document.get_str("address")?;
+                let Ok(address) = Address::from_str(address_str) else {
+                    warn!(
+                        "Skipping {} {} with invalid address {}",
+                        name, id, address_str
+                    );
+                    continue;
+                };
+
+                let update = doc! { "$set": { "address": address_to_string(&address) } };
+                match collection.update_one(doc! { "_id": id }, update).await {
+                    Ok(_) => normalized += 1,
+                    Err(e) if is_duplicate_key_error(&e) => {
+                        collection.delete_one(doc! { "_id": id }).await?;
+                        removed += 1;
+                    }
+                    Err(e) => return Err(e.into()),
+                }
+            }
+
+            if normalized + removed > 0 {
+                info!(
+                    "Normalized {} {} addresses, removed {} duplicates",
+                    normalized, name, removed
+                );
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Remove the duplicate networks (chain_id), pools and tokens (network_id, address) left by
+/// concurrent inserts before the unique indexes existed, keeping the oldest document
+fn remove_duplicates(database: &MongoDatabase) -> BoxFuture<'_, Result<()>> {
+    Box::pin(async move {
+        let keys = [
+            ("networks", doc! { "chain_id": "$chain_id" }),
+            (
+                "pools",
+                doc! { "network_id": "$network_id", "address": "$address" },
+            ),
+            (
+                "tokens",
+                doc! { "network_id": "$network_id", "address": "$address" },
+            ),
+        ];
+
+        for (name, key) in keys {
+            let collection = database.collection::<Document>(name);
+            let pipeline = vec![
+                doc! { "$sort": { "_id": 1 } },
+                doc! { "$group": { "_id": key, "ids": { "$push": "$_id" } } },
+                doc! { "$match": { "ids.1": { "$exists": true } } },
+            ];
+            let groups: Vec<Document> = collection.aggregate(pipeline).await?.try_collect().await?;
+
+            let mut duplicates: Vec<ObjectId> = Vec::new();
+            for group in groups {
+                duplicates.extend(
+                    group
+                        .get_array("ids")?
+                        .iter()
+                        .skip(1)
+                        .filter_map(|id| id.as_object_id()),
+                );
+            }
+
+            if !duplicates.is_empty() {
+                let result = collection
+                    .delete_many(doc! { "_id": { "$in": &duplicates } })
+                    .await?;
+                warn!("Removed {} duplicate {}", result.deleted_count, name);
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Set the GeckoTerminal network slug of the networks of well-known chains that have none
+///
+/// Prices used to be fetched with the lowercased network name as slug, which GeckoTerminal
+/// does not know for most chains (e.g. "ethereum" instead of "eth").
+fn set_price_networks(database: &MongoDatabase) -> BoxFuture<'_, Result<()>> {
+    Box::pin(async move {
+        let collection = database.collection::<Document>("networks");
+        let mut updated = 0;
+        for (chain_id, slug) in KNOWN_PRICE_NETWORKS {
+            let result = collection
+                .update_one(
+                    doc! { "chain_id": *chain_id as i64, "price_network": null },
+                    doc! { "$set": { "price_network": *slug } },
+                )
+                .await?;
+            updated += result.modified_count;
+        }
+
+        if updated > 0 {
+            info!("Set the price network of {} networks", updated);
+        }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migration_versions_are_increasing() {
+        assert!(MIGRATIONS
+            .windows(2)
+            .all(|pair| pair[0].version < pair[1].version));
+    }
+
+    #[test]
+    fn test_pending_migrations() {
+        let applied = HashSet::from([1]);
+        let versions: Vec<u32> = pending(MIGRATIONS, &applied)
+            .iter()
+            .map(|migration| migration.version)
+            .collect();
+        assert_eq!(versions, vec![2, 3]);
+
+        let applied = MIGRATIONS
+            .iter()
+            .map(|migration| migration.version)
+            .collect();
+        assert!(pending(MIGRATIONS, &applied).is_empty());
+    }
+}
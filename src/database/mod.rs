@@ -1,12 +1,13 @@
 // MongoDB modules
+pub mod changes;
+pub mod migrations;
 pub mod models;
 pub mod mongodb;
-// pub mod mongodb_logger;
 pub mod repositories;
 pub mod service;
 
 // Re-export commonly used types
-pub use mongodb::MongoDbClient;
+pub use mongodb::{MongoDbClient, WriteSession};
 pub use service::MongoDbService;
 
 // Legacy sled Database (kept for backward compatibility if needed)
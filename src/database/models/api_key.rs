@@ -0,0 +1,160 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::str::FromStr;
+
+/// Permission granted to an API key
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiKeyScope {
+    Read,
+    Write,
+    Admin,
+    HardDelete,
+}
+
+impl ApiKeyScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Read => "read",
+            Self::Write => "write",
+            Self::Admin => "admin",
+            Self::HardDelete => "hard_delete",
+        }
+    }
+
+    /// Whether holding this scope is enough for `required`
+    /// `admin` grants everything and `write` also grants `read`
+    pub fn grants(&self, required: ApiKeyScope) -> bool {
+        match self {
+            Self::Admin => true,
+            Self::Write => matches!(required, Self::Write | Self::Read),
+            scope => *scope == required,
+        }
+    }
+}
+
+impl fmt::Display for ApiKeyScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ApiKeyScope {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "read" => Ok(Self::Read),
+            "write" => Ok(Self::Write),
+            "admin" => Ok(Self::Admin),
+            "hard_delete" => Ok(Self::HardDelete),
+            _ => Err(anyhow::anyhow!("Invalid API key scope '{}'", s)),
+        }
+    }
+}
+
+/// Seconds between two updates of the `last_used_at` of a key, which is used on every request
+pub const LAST_USED_INTERVAL_SECS: u64 = 60;
+
+/// Hash of an API key as stored in MongoDB (hex encoded SHA-256)
+pub fn hash_api_key(key: &str) -> String {
+    hex::encode(Sha256::digest(key.as_bytes()))
+}
+
+/// API key model for MongoDB
+/// Only the hash of the key is stored, the key itself is shown once at creation
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ApiKeyRecord {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<bson::oid::ObjectId>,
+    pub name: String,
+    pub key_hash: String,
+    pub key_prefix: String, // first characters of the key, to tell keys apart
+    pub scopes: Vec<ApiKeyScope>,
+    pub expires_at: Option<u64>,
+    pub revoked_at: Option<u64>,
+    pub last_used_at: Option<u64>,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+impl ApiKeyRecord {
+    pub fn new(name: String, key: &str, scopes: Vec<ApiKeyScope>, expires_at: Option<u64>) -> Self {
+        Self {
+            id: None,
+            name,
+            key_hash: hash_api_key(key),
+            key_prefix: key.chars().take(8).collect(),
+            scopes,
+            expires_at,
+            revoked_at: None,
+            last_used_at: None,
+            created_at: Utc::now().timestamp() as u64,
+            updated_at: Utc::now().timestamp() as u64,
+        }
+    }
+
+    /// Whether the key is neither revoked nor expired at `now`
+    pub fn is_active(&self, now: u64) -> bool {
+        self.revoked_at.is_none() && self.expires_at.is_none_or(|expires_at| expires_at > now)
+    }
+
+    /// Whether a use of the key at `now` should be recorded in `last_used_at`
+    pub fn needs_touch(&self, now: u64) -> bool {
+        self.last_used_at
+            .is_none_or(|last_used_at| now >= last_used_at + LAST_USED_INTERVAL_SECS)
+    }
+
+    /// Whether one of the scopes of the key grants `required`
+    pub fn has_scope(&self, required: ApiKeyScope) -> bool {
+        self.scopes.iter().any(|scope| scope.grants(required))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_key_scopes() {
+        let key = ApiKeyRecord::new("ops".to_string(), "secret", vec![ApiKeyScope::Write], None);
+        assert!(key.has_scope(ApiKeyScope::Read));
+        assert!(key.has_scope(ApiKeyScope::Write));
+        assert!(!key.has_scope(ApiKeyScope::HardDelete));
+        assert!(!key.has_scope(ApiKeyScope::Admin));
+
+        let admin = ApiKeyRecord::new("root".to_string(), "secret", vec![ApiKeyScope::Admin], None);
+        assert!(admin.has_scope(ApiKeyScope::HardDelete));
+
+        assert_eq!(
+            "hard_delete".parse::<ApiKeyScope>().unwrap(),
+            ApiKeyScope::HardDelete
+        );
+        assert!("owner".parse::<ApiKeyScope>().is_err());
+    }
+
+    #[test]
+    fn test_api_key_is_active() {
+        let mut key = ApiKeyRecord::new("ops".to_string(), "secret", vec![], Some(100));
+        assert_eq!(key.key_hash, hash_api_key("secret"));
+        assert_ne!(key.key_hash, "secret");
+        assert!(key.is_active(99));
+        assert!(!key.is_active(100));
+
+        key.expires_at = None;
+        key.revoked_at = Some(1);
+        assert!(!key.is_active(0));
+    }
+
+    #[test]
+    fn test_api_key_needs_touch() {
+        let mut key = ApiKeyRecord::new("ops".to_string(), "secret", vec![], None);
+        assert!(key.needs_touch(1_000));
+
+        key.last_used_at = Some(1_000);
+        assert!(!key.needs_touch(1_059));
+        assert!(key.needs_touch(1_060));
+    }
+}
@@ -0,0 +1,211 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::str::FromStr;
+
+/// Fields left out of audit diffs, they change on every write
+const IGNORED_FIELDS: [&str; 3] = ["_id", "created_at", "updated_at"];
+
+/// Change made to a configuration entity
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+    Undelete,
+    HardDelete,
+    Restore,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Update => "update",
+            Self::Delete => "delete",
+            Self::Undelete => "undelete",
+            Self::HardDelete => "hard_delete",
+            Self::Restore => "restore",
+        }
+    }
+}
+
+impl fmt::Display for AuditAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for AuditAction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "create" => Ok(Self::Create),
+            "update" => Ok(Self::Update),
+            "delete" => Ok(Self::Delete),
+            "undelete" => Ok(Self::Undelete),
+            "hard_delete" => Ok(Self::HardDelete),
+            "restore" => Ok(Self::Restore),
+            _ => Err(anyhow::anyhow!("Invalid audit action '{}'", s)),
+        }
+    }
+}
+
+/// Kind of configuration entity an audit entry is about
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditEntity {
+    Network,
+    Pool,
+    Path,
+    Token,
+    ProfitToken,
+    Config,
+    ConfigSnapshot,
+}
+
+impl AuditEntity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Network => "network",
+            Self::Pool => "pool",
+            Self::Path => "path",
+            Self::Token => "token",
+            Self::ProfitToken => "profit_token",
+            Self::Config => "config",
+            Self::ConfigSnapshot => "config_snapshot",
+        }
+    }
+}
+
+impl fmt::Display for AuditEntity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for AuditEntity {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "network" => Ok(Self::Network),
+            "pool" => Ok(Self::Pool),
+            "path" => Ok(Self::Path),
+            "token" => Ok(Self::Token),
+            "profit_token" => Ok(Self::ProfitToken),
+            "config" => Ok(Self::Config),
+            "config_snapshot" => Ok(Self::ConfigSnapshot),
+            _ => Err(anyhow::anyhow!("Invalid audit entity '{}'", s)),
+        }
+    }
+}
+
+/// Value of one field before and after a change, `null` when the field is absent
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: Value,
+    pub after: Value,
+}
+
+/// Top-level fields that differ between two JSON objects, sorted by name
+/// `Value::Null` stands for a missing object (before a create, after a hard delete)
+pub fn diff_fields(before: &Value, after: &Value) -> Vec<FieldChange> {
+    let empty = serde_json::Map::new();
+    let before = before.as_object().unwrap_or(&empty);
+    let after = after.as_object().unwrap_or(&empty);
+
+    let mut fields: Vec<&String> = before.keys().chain(after.keys()).collect();
+    fields.sort();
+    fields.dedup();
+
+    fields
+        .into_iter()
+        .filter(|field| !IGNORED_FIELDS.contains(&field.as_str()))
+        .filter_map(|field| {
+            let old = before.get(field).unwrap_or(&Value::Null);
+            let new = after.get(field).unwrap_or(&Value::Null);
+            (old != new).then(|| FieldChange {
+                field: field.clone(),
+                before: old.clone(),
+                after: new.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Audit log entry model for MongoDB
+/// One change made to a network, pool, path, token, the config or its snapshots through the API
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AuditEntry {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<bson::oid::ObjectId>,
+    pub actor: String, // name of the API key that made the change
+    pub action: AuditAction,
+    pub entity: AuditEntity,
+    pub entity_id: String,
+    pub changes: Vec<FieldChange>,
+    pub created_at: u64,
+}
+
+impl AuditEntry {
+    pub fn new(
+        actor: String,
+        action: AuditAction,
+        entity: AuditEntity,
+        entity_id: String,
+        changes: Vec<FieldChange>,
+    ) -> Self {
+        Self {
+            id: None,
+            actor,
+            action,
+            entity,
+            entity_id,
+            changes,
+            created_at: Utc::now().timestamp() as u64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_diff_fields() {
+        let before = json!({
+            "_id": { "$oid": "65f000000000000000000000" },
+            "name": "Base",
+            "rpcs": ["https://a"],
+            "deleted_at": null,
+            "updated_at": 1,
+        });
+        let after = json!({
+            "_id": { "$oid": "65f000000000000000000000" },
+            "name": "Base",
+            "rpcs": ["https://a", "https://b"],
+            "deleted_at": 10,
+            "updated_at": 2,
+        });
+
+        let changes = diff_fields(&before, &after);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].field, "deleted_at");
+        assert_eq!(changes[0].before, Value::Null);
+        assert_eq!(changes[0].after, json!(10));
+        assert_eq!(changes[1].field, "rpcs");
+
+        let created = diff_fields(&Value::Null, &after);
+        assert_eq!(
+            created.iter().map(|c| c.field.as_str()).collect::<Vec<_>>(),
+            vec!["deleted_at", "name", "rpcs"]
+        );
+        assert!(diff_fields(&after, &after).is_empty());
+    }
+}
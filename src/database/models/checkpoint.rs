@@ -0,0 +1,31 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// Indexer name used by the pool indexer checkpoint
+pub const POOL_INDEXER: &str = "pool_indexer";
+
+/// Indexing checkpoint model for MongoDB
+/// Stores the last block processed by a background scanner on a network
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IndexerCheckpoint {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<bson::oid::ObjectId>,
+    pub network_id: u64,
+    pub indexer: String,
+    pub last_block: u64,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+impl IndexerCheckpoint {
+    pub fn new(network_id: u64, indexer: String, last_block: u64) -> Self {
+        Self {
+            id: None,
+            network_id,
+            indexer,
+            last_block,
+            created_at: Utc::now().timestamp() as u64,
+            updated_at: Utc::now().timestamp() as u64,
+        }
+    }
+}
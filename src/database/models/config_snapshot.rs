@@ -0,0 +1,279 @@
+use anyhow::{anyhow, Result};
+use bson::Document;
+use chrono::Utc;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::database::models::{AuditEntity, Config, Network, Path};
+
+/// Pool of a configuration snapshot, pools carry nothing besides their network and address
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct SnapshotPool {
+    pub network_id: u64,
+    pub address: String,
+}
+
+/// Configuration snapshot
+/// Immutable copy of the active networks, pools and paths and of the config, numbered from 1
+///
+/// A snapshot is stored as a `ConfigSnapshotHeader` in `config_snapshots` and one
+/// `ConfigSnapshotRecord` per network, pool and path in `config_snapshot_records`, so its size
+/// is not bound by the 16 MB limit of a MongoDB document.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ConfigSnapshot {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<bson::oid::ObjectId>,
+    pub version: u64, // assigned on insert
+    pub description: Option<String>,
+    pub created_by: String, // name of the API key that took the snapshot
+    pub networks: Vec<Network>,
+    pub pools: Vec<SnapshotPool>,
+    pub paths: Vec<Path>,
+    pub config: Option<Config>,
+    pub created_at: u64,
+}
+
+impl ConfigSnapshot {
+    pub fn new(
+        description: Option<String>,
+        created_by: String,
+        networks: Vec<Network>,
+        pools: Vec<SnapshotPool>,
+        paths: Vec<Path>,
+        config: Option<Config>,
+    ) -> Self {
+        Self {
+            id: None,
+            version: 0,
+            description,
+            created_by,
+            networks,
+            pools,
+            paths,
+            config,
+            created_at: Utc::now().timestamp() as u64,
+        }
+    }
+
+    /// Header to store for the snapshot, not complete until its records are stored
+    pub fn header(&self) -> ConfigSnapshotHeader {
+        ConfigSnapshotHeader {
+            id: self.id,
+            version: self.version,
+            description: self.description.clone(),
+            created_by: self.created_by.clone(),
+            networks: self.networks.len() as u64,
+            pools: self.pools.len() as u64,
+            paths: self.paths.len() as u64,
+            config: self.config.clone(),
+            complete: false,
+            created_at: self.created_at,
+        }
+    }
+
+    /// Records to store for the snapshot, numbered in the snapshot order
+    pub fn records(&self) -> Result<Vec<ConfigSnapshotRecord>> {
+        let mut records =
+            Vec::with_capacity(self.networks.len() + self.pools.len() + self.paths.len());
+        records.extend(ConfigSnapshotRecord::numbered(
+            self.version,
+            AuditEntity::Network,
+            &self.networks,
+        )?);
+        records.extend(ConfigSnapshotRecord::numbered(
+            self.version,
+            AuditEntity::Pool,
+            &self.pools,
+        )?);
+        records.extend(ConfigSnapshotRecord::numbered(
+            self.version,
+            AuditEntity::Path,
+            &self.paths,
+        )?);
+        Ok(records)
+    }
+
+    /// Snapshot from its stored header and records, records in the snapshot order
+    pub fn from_parts(
+        header: ConfigSnapshotHeader,
+        records: Vec<ConfigSnapshotRecord>,
+    ) -> Result<Self> {
+        let mut snapshot = Self {
+            id: header.id,
+            version: header.version,
+            description: header.description,
+            created_by: header.created_by,
+            networks: Vec::new(),
+            pools: Vec::new(),
+            paths: Vec::new(),
+            config: header.config,
+            created_at: header.created_at,
+        };
+        for record in records {
+            match record.entity {
+                AuditEntity::Network => snapshot.networks.push(record.decode()?),
+                AuditEntity::Pool => snapshot.pools.push(record.decode()?),
+                AuditEntity::Path => snapshot.paths.push(record.decode()?),
+                entity => {
+                    return Err(anyhow!(
+                        "Config snapshot version {} has a record of unexpected kind {}",
+                        header.version,
+                        entity
+                    ))
+                }
+            }
+        }
+        Ok(snapshot)
+    }
+}
+
+/// Stored document of a configuration snapshot, in `config_snapshots`
+/// Only snapshots whose records were all stored are `complete`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ConfigSnapshotHeader {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<bson::oid::ObjectId>,
+    pub version: u64,
+    pub description: Option<String>,
+    pub created_by: String,
+    pub networks: u64, // number of network records
+    pub pools: u64,
+    pub paths: u64,
+    pub config: Option<Config>,
+    pub complete: bool,
+    pub created_at: u64,
+}
+
+/// One network, pool or path of a configuration snapshot, in `config_snapshot_records`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ConfigSnapshotRecord {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<bson::oid::ObjectId>,
+    pub version: u64,
+    pub entity: AuditEntity, // network, pool or path
+    pub position: u64,       // order of the record among the records of its kind
+    pub record: Document,
+}
+
+impl ConfigSnapshotRecord {
+    /// Records of one kind, numbered in the given order
+    fn numbered<T: Serialize>(
+        version: u64,
+        entity: AuditEntity,
+        records: &[T],
+    ) -> Result<Vec<Self>> {
+        records
+            .iter()
+            .enumerate()
+            .map(|(position, record)| {
+                Ok(Self {
+                    id: None,
+                    version,
+                    entity,
+                    position: position as u64,
+                    record: bson::to_document(record)?,
+                })
+            })
+            .collect()
+    }
+
+    fn decode<T: DeserializeOwned>(self) -> Result<T> {
+        Ok(bson::from_document(self.record)?)
+    }
+}
+
+/// Configuration snapshot without its content, with the number of records of each kind
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ConfigSnapshotSummary {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<bson::oid::ObjectId>,
+    pub version: u64,
+    pub description: Option<String>,
+    pub created_by: String,
+    pub networks: u64,
+    pub pools: u64,
+    pub paths: u64,
+    pub created_at: u64,
+}
+
+impl From<&ConfigSnapshot> for ConfigSnapshotSummary {
+    fn from(snapshot: &ConfigSnapshot) -> Self {
+        Self {
+            id: snapshot.id,
+            version: snapshot.version,
+            description: snapshot.description.clone(),
+            created_by: snapshot.created_by.clone(),
+            networks: snapshot.networks.len() as u64,
+            pools: snapshot.pools.len() as u64,
+            paths: snapshot.paths.len() as u64,
+            created_at: snapshot.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Maximum size of a MongoDB document
+    const MAX_DOCUMENT_SIZE: usize = 16 * 1024 * 1024;
+
+    fn snapshot(pools: usize) -> ConfigSnapshot {
+        let pools = (0..pools)
+            .map(|i| SnapshotPool {
+                network_id: 8453,
+                address: format!("0x{:040x}", i),
+            })
+            .collect();
+        let mut snapshot = ConfigSnapshot::new(
+            Some("nightly".to_string()),
+            "admin".to_string(),
+            vec![],
+            pools,
+            vec![Path::new(vec![])],
+            None,
+        );
+        snapshot.version = 7;
+        snapshot
+    }
+
+    #[test]
+    fn test_snapshot_size_does_not_grow_with_pools() {
+        // 400k pools would take about 30 MB in a single document
+        let large = snapshot(400_000);
+        let header_size = bson::to_vec(&large.header()).unwrap().len();
+        assert_eq!(
+            header_size,
+            bson::to_vec(&snapshot(0).header()).unwrap().len()
+        );
+
+        let records = large.records().unwrap();
+        assert_eq!(records.len(), 400_001);
+        let record_size = records
+            .iter()
+            .map(|record| bson::to_vec(record).unwrap().len())
+            .max()
+            .unwrap();
+        assert!(header_size < 1024 && record_size < 1024);
+        assert!(header_size + record_size < MAX_DOCUMENT_SIZE);
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let snapshot = snapshot(3);
+        let header = snapshot.header();
+        assert_eq!((header.pools, header.paths), (3, 1));
+        assert!(!header.complete);
+
+        let mut records = snapshot.records().unwrap();
+        assert!(records.iter().all(|record| record.version == 7));
+        records.reverse();
+        // Records come back sorted by kind and position
+        records.sort_by_key(|record| (record.entity.as_str(), record.position));
+
+        let restored = ConfigSnapshot::from_parts(header, records).unwrap();
+        assert_eq!(restored.pools, snapshot.pools);
+        assert_eq!(restored.paths.len(), 1);
+        assert_eq!(restored.description.as_deref(), Some("nightly"));
+    }
+}
@@ -1,14 +1,32 @@
+pub mod api_key;
+pub mod audit;
+pub mod checkpoint;
 pub mod config;
+pub mod config_snapshot;
 pub mod network;
+pub mod opportunity;
 pub mod path;
 pub mod pool;
+pub mod price;
+pub mod profit_token;
+pub mod rpc_health;
 pub mod token;
 pub mod utils;
 
 // Re-export models explicitly to avoid ambiguous glob re-exports
+pub use api_key::{hash_api_key, ApiKeyRecord, ApiKeyScope};
+pub use audit::{diff_fields, AuditAction, AuditEntity, AuditEntry, FieldChange};
+pub use checkpoint::IndexerCheckpoint;
 pub use config::Config;
+pub use config_snapshot::{
+    ConfigSnapshot, ConfigSnapshotHeader, ConfigSnapshotRecord, ConfigSnapshotSummary, SnapshotPool,
+};
 pub use network::Network;
+pub use opportunity::{Opportunity, OpportunityFilter, OpportunityStatus};
 pub use path::Path;
 pub use pool::Pool;
+pub use price::PriceSnapshot;
+pub use profit_token::ProfitTokenConfig;
+pub use rpc_health::{RpcHealth, RpcKind};
 pub use token::Token;
 pub use utils::{address_to_string, u256_to_string};
@@ -2,6 +2,8 @@ use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::database::models::RpcKind;
+
 /// Network model for MongoDB
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Network {
@@ -17,6 +19,7 @@ pub struct Network {
     pub v2_factory_to_fee: Option<HashMap<String, u64>>,
     pub aero_factory_addresses: Option<Vec<String>>,
     pub multicall_address: Option<String>,
+    pub price_network: Option<String>, // GeckoTerminal network slug, e.g. "eth" or "polygon_pos"
     pub max_blocks_per_batch: u64,
     pub wait_time_fetch: u64,
     pub created_at: u64,
@@ -51,6 +54,7 @@ impl Network {
             v2_factory_to_fee,
             aero_factory_addresses,
             multicall_address,
+            price_network: None,
             max_blocks_per_batch,
             wait_time_fetch,
             created_at: Utc::now().timestamp() as u64,
@@ -58,4 +62,22 @@ impl Network {
             deleted_at: None,
         }
     }
+
+    /// RPC and WebSocket endpoints of the network, each listed once, RPCs first
+    pub fn rpc_endpoints(&self) -> Vec<(String, RpcKind)> {
+        let rpcs = self.rpcs.iter().map(|url| (url, RpcKind::Http));
+        let websockets = self
+            .websocket_urls
+            .iter()
+            .flatten()
+            .map(|url| (url, RpcKind::Websocket));
+
+        let mut endpoints: Vec<(String, RpcKind)> = Vec::new();
+        for (url, kind) in rpcs.chain(websockets) {
+            if !endpoints.iter().any(|(known, _)| known == url) {
+                endpoints.push((url.clone(), kind));
+            }
+        }
+        endpoints
+    }
 }
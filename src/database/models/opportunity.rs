@@ -0,0 +1,249 @@
+use bson::{doc, Document};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Outcome of an arbitrage opportunity found by the bot
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OpportunityStatus {
+    Detected,
+    Pending,
+    Succeeded,
+    PartiallySucceeded,
+    Reverted,
+    Error,
+}
+
+impl OpportunityStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Detected => "detected",
+            Self::Pending => "pending",
+            Self::Succeeded => "succeeded",
+            Self::PartiallySucceeded => "partially_succeeded",
+            Self::Reverted => "reverted",
+            Self::Error => "error",
+        }
+    }
+}
+
+impl fmt::Display for OpportunityStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for OpportunityStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "detected" => Ok(Self::Detected),
+            "pending" => Ok(Self::Pending),
+            "succeeded" => Ok(Self::Succeeded),
+            "partially_succeeded" => Ok(Self::PartiallySucceeded),
+            "reverted" => Ok(Self::Reverted),
+            "error" => Ok(Self::Error),
+            _ => Err(anyhow::anyhow!("Invalid opportunity status '{}'", s)),
+        }
+    }
+}
+
+/// Opportunity model for MongoDB
+/// One cross-chain arbitrage found by the bot, from detection to its final outcome
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Opportunity {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<bson::oid::ObjectId>,
+    pub source_chain_id: u64,
+    pub target_chain_id: u64,
+    pub path_id: Option<String>,
+    pub anchor_token: String,
+    pub amount_in: String,          // raw amount of the source anchor token
+    pub amount_out: Option<String>, // raw amount of the target anchor token
+    pub estimate_profit_usd: Option<f64>,
+    pub profit_usd: Option<f64>,
+    pub gas_usd: Option<f64>,
+    pub status: OpportunityStatus,
+    pub source_block_number: Option<u64>,
+    pub target_block_number: Option<u64>,
+    pub source_tx: Option<String>,
+    pub target_tx: Option<String>,
+    pub error: Option<String>,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+impl Opportunity {
+    pub fn new(
+        source_chain_id: u64,
+        target_chain_id: u64,
+        path_id: Option<String>,
+        anchor_token: String,
+        amount_in: String,
+        estimate_profit_usd: Option<f64>,
+        source_block_number: Option<u64>,
+    ) -> Self {
+        Self {
+            id: None,
+            source_chain_id,
+            target_chain_id,
+            path_id,
+            anchor_token,
+            amount_in,
+            amount_out: None,
+            estimate_profit_usd,
+            profit_usd: None,
+            gas_usd: None,
+            status: OpportunityStatus::Detected,
+            source_block_number,
+            target_block_number: None,
+            source_tx: None,
+            target_tx: None,
+            error: None,
+            created_at: Utc::now().timestamp() as u64,
+            updated_at: Utc::now().timestamp() as u64,
+        }
+    }
+}
+
+/// Conditions selecting opportunities, shared by the opportunity list and the analytics rollups
+/// Every condition is optional; `from` and `to` bound `created_at`, both included
+#[derive(Debug, Clone, Default)]
+pub struct OpportunityFilter<'a> {
+    pub chain_id: Option<u64>, // matches either the source or the target chain
+    pub source_chain_id: Option<u64>,
+    pub target_chain_id: Option<u64>,
+    pub path_id: Option<&'a str>,
+    pub status: Option<&'a str>,
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+}
+
+impl OpportunityFilter<'_> {
+    /// Build the MongoDB filter document
+    /// Fails on an unknown status or when `from` is after `to`
+    pub fn to_document(&self) -> anyhow::Result<Document> {
+        let mut filter = Document::new();
+
+        if let Some(chain_id) = self.chain_id {
+            filter.insert(
+                "$or",
+                vec![
+                    doc! { "source_chain_id": chain_id as i64 },
+                    doc! { "target_chain_id": chain_id as i64 },
+                ],
+            );
+        }
+        if let Some(source_chain_id) = self.source_chain_id {
+            filter.insert("source_chain_id", source_chain_id as i64);
+        }
+        if let Some(target_chain_id) = self.target_chain_id {
+            filter.insert("target_chain_id", target_chain_id as i64);
+        }
+        if let Some(path_id) = self.path_id {
+            filter.insert("path_id", path_id);
+        }
+        if let Some(status) = self.status {
+            filter.insert("status", OpportunityStatus::from_str(status)?.as_str());
+        }
+
+        if let (Some(from), Some(to)) = (self.from, self.to) {
+            if from > to {
+                return Err(anyhow::anyhow!(
+                    "Invalid time window: from {} is after to {}",
+                    from,
+                    to
+                ));
+            }
+        }
+        let mut created_at = Document::new();
+        if let Some(from) = self.from {
+            created_at.insert("$gte", from as i64);
+        }
+        if let Some(to) = self.to {
+            created_at.insert("$lte", to as i64);
+        }
+        if !created_at.is_empty() {
+            filter.insert("created_at", created_at);
+        }
+
+        Ok(filter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opportunity_status_round_trip() {
+        for status in [
+            OpportunityStatus::Detected,
+            OpportunityStatus::Pending,
+            OpportunityStatus::Succeeded,
+            OpportunityStatus::PartiallySucceeded,
+            OpportunityStatus::Reverted,
+            OpportunityStatus::Error,
+        ] {
+            assert_eq!(
+                status.as_str().parse::<OpportunityStatus>().unwrap(),
+                status
+            );
+            assert_eq!(
+                bson::to_bson(&status).unwrap(),
+                bson::Bson::String(status.to_string())
+            );
+        }
+        assert!("unknown".parse::<OpportunityStatus>().is_err());
+    }
+
+    #[test]
+    fn test_opportunity_filter_to_document() {
+        assert_eq!(OpportunityFilter::default().to_document().unwrap(), doc! {});
+
+        let filter = OpportunityFilter {
+            chain_id: Some(1),
+            target_chain_id: Some(8453),
+            path_id: Some("path-1"),
+            status: Some("Succeeded"),
+            from: Some(100),
+            to: Some(200),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.to_document().unwrap(),
+            doc! {
+                "$or": [{ "source_chain_id": 1_i64 }, { "target_chain_id": 1_i64 }],
+                "target_chain_id": 8453_i64,
+                "path_id": "path-1",
+                "status": "succeeded",
+                "created_at": { "$gte": 100_i64, "$lte": 200_i64 },
+            }
+        );
+
+        let filter = OpportunityFilter {
+            to: Some(200),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.to_document().unwrap(),
+            doc! { "created_at": { "$lte": 200_i64 } }
+        );
+
+        let filter = OpportunityFilter {
+            from: Some(300),
+            to: Some(200),
+            ..Default::default()
+        };
+        assert!(filter.to_document().is_err());
+
+        let filter = OpportunityFilter {
+            status: Some("lost"),
+            ..Default::default()
+        };
+        assert!(filter.to_document().is_err());
+    }
+}
@@ -0,0 +1,30 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::bot::models::profit_token::price_updater::PriceSourceType;
+
+/// Last known USD price of a token for MongoDB
+/// Kept so prices stay available while the price source is down
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PriceSnapshot {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<bson::oid::ObjectId>,
+    pub network_id: u64,
+    pub address: String,
+    pub price_usd: f64,
+    pub source: PriceSourceType,
+    pub fetched_at: u64,
+}
+
+impl PriceSnapshot {
+    pub fn new(network_id: u64, address: String, price_usd: f64, source: PriceSourceType) -> Self {
+        Self {
+            id: None,
+            network_id,
+            address,
+            price_usd,
+            source,
+            fetched_at: Utc::now().timestamp() as u64,
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use alloy::primitives::{Address, U256};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use crate::bot::models::profit_token::{price_updater::PriceSourceType, ProfitToken};
+
+/// Profit token configuration of a network for MongoDB
+/// A token in which arbitrage profits may be taken, with its minimum profit and price
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProfitTokenConfig {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<bson::oid::ObjectId>,
+    pub network_id: u64,
+    pub address: String,
+    pub min_profit: String, // minimum profit in the token's smallest unit (U256 as string)
+    pub price_source: Option<PriceSourceType>, // None to always use `default_price`
+    pub default_price: f64, // USD price used when the price source is unavailable
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+impl ProfitTokenConfig {
+    pub fn new(
+        network_id: u64,
+        address: String,
+        min_profit: String,
+        price_source: Option<PriceSourceType>,
+        default_price: f64,
+    ) -> Self {
+        Self {
+            id: None,
+            network_id,
+            address,
+            min_profit,
+            price_source,
+            default_price,
+            created_at: Utc::now().timestamp() as u64,
+            updated_at: Utc::now().timestamp() as u64,
+        }
+    }
+
+    /// Profit token for the bot's profit token registry, the price is not known yet
+    pub fn to_profit_token(&self) -> anyhow::Result<ProfitToken> {
+        Ok(ProfitToken {
+            address: Address::from_str(&self.address)?,
+            min_profit: U256::from_str(&self.min_profit)?,
+            price_source: self.price_source.clone(),
+            price: None,
+            default_price: self.default_price,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_profit_token() {
+        let config = ProfitTokenConfig::new(
+            1,
+            "0x6b175474e89094c44da98b954eedeac495271d0f".to_string(),
+            "1000000000000000000".to_string(),
+            None,
+            1.0,
+        );
+        let token = config.to_profit_token().unwrap();
+        assert_eq!(token.min_profit, U256::from(10u64).pow(U256::from(18)));
+        assert_eq!(token.price_source, None);
+        assert_eq!(token.default_price, 1.0);
+
+        let config = ProfitTokenConfig {
+            min_profit: "-1".to_string(),
+            ..config
+        };
+        assert!(config.to_profit_token().is_err());
+    }
+}
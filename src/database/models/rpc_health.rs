@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+/// Transport of an RPC endpoint
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RpcKind {
+    Http,      // from the network's `rpcs`
+    Websocket, // from the network's `websocket_urls`
+}
+
+/// Result of the latest health check of an RPC endpoint for MongoDB
+/// One record per network and URL, replaced on every check
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RpcHealth {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<bson::oid::ObjectId>,
+    pub network_id: u64,
+    pub url: String,
+    pub kind: RpcKind,
+    pub healthy: bool, // answered with the network's chain id within the timeout
+    pub head_block: Option<u64>,
+    pub lag: Option<u64>, // blocks behind the highest head of the network's endpoints
+    pub latency_ms: Option<u64>,
+    pub error: Option<String>,
+    pub recent: Vec<bool>, // outcome of the last checks, oldest first
+    pub error_rate: f64,   // share of failed checks in `recent`
+    pub last_ok_at: Option<u64>,
+    pub checked_at: u64,
+}
@@ -11,6 +11,10 @@ pub struct Token {
     pub name: Option<String>,
     pub symbol: Option<String>,
     pub decimals: Option<u8>,
+    #[serde(default)]
+    pub blacklisted: bool, // excluded from routing
+    #[serde(default)]
+    pub profit_token: bool, // profits may be taken in this token
     pub created_at: u64,
     pub updated_at: u64,
     pub deleted_at: Option<u64>,
@@ -31,6 +35,8 @@ impl Token {
             name,
             symbol,
             decimals,
+            blacklisted: false,
+            profit_token: false,
             created_at: Utc::now().timestamp() as u64,
             updated_at: Utc::now().timestamp() as u64,
             deleted_at: None,
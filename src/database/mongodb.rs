@@ -1,17 +1,78 @@
 use crate::config::MongoDbConfig;
+use crate::database::migrations::run_migrations;
+use crate::database::repositories::search::case_insensitive;
 use anyhow::{anyhow, Result};
 use log::{error, info};
 use mongodb::{
     bson::doc,
+    error::{Error as MongoError, ErrorKind, InsertManyError, WriteError, WriteFailure},
     options::{ClientOptions, IndexOptions, ServerApi, ServerApiVersion},
-    Client, Collection, Database as MongoDatabase, IndexModel,
+    Client, ClientSession, Collection, Database as MongoDatabase, IndexModel,
 };
 use std::sync::Arc;
 
+/// Whether a write failed on a unique index (E11000 duplicate key)
+pub(crate) fn is_duplicate_key_error(error: &MongoError) -> bool {
+    matches!(
+        error.kind.as_ref(),
+        ErrorKind::Write(WriteFailure::WriteError(WriteError { code: 11000, .. }))
+    )
+}
+
+/// Number of documents an ordered `insert_many` stored before failing
+/// Documents are inserted in order until the first one rejected
+pub(crate) fn inserted_before_error(error: &MongoError) -> usize {
+    match error.kind.as_ref() {
+        ErrorKind::InsertMany(InsertManyError {
+            write_errors: Some(errors),
+            ..
+        }) => errors.iter().map(|error| error.index).min().unwrap_or(0),
+        _ => 0,
+    }
+}
+
+/// Session of a group of writes, see `MongoDbClient::start_writes`
+///
+/// In a transaction, none of the writes is visible until `commit` and all are undone by
+/// `abort`. Otherwise every write is applied when made and `commit`/`abort` do nothing.
+pub struct WriteSession {
+    session: ClientSession,
+    transactional: bool,
+}
+
+impl WriteSession {
+    /// Session to pass to the writes of the group
+    pub fn session(&mut self) -> &mut ClientSession {
+        &mut self.session
+    }
+
+    /// Whether the writes are made in a transaction
+    pub fn is_transactional(&self) -> bool {
+        self.transactional
+    }
+
+    /// Commit the transaction, if any
+    pub async fn commit(mut self) -> Result<()> {
+        if self.transactional {
+            self.session.commit_transaction().await?;
+        }
+        Ok(())
+    }
+
+    /// Abort the transaction, if any, undoing its writes
+    pub async fn abort(mut self) {
+        if self.transactional {
+            if let Err(e) = self.session.abort_transaction().await {
+                error!("Failed to abort MongoDB transaction: {}", e);
+            }
+        }
+    }
+}
+
 /// MongoDB client wrapper for managing database connections and operations
 #[derive(Debug, Clone)]
 pub struct MongoDbClient {
-    _client: Client,
+    client: Client,
     database: MongoDatabase,
 }
 
@@ -57,12 +118,9 @@ impl MongoDbClient {
             }
         }
 
-        let db_client = Arc::new(Self {
-            _client: client,
-            database,
-        });
+        let db_client = Arc::new(Self { client, database });
 
-        db_client.create_indexes().await?;
+        db_client.migrate().await?;
 
         Ok(db_client)
     }
@@ -77,6 +135,35 @@ impl MongoDbClient {
         self.database.clone()
     }
 
+    /// Whether the deployment supports multi-document transactions
+    /// Transactions need a replica set or a sharded cluster, a standalone server has neither
+    pub async fn supports_transactions(&self) -> Result<bool> {
+        let hello = self.database.run_command(doc! { "hello": 1 }).await?;
+        Ok(hello.contains_key("setName") || hello.get_str("msg") == Ok("isdbgrid"))
+    }
+
+    /// Start a session for a group of writes, in a transaction when the deployment supports them
+    pub async fn start_writes(&self) -> Result<WriteSession> {
+        let mut session = self.client.start_session().await?;
+        let transactional = self.supports_transactions().await?;
+        if transactional {
+            session.start_transaction().await?;
+        }
+        Ok(WriteSession {
+            session,
+            transactional,
+        })
+    }
+
+    /// Apply the pending data migrations, then create the indexes
+    ///
+    /// Migrations run first so that data violating a unique index (e.g. duplicates inserted
+    /// before the index existed) is fixed before the index is built.
+    pub async fn migrate(&self) -> Result<()> {
+        run_migrations(&self.database).await?;
+        self.create_indexes().await
+    }
+
     /// Create required indexes for all collections
     pub async fn create_indexes(&self) -> Result<()> {
         info!("Creating MongoDB indexes...");
@@ -87,6 +174,15 @@ impl MongoDbClient {
         // Token indexes
         self.create_token_indexes().await?;
 
+        // Profit token indexes
+        self.create_profit_token_indexes().await?;
+
+        // Price snapshot indexes
+        self.create_price_indexes().await?;
+
+        // RPC health indexes
+        self.create_rpc_health_indexes().await?;
+
         // Pool indexes
         self.create_pool_indexes().await?;
 
@@ -96,6 +192,18 @@ impl MongoDbClient {
         // Opportunity indexes
         self.create_opportunity_indexes().await?;
 
+        // Indexer checkpoint indexes
+        self.create_checkpoint_indexes().await?;
+
+        // API key indexes
+        self.create_api_key_indexes().await?;
+
+        // Audit log indexes
+        self.create_audit_log_indexes().await?;
+
+        // Config snapshot indexes
+        self.create_config_snapshot_indexes().await?;
+
         info!("MongoDB indexes created successfully");
         Ok(())
     }
@@ -125,6 +233,30 @@ impl MongoDbClient {
             .await
             .map_err(|e| anyhow!("Failed to create network name index: {}", e))?;
 
+        // Case-insensitive index on name for prefix searches
+        let name_search_index = IndexModel::builder()
+            .keys(doc! { "name": 1 })
+            .options(
+                IndexOptions::builder()
+                    .name("name_ci".to_string())
+                    .collation(case_insensitive())
+                    .build(),
+            )
+            .build();
+
+        collection
+            .create_index(name_search_index)
+            .await
+            .map_err(|e| anyhow!("Failed to create network name search index: {}", e))?;
+
+        // Text index on name for full-text search
+        let text_index = IndexModel::builder().keys(doc! { "name": "text" }).build();
+
+        collection
+            .create_index(text_index)
+            .await
+            .map_err(|e| anyhow!("Failed to create network text index: {}", e))?;
+
         Ok(())
     }
 
@@ -142,8 +274,32 @@ impl MongoDbClient {
             ),
             // Index on symbol for fast searches
             (doc! { "symbol": 1 }, IndexOptions::default()),
+            // Case-insensitive indexes on symbol and name for prefix searches
+            (
+                doc! { "symbol": 1 },
+                IndexOptions::builder()
+                    .name("symbol_ci".to_string())
+                    .collation(case_insensitive())
+                    .build(),
+            ),
+            (
+                doc! { "name": 1 },
+                IndexOptions::builder()
+                    .name("name_ci".to_string())
+                    .collation(case_insensitive())
+                    .build(),
+            ),
             // Index on network_id for filtering
             (doc! { "network_id": 1 }, IndexOptions::default()),
+            // Index on address for searches across networks
+            (doc! { "address": 1 }, IndexOptions::default()),
+            // Text index on symbol and name for full-text search, symbol matches rank higher
+            (
+                doc! { "symbol": "text", "name": "text" },
+                IndexOptions::builder()
+                    .weights(doc! { "symbol": 2, "name": 1 })
+                    .build(),
+            ),
         ];
 
         for (keys, options) in indexes {
@@ -157,6 +313,103 @@ impl MongoDbClient {
         Ok(())
     }
 
+    /// Create indexes for profit_tokens collection
+    async fn create_profit_token_indexes(&self) -> Result<()> {
+        let collection = self
+            .database
+            .collection::<mongodb::bson::Document>("profit_tokens");
+
+        // Compound unique index for network_id + address
+        let unique_index = IndexModel::builder()
+            .keys(doc! { "network_id": 1, "address": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build();
+
+        collection
+            .create_index(unique_index)
+            .await
+            .map_err(|e| anyhow!("Failed to create profit token unique index: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Create indexes for token_prices collection
+    async fn create_price_indexes(&self) -> Result<()> {
+        let collection = self
+            .database
+            .collection::<mongodb::bson::Document>("token_prices");
+
+        // Compound unique index for network_id + address, one snapshot per token
+        let unique_index = IndexModel::builder()
+            .keys(doc! { "network_id": 1, "address": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build();
+
+        collection
+            .create_index(unique_index)
+            .await
+            .map_err(|e| anyhow!("Failed to create price snapshot unique index: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Create indexes for rpc_health collection
+    async fn create_rpc_health_indexes(&self) -> Result<()> {
+        let collection = self
+            .database
+            .collection::<mongodb::bson::Document>("rpc_health");
+
+        // Compound unique index for network_id + url, one record per endpoint
+        let unique_index = IndexModel::builder()
+            .keys(doc! { "network_id": 1, "url": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build();
+
+        collection
+            .create_index(unique_index)
+            .await
+            .map_err(|e| anyhow!("Failed to create RPC health unique index: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Create indexes for config_snapshots and config_snapshot_records collections
+    async fn create_config_snapshot_indexes(&self) -> Result<()> {
+        let collection = self
+            .database
+            .collection::<mongodb::bson::Document>("config_snapshots");
+
+        // Unique index on version, each version is taken once
+        let unique_index = IndexModel::builder()
+            .keys(doc! { "version": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build();
+
+        collection
+            .create_index(unique_index)
+            .await
+            .map_err(|e| anyhow!("Failed to create config snapshot unique index: {}", e))?;
+
+        let collection = self
+            .database
+            .collection::<mongodb::bson::Document>("config_snapshot_records");
+
+        // Unique index on version + entity + position, records are read back in this order
+        let unique_index = IndexModel::builder()
+            .keys(doc! { "version": 1, "entity": 1, "position": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build();
+
+        collection.create_index(unique_index).await.map_err(|e| {
+            anyhow!(
+                "Failed to create config snapshot record unique index: {}",
+                e
+            )
+        })?;
+
+        Ok(())
+    }
+
     /// Create indexes for pools collection
     async fn create_pool_indexes(&self) -> Result<()> {
         let collection = self.database.collection::<mongodb::bson::Document>("pools");
@@ -180,6 +433,14 @@ impl MongoDbClient {
             .await
             .map_err(|e| anyhow!("Failed to create pool network_id index: {}", e))?;
 
+        // Index on address for searches across networks
+        let address_index = IndexModel::builder().keys(doc! { "address": 1 }).build();
+
+        collection
+            .create_index(address_index)
+            .await
+            .map_err(|e| anyhow!("Failed to create pool address index: {}", e))?;
+
         Ok(())
     }
 
@@ -219,23 +480,21 @@ impl MongoDbClient {
             .collection::<mongodb::bson::Document>("opportunities");
 
         let indexes = [
-            // Index on network_id for filtering
-            (doc! { "network_id": 1 }, IndexOptions::default()),
+            // Index on source_chain_id for filtering
+            (doc! { "source_chain_id": 1 }, IndexOptions::default()),
+            // Index on target_chain_id for filtering
+            (doc! { "target_chain_id": 1 }, IndexOptions::default()),
             // Index on status for filtering
             (doc! { "status": 1 }, IndexOptions::default()),
-            // Index on profit_token for token-based queries
-            (doc! { "profit_token": 1 }, IndexOptions::default()),
+            // Index on path_id for path-based queries
+            (doc! { "path_id": 1 }, IndexOptions::default()),
             // Index on created_at for chronological ordering (newest first)
             (doc! { "created_at": -1 }, IndexOptions::default()),
-            // Index on execute_block_number for block-based queries
-            (doc! { "execute_block_number": 1 }, IndexOptions::default()),
             // Index on profit_usd for profit ranking (highest first)
             (doc! { "profit_usd": -1 }, IndexOptions::default()),
-            // Index on source_pool for pool-based queries
-            (doc! { "source_pool": 1 }, IndexOptions::default()),
-            // Compound index for network + status queries
+            // Compound index for chain + status queries
             (
-                doc! { "network_id": 1, "status": 1 },
+                doc! { "source_chain_id": 1, "status": 1 },
                 IndexOptions::default(),
             ),
         ];
@@ -250,4 +509,70 @@ impl MongoDbClient {
 
         Ok(())
     }
+
+    /// Create indexes for indexer_checkpoints collection
+    async fn create_checkpoint_indexes(&self) -> Result<()> {
+        let collection = self
+            .database
+            .collection::<mongodb::bson::Document>("indexer_checkpoints");
+
+        // Compound unique index for network_id + indexer
+        let unique_index = IndexModel::builder()
+            .keys(doc! { "network_id": 1, "indexer": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build();
+
+        collection
+            .create_index(unique_index)
+            .await
+            .map_err(|e| anyhow!("Failed to create checkpoint unique index: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Create indexes for api_keys collection
+    async fn create_api_key_indexes(&self) -> Result<()> {
+        let collection = self
+            .database
+            .collection::<mongodb::bson::Document>("api_keys");
+
+        // Unique index on key_hash, keys are looked up by hash
+        let unique_index = IndexModel::builder()
+            .keys(doc! { "key_hash": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build();
+
+        collection
+            .create_index(unique_index)
+            .await
+            .map_err(|e| anyhow!("Failed to create API key unique index: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Create indexes for audit_log collection
+    async fn create_audit_log_indexes(&self) -> Result<()> {
+        let collection = self
+            .database
+            .collection::<mongodb::bson::Document>("audit_log");
+
+        let indexes = [
+            // Index on created_at for chronological ordering (newest first)
+            doc! { "created_at": -1 },
+            // Compound index for the history of one entity
+            doc! { "entity": 1, "entity_id": 1, "created_at": -1 },
+            // Index on actor for per-key queries
+            doc! { "actor": 1, "created_at": -1 },
+        ];
+
+        for keys in indexes {
+            let index = IndexModel::builder().keys(keys).build();
+            collection
+                .create_index(index)
+                .await
+                .map_err(|e| anyhow!("Failed to create audit log index: {}", e))?;
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,36 @@
+use crate::database::models::ApiKeyRecord;
+use crate::database::mongodb::MongoDbClient;
+use anyhow::Result;
+use bson::{doc, oid::ObjectId};
+use chrono::Utc;
+use std::sync::Arc;
+
+/// API key repository for MongoDB operations
+#[derive(Debug, Clone)]
+pub struct ApiKeyRepository {
+    client: Arc<MongoDbClient>,
+}
+
+impl ApiKeyRepository {
+    /// Create a new ApiKeyRepository instance
+    pub fn new(client: Arc<MongoDbClient>) -> Self {
+        Self { client }
+    }
+
+    /// Find an API key by the hash of the key
+    pub async fn find_by_hash(&self, key_hash: &str) -> Result<Option<ApiKeyRecord>> {
+        let collection = self.client.collection::<ApiKeyRecord>("api_keys");
+        let api_key = collection.find_one(doc! { "key_hash": key_hash }).await?;
+
+        Ok(api_key)
+    }
+
+    /// Record that an API key was just used
+    pub async fn touch(&self, id: &ObjectId) -> Result<()> {
+        let collection = self.client.collection::<ApiKeyRecord>("api_keys");
+        let update = doc! { "$set": { "last_used_at": Utc::now().timestamp() } };
+        collection.update_one(doc! { "_id": id }, update).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+use crate::database::models::IndexerCheckpoint;
+use crate::database::mongodb::MongoDbClient;
+use anyhow::Result;
+use bson::doc;
+use chrono::Utc;
+use futures::TryStreamExt;
+use log::debug;
+use std::sync::Arc;
+
+/// Checkpoint repository for MongoDB operations
+/// Keeps one checkpoint per (network_id, indexer) pair
+#[derive(Debug, Clone)]
+pub struct CheckpointRepository {
+    client: Arc<MongoDbClient>,
+}
+
+impl CheckpointRepository {
+    /// Create a new CheckpointRepository instance
+    pub fn new(client: Arc<MongoDbClient>) -> Self {
+        Self { client }
+    }
+
+    /// Find the checkpoint of an indexer on a network
+    pub async fn find(&self, network_id: u64, indexer: &str) -> Result<Option<IndexerCheckpoint>> {
+        let collection = self
+            .client
+            .collection::<IndexerCheckpoint>("indexer_checkpoints");
+        let filter = doc! {
+            "network_id": network_id as i64,
+            "indexer": indexer
+        };
+        let checkpoint = collection.find_one(filter).await?;
+
+        Ok(checkpoint)
+    }
+
+    /// Get the last processed block of an indexer on a network
+    pub async fn get_last_block(&self, network_id: u64, indexer: &str) -> Result<Option<u64>> {
+        Ok(self
+            .find(network_id, indexer)
+            .await?
+            .map(|checkpoint| checkpoint.last_block))
+    }
+
+    /// Find all checkpoints for a given network
+    pub async fn find_by_network_id(&self, network_id: u64) -> Result<Vec<IndexerCheckpoint>> {
+        let collection = self
+            .client
+            .collection::<IndexerCheckpoint>("indexer_checkpoints");
+        let filter = doc! { "network_id": network_id as i64 };
+        let checkpoints = collection
+            .find(filter)
+            .await?
+            .try_collect::<Vec<IndexerCheckpoint>>()
+            .await?;
+
+        Ok(checkpoints)
+    }
+
+    /// Find the checkpoints of several networks in one query
+    pub async fn find_by_network_ids(&self, network_ids: &[u64]) -> Result<Vec<IndexerCheckpoint>> {
+        let collection = self
+            .client
+            .collection::<IndexerCheckpoint>("indexer_checkpoints");
+        let network_ids: Vec<i64> = network_ids.iter().map(|id| *id as i64).collect();
+        let filter = doc! { "network_id": { "$in": network_ids } };
+        let checkpoints = collection
+            .find(filter)
+            .await?
+            .try_collect::<Vec<IndexerCheckpoint>>()
+            .await?;
+
+        Ok(checkpoints)
+    }
+
+    /// Find all checkpoints (across all networks)
+    pub async fn find_all(&self) -> Result<Vec<IndexerCheckpoint>> {
+        let collection = self
+            .client
+            .collection::<IndexerCheckpoint>("indexer_checkpoints");
+        let checkpoints = collection
+            .find(doc! {})
+            .await?
+            .try_collect::<Vec<IndexerCheckpoint>>()
+            .await?;
+
+        Ok(checkpoints)
+    }
+
+    /// Set the last processed block of an indexer on a network (insert if not exists)
+    ///
+    /// The checkpoint never moves backwards, a run finishing its batch after a newer run went
+    /// further leaves it as is.
+    pub async fn upsert(&self, network_id: u64, indexer: &str, last_block: u64) -> Result<()> {
+        let collection = self
+            .client
+            .collection::<IndexerCheckpoint>("indexer_checkpoints");
+        let now = Utc::now().timestamp();
+        let filter = doc! {
+            "network_id": network_id as i64,
+            "indexer": indexer
+        };
+        let update = doc! {
+            "$max": {
+                "last_block": last_block as i64
+            },
+            "$set": {
+                "updated_at": now
+            },
+            "$setOnInsert": {
+                "created_at": now
+            }
+        };
+
+        collection.update_one(filter, update).upsert(true).await?;
+        debug!(
+            "Updated {} checkpoint for network {} to block {}",
+            indexer, network_id, last_block
+        );
+
+        Ok(())
+    }
+
+    /// Delete all checkpoints for a given network
+    pub async fn delete_by_network_id(&self, network_id: u64) -> Result<u64> {
+        let collection = self
+            .client
+            .collection::<IndexerCheckpoint>("indexer_checkpoints");
+        let filter = doc! { "network_id": network_id as i64 };
+        let result = collection.delete_many(filter).await?;
+
+        Ok(result.deleted_count)
+    }
+}
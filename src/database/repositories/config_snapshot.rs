@@ -0,0 +1,175 @@
+use crate::database::models::{
+    ConfigSnapshot, ConfigSnapshotHeader, ConfigSnapshotRecord, ConfigSnapshotSummary,
+};
+use crate::database::mongodb::{is_duplicate_key_error, MongoDbClient};
+use anyhow::{anyhow, Result};
+use bson::doc;
+use futures::TryStreamExt;
+use log::{debug, info, warn};
+use std::sync::Arc;
+
+/// Attempts at taking the next version when snapshots are created concurrently
+const MAX_INSERT_ATTEMPTS: usize = 5;
+
+/// Records inserted per `insert_many` call
+const RECORD_BATCH_SIZE: usize = 10_000;
+
+/// Configuration snapshot repository for MongoDB operations
+///
+/// Headers are stored in `config_snapshots` and records in `config_snapshot_records`, see
+/// `ConfigSnapshot`. A snapshot is only returned once all its records are stored.
+#[derive(Debug, Clone)]
+pub struct ConfigSnapshotRepository {
+    client: Arc<MongoDbClient>,
+}
+
+impl ConfigSnapshotRepository {
+    /// Create a new ConfigSnapshotRepository instance
+    pub fn new(client: Arc<MongoDbClient>) -> Self {
+        Self { client }
+    }
+
+    /// Find the latest version taken, by a complete snapshot or one being stored
+    pub async fn find_latest_version(&self) -> Result<Option<u64>> {
+        let collection = self
+            .client
+            .collection::<ConfigSnapshotSummary>("config_snapshots");
+        let latest = collection
+            .find_one(doc! {})
+            .projection(Self::summary_projection())
+            .sort(doc! { "version": -1 })
+            .await?;
+
+        Ok(latest.map(|summary| summary.version))
+    }
+
+    /// Insert a snapshot under the next version
+    /// The unique index on version rejects a concurrent insert, which is retried with the next one
+    ///
+    /// The header is inserted first to take the version, then the records, then the header is
+    /// marked complete. If storing the records fails, what was stored is removed.
+    pub async fn insert(&self, mut snapshot: ConfigSnapshot) -> Result<ConfigSnapshot> {
+        let collection = self
+            .client
+            .collection::<ConfigSnapshotHeader>("config_snapshots");
+
+        for _ in 0..MAX_INSERT_ATTEMPTS {
+            snapshot.version = self.find_latest_version().await?.unwrap_or(0) + 1;
+            match collection.insert_one(snapshot.header()).await {
+                Ok(result) => {
+                    snapshot.id = result.inserted_id.as_object_id();
+                    if let Err(e) = self.insert_records(&snapshot).await {
+                        self.remove(snapshot.version).await;
+                        return Err(e);
+                    }
+                    info!("Inserted config snapshot version {}", snapshot.version);
+                    return Ok(snapshot);
+                }
+                Err(e) if is_duplicate_key_error(&e) => {
+                    debug!(
+                        "Config snapshot version {} already taken, retrying",
+                        snapshot.version
+                    );
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        Err(anyhow!(
+            "Failed to take a config snapshot version after {} attempts",
+            MAX_INSERT_ATTEMPTS
+        ))
+    }
+
+    /// Insert the records of a snapshot whose header is stored, then mark it complete
+    async fn insert_records(&self, snapshot: &ConfigSnapshot) -> Result<()> {
+        let records = snapshot.records()?;
+        let collection = self
+            .client
+            .collection::<ConfigSnapshotRecord>("config_snapshot_records");
+        for batch in records.chunks(RECORD_BATCH_SIZE) {
+            collection.insert_many(batch).await?;
+        }
+
+        self.client
+            .collection::<ConfigSnapshotHeader>("config_snapshots")
+            .update_one(
+                doc! { "version": snapshot.version as i64 },
+                doc! { "$set": { "complete": true } },
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Remove the header and records of a snapshot that failed to be stored
+    async fn remove(&self, version: u64) {
+        let filter = doc! { "version": version as i64 };
+        let records = self
+            .client
+            .collection::<ConfigSnapshotRecord>("config_snapshot_records")
+            .delete_many(filter.clone())
+            .await;
+        let header = self
+            .client
+            .collection::<ConfigSnapshotHeader>("config_snapshots")
+            .delete_one(filter)
+            .await;
+        if let Err(e) = records.and(header) {
+            warn!(
+                "Failed to remove incomplete config snapshot version {}: {}",
+                version, e
+            );
+        }
+    }
+
+    /// Find a complete snapshot by version, with its records
+    pub async fn find_by_version(&self, version: u64) -> Result<Option<ConfigSnapshot>> {
+        let header = self
+            .client
+            .collection::<ConfigSnapshotHeader>("config_snapshots")
+            .find_one(doc! { "version": version as i64, "complete": true })
+            .await?;
+        let Some(header) = header else {
+            return Ok(None);
+        };
+
+        let records: Vec<ConfigSnapshotRecord> = self
+            .client
+            .collection::<ConfigSnapshotRecord>("config_snapshot_records")
+            .find(doc! { "version": version as i64 })
+            .sort(doc! { "entity": 1, "position": 1 })
+            .await?
+            .try_collect()
+            .await?;
+
+        Ok(Some(ConfigSnapshot::from_parts(header, records)?))
+    }
+
+    /// Find the summaries of all complete snapshots, newest first
+    pub async fn find_summaries(&self) -> Result<Vec<ConfigSnapshotSummary>> {
+        let collection = self
+            .client
+            .collection::<ConfigSnapshotSummary>("config_snapshots");
+
+        Ok(collection
+            .find(doc! { "complete": true })
+            .projection(Self::summary_projection())
+            .sort(doc! { "version": -1 })
+            .await?
+            .try_collect()
+            .await?)
+    }
+
+    /// Projection of a header onto its summary, leaving out the config
+    fn summary_projection() -> bson::Document {
+        doc! {
+            "version": 1,
+            "description": 1,
+            "created_by": 1,
+            "created_at": 1,
+            "networks": 1,
+            "pools": 1,
+            "paths": 1
+        }
+    }
+}
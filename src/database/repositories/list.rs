@@ -0,0 +1,214 @@
+use anyhow::{anyhow, Result};
+use bson::{doc, oid::ObjectId, Bson, Document};
+use futures::TryStreamExt;
+use mongodb::Collection;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::database::repositories::pagination::{page_offset, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+
+/// Query parameters shared by the list endpoints
+///
+/// Pages are selected either by number (`page`) or by `cursor`, the `next_cursor` of the
+/// previous page. Cursors follow the default order and cannot be combined with `sort`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ListQuery {
+    pub limit: Option<u64>, // defaults to 50, at most 500
+    pub page: Option<u64>,  // starts at 1
+    pub cursor: Option<String>,
+    pub sort: Option<String>, // field name, prefixed with `-` for descending order
+    #[serde(default)]
+    pub include_deleted: bool,
+    pub updated_since: Option<u64>, // Unix timestamp in seconds, matched against `updated_at`
+}
+
+/// One page of documents with the total number of documents matching the filters
+#[derive(Debug, Clone, Serialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u64>, // absent when paginating by cursor
+    pub limit: u64,
+    pub next_cursor: Option<String>, // absent on the last page
+}
+
+impl<T> Page<T> {
+    /// Map the items of the page, keeping the pagination
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            total: self.total,
+            page: self.page,
+            limit: self.limit,
+            next_cursor: self.next_cursor,
+        }
+    }
+}
+
+impl ListQuery {
+    /// Page size, clamped to `MAX_PAGE_SIZE`
+    fn limit(&self) -> u64 {
+        self.limit
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE)
+    }
+
+    /// Sort document for the `sort` parameter, `_id` breaks ties
+    fn sort_document(&self, sort_fields: &[&str]) -> Result<Document> {
+        let Some(sort) = self.sort.as_deref().filter(|s| !s.is_empty()) else {
+            return Ok(doc! { "_id": 1 });
+        };
+
+        let (field, direction) = match sort.strip_prefix('-') {
+            Some(field) => (field, -1),
+            None => (sort, 1),
+        };
+        if field != "created_at" && field != "updated_at" && !sort_fields.contains(&field) {
+            return Err(anyhow!(
+                "Invalid sort field '{}': expected one of created_at, updated_at{}",
+                field,
+                sort_fields
+                    .iter()
+                    .map(|f| format!(", {}", f))
+                    .collect::<String>()
+            ));
+        }
+
+        Ok(doc! { field: direction, "_id": direction })
+    }
+
+    /// Add the `include_deleted` and `updated_since` conditions to `filter`
+    fn apply_filters(&self, filter: &mut Document) {
+        if !self.include_deleted {
+            // Matches both a null and a missing deleted_at
+            filter.insert("deleted_at", Bson::Null);
+        }
+        if let Some(updated_since) = self.updated_since {
+            filter.insert("updated_at", doc! { "$gt": updated_since as i64 });
+        }
+    }
+}
+
+/// Find one page of the documents of `collection` matching `filter` and the list query
+/// `sort_fields` are the fields accepted by `sort`, besides `created_at` and `updated_at`
+pub(crate) async fn find_page<T>(
+    collection: &Collection<T>,
+    mut filter: Document,
+    query: &ListQuery,
+    sort_fields: &[&str],
+) -> Result<Page<T>>
+where
+    T: DeserializeOwned + Send + Sync,
+{
+    let limit = query.limit();
+    let sort = query.sort_document(sort_fields)?;
+    query.apply_filters(&mut filter);
+
+    let collection = collection.clone_with_type::<Document>();
+    let total = collection.count_documents(filter.clone()).await?;
+
+    let (page, skip) = match (&query.cursor, query.page) {
+        (Some(_), Some(_)) => {
+            return Err(anyhow!(
+                "Invalid query: use either page or cursor, not both"
+            ));
+        }
+        (Some(_), None) if query.sort.as_deref().is_some_and(|s| !s.is_empty()) => {
+            return Err(anyhow!(
+                "Invalid query: cursor cannot be combined with sort"
+            ));
+        }
+        (Some(cursor), None) => {
+            let after = ObjectId::parse_str(cursor)
+                .map_err(|e| anyhow!("Invalid cursor '{}': {}", cursor, e))?;
+            filter.insert("_id", doc! { "$gt": after });
+            (None, 0)
+        }
+        (None, page) => {
+            let page = page.unwrap_or(1);
+            (Some(page), page_offset(page, limit)?)
+        }
+    };
+
+    let documents: Vec<Document> = collection
+        .find(filter)
+        .sort(sort)
+        .skip(skip)
+        .limit(limit as i64)
+        .await?
+        .try_collect()
+        .await?;
+
+    // Only offer a cursor while the default order is used and the page is full
+    let next_cursor = if query.sort.as_deref().is_some_and(|s| !s.is_empty())
+        || (documents.len() as u64) < limit
+    {
+        None
+    } else {
+        documents
+            .last()
+            .and_then(|document| document.get_object_id("_id").ok())
+            .map(|id| id.to_hex())
+    };
+
+    let items = documents
+        .into_iter()
+        .map(bson::from_document)
+        .collect::<Result<Vec<T>, _>>()?;
+
+    Ok(Page {
+        items,
+        total,
+        page,
+        limit,
+        next_cursor,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_query_sort_document() {
+        let query = ListQuery::default();
+        assert_eq!(query.sort_document(&[]).unwrap(), doc! { "_id": 1 });
+
+        let query = ListQuery {
+            sort: Some("-chain_id".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.sort_document(&["chain_id"]).unwrap(),
+            doc! { "chain_id": -1, "_id": -1 }
+        );
+        assert!(query.sort_document(&["name"]).is_err());
+    }
+
+    #[test]
+    fn test_list_query_filters() {
+        let mut filter = doc! { "network_id": 1_i64 };
+        ListQuery {
+            updated_since: Some(100),
+            ..Default::default()
+        }
+        .apply_filters(&mut filter);
+        assert_eq!(
+            filter,
+            doc! {
+                "network_id": 1_i64,
+                "deleted_at": null,
+                "updated_at": { "$gt": 100_i64 }
+            }
+        );
+
+        let mut filter = doc! {};
+        ListQuery {
+            include_deleted: true,
+            ..Default::default()
+        }
+        .apply_filters(&mut filter);
+        assert!(filter.is_empty());
+    }
+}
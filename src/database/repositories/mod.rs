@@ -1,11 +1,30 @@
+pub mod api_key;
+pub mod checkpoint;
 pub mod config;
+pub mod config_snapshot;
+pub mod list;
 pub mod network;
+pub mod opportunity;
+pub mod pagination;
 pub mod path;
 pub mod pool;
+pub mod price;
+pub mod profit_token;
+pub mod rpc_health;
+pub mod search;
 pub mod token;
 
+pub use api_key::ApiKeyRepository;
+pub use checkpoint::CheckpointRepository;
 pub use config::ConfigRepository;
+pub use config_snapshot::ConfigSnapshotRepository;
+pub use list::{ListQuery, Page};
 pub use network::NetworkRepository;
+pub use opportunity::OpportunityRepository;
+pub use pagination::{page_offset, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
 pub use path::PathRepository;
 pub use pool::PoolRepository;
+pub use price::PriceRepository;
+pub use profit_token::ProfitTokenRepository;
+pub use rpc_health::RpcHealthRepository;
 pub use token::TokenRepository;
@@ -1,5 +1,7 @@
 use crate::database::models::Network;
-use crate::database::mongodb::MongoDbClient;
+use crate::database::mongodb::{is_duplicate_key_error, MongoDbClient};
+use crate::database::repositories::list::{find_page, ListQuery, Page};
+use crate::database::repositories::search::{case_insensitive, prefix_range, search, PrefixFilter};
 use anyhow::Result;
 use bson::doc;
 use chrono::Utc;
@@ -32,8 +34,14 @@ impl NetworkRepository {
             return Ok(network.chain_id);
         }
 
-        // Insert new network
-        collection.insert_one(network.clone()).await?;
+        // Insert new network, the unique index rejects a concurrent insert of the same chain_id
+        if let Err(e) = collection.insert_one(network.clone()).await {
+            if is_duplicate_key_error(&e) {
+                debug!("Network with chain_id {} already exists", network.chain_id);
+                return Ok(network.chain_id);
+            }
+            return Err(e.into());
+        }
         info!(
             "Inserted new network: {} (chain_id: {})",
             network.name, network.chain_id
@@ -160,6 +168,31 @@ impl NetworkRepository {
         Ok(networks)
     }
 
+    /// Find one page of networks
+    pub async fn find_page(&self, query: &ListQuery) -> Result<Page<Network>> {
+        let collection = self.client.collection::<Network>("networks");
+        find_page(&collection, doc! {}, query, &["chain_id", "name"]).await
+    }
+
+    /// Search the network whose chain_id is `query`, then networks whose name starts with
+    /// `query` regardless of case, then networks whose name contains the words of `query`
+    pub async fn search(&self, query: &str, limit: u64) -> Result<Vec<Network>> {
+        let collection = self.client.collection::<Network>("networks");
+        let mut prefix_filters = Vec::new();
+        if let Ok(chain_id) = query.parse::<u64>() {
+            prefix_filters.push(PrefixFilter {
+                filter: doc! { "chain_id": chain_id as i64 },
+                collation: None,
+            });
+        }
+        prefix_filters.push(PrefixFilter {
+            filter: doc! { "name": prefix_range(query) },
+            collation: Some(case_insensitive()),
+        });
+
+        search(&collection, prefix_filters, Some(query), limit).await
+    }
+
     /// Find networks updated since the given timestamp
     /// Uses updated_at field to track when networks were last modified
     pub async fn find_updated_since(&self, since_timestamp: u64) -> Result<Vec<Network>> {
@@ -0,0 +1,26 @@
+use crate::database::models::Opportunity;
+use crate::database::mongodb::MongoDbClient;
+use anyhow::Result;
+use bson::{doc, oid::ObjectId};
+use std::sync::Arc;
+
+/// Opportunity repository for MongoDB operations
+#[derive(Debug, Clone)]
+pub struct OpportunityRepository {
+    client: Arc<MongoDbClient>,
+}
+
+impl OpportunityRepository {
+    /// Create a new OpportunityRepository instance
+    pub fn new(client: Arc<MongoDbClient>) -> Self {
+        Self { client }
+    }
+
+    /// Find an opportunity by id
+    pub async fn find_by_id(&self, id: &ObjectId) -> Result<Option<Opportunity>> {
+        let collection = self.client.collection::<Opportunity>("opportunities");
+        let opportunity = collection.find_one(doc! { "_id": id }).await?;
+
+        Ok(opportunity)
+    }
+}
@@ -0,0 +1,30 @@
+use anyhow::{anyhow, Result};
+
+/// Default number of documents per page
+pub const DEFAULT_PAGE_SIZE: u64 = 50;
+
+/// Maximum number of documents per page
+pub const MAX_PAGE_SIZE: u64 = 500;
+
+/// Number of documents to skip to reach `page` (starting at 1) with `limit` documents per page
+/// Fails on page 0 and on pages too far out to be addressed
+pub fn page_offset(page: u64, limit: u64) -> Result<u64> {
+    page.checked_sub(1)
+        .ok_or_else(|| anyhow!("Invalid page: pages start at 1"))?
+        .checked_mul(limit)
+        .ok_or_else(|| anyhow!("Invalid page: page {} is out of range", page))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_offset() {
+        assert_eq!(page_offset(1, 50).unwrap(), 0);
+        assert_eq!(page_offset(3, 50).unwrap(), 100);
+        assert!(page_offset(0, 50).is_err());
+        let error = page_offset(u64::MAX, 500).unwrap_err();
+        assert!(error.to_string().starts_with("Invalid page"));
+    }
+}
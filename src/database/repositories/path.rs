@@ -2,6 +2,7 @@ use crate::bot::models::path::PoolDirection;
 use crate::database::models::utils::address_to_string;
 use crate::database::models::Path;
 use crate::database::mongodb::MongoDbClient;
+use crate::database::repositories::list::{find_page, ListQuery, Page};
 use alloy::primitives::Address;
 use anyhow::Result;
 use bson::doc;
@@ -119,6 +120,31 @@ impl PathRepository {
         Ok(paths)
     }
 
+    /// Find one page of paths, optionally only those with a leg on `chain_id` and/or
+    /// anchored on `anchor_token` (both on the same leg when both are set)
+    pub async fn find_page(
+        &self,
+        chain_id: Option<u64>,
+        anchor_token: Option<&str>,
+        query: &ListQuery,
+    ) -> Result<Page<Path>> {
+        let collection = self.client.collection::<Path>("paths");
+        let mut leg = doc! {};
+        if let Some(chain_id) = chain_id {
+            leg.insert("chain_id", chain_id as i64);
+        }
+        if let Some(anchor_token) = anchor_token {
+            leg.insert("anchor_token", anchor_token);
+        }
+        let filter = if leg.is_empty() {
+            doc! {}
+        } else {
+            doc! { "paths": { "$elemMatch": leg } }
+        };
+
+        find_page(&collection, filter, query, &[]).await
+    }
+
     /// Update path chains
     pub async fn update_chains(
         &self,
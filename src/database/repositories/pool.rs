@@ -1,6 +1,8 @@
 use crate::database::models::pool::Pool;
 use crate::database::models::utils::address_to_string;
-use crate::database::mongodb::MongoDbClient;
+use crate::database::mongodb::{is_duplicate_key_error, MongoDbClient};
+use crate::database::repositories::list::{find_page, ListQuery, Page};
+use crate::database::repositories::search::{address_prefix_regex, search, PrefixFilter};
 use alloy::primitives::Address;
 use anyhow::Result;
 use bson::doc;
@@ -40,8 +42,14 @@ impl PoolRepository {
             return Ok((addr_str, false));
         }
 
-        // Insert new pool
-        collection.insert_one(pool.clone()).await?;
+        // Insert new pool, the unique index rejects a concurrent insert of the same pool
+        if let Err(e) = collection.insert_one(pool.clone()).await {
+            if is_duplicate_key_error(&e) {
+                debug!("Pool {} already exists", addr_str);
+                return Ok((addr_str, false));
+            }
+            return Err(e.into());
+        }
         info!(
             "Inserted new pool: {} (network_id: {})",
             addr_str, pool.network_id
@@ -85,8 +93,16 @@ impl PoolRepository {
     /// Bulk insert pools if they don't exist yet
     /// Returns (inserted_count, skipped_count)
     pub async fn bulk_insert_if_not_exists(&self, pools: Vec<Pool>) -> Result<(usize, usize)> {
+        let total = pools.len();
+        let inserted = self.insert_new(pools).await?.len();
+
+        Ok((inserted, total - inserted))
+    }
+
+    /// Insert the pools that do not exist yet and return their addresses
+    pub async fn insert_new(&self, pools: Vec<Pool>) -> Result<Vec<String>> {
         if pools.is_empty() {
-            return Ok((0, 0));
+            return Ok(Vec::new());
         }
 
         let collection = self.client.collection::<Pool>("pools");
@@ -113,20 +129,19 @@ impl PoolRepository {
             .collect();
 
         if to_insert.is_empty() {
-            let skipped = target_addresses.len();
-            return Ok((0, skipped));
+            return Ok(Vec::new());
         }
 
-        let result = collection.insert_many(to_insert).await?;
-        let inserted = result.inserted_ids.len();
-        let skipped = target_addresses.len().saturating_sub(inserted);
+        let inserted: Vec<String> = to_insert.iter().map(|p| p.address.clone()).collect();
+        collection.insert_many(to_insert).await?;
 
         debug!(
             "Bulk inserted {} pools, skipped {} (already exist)",
-            inserted, skipped
+            inserted.len(),
+            target_addresses.len() - inserted.len()
         );
 
-        Ok((inserted, skipped))
+        Ok(inserted)
     }
 
     /// Update pool's updated_at timestamp
@@ -213,6 +228,32 @@ impl PoolRepository {
         Ok(pools)
     }
 
+    /// Find one page of pools, of a single network if `network_id` is set
+    pub async fn find_page(
+        &self,
+        network_id: Option<u64>,
+        query: &ListQuery,
+    ) -> Result<Page<Pool>> {
+        let collection = self.client.collection::<Pool>("pools");
+        let mut filter = doc! {};
+        if let Some(network_id) = network_id {
+            filter.insert("network_id", network_id as i64);
+        }
+
+        find_page(&collection, filter, query, &["network_id", "address"]).await
+    }
+
+    /// Search pools whose address starts with `query`
+    pub async fn search(&self, query: &str, limit: u64) -> Result<Vec<Pool>> {
+        let collection = self.client.collection::<Pool>("pools");
+        let prefix_filters = vec![PrefixFilter {
+            filter: doc! { "address": address_prefix_regex(query) },
+            collation: None,
+        }];
+
+        search(&collection, prefix_filters, None, limit).await
+    }
+
     /// Find pools updated since the given timestamp
     /// Uses updated_at field, or created_at if updated_at is not set
     pub async fn find_updated_since(&self, since_timestamp: u64) -> Result<Vec<Pool>> {
@@ -0,0 +1,63 @@
+use crate::database::models::utils::address_to_string;
+use crate::database::models::PriceSnapshot;
+use crate::database::mongodb::MongoDbClient;
+use alloy::primitives::Address;
+use anyhow::Result;
+use bson::doc;
+use futures::TryStreamExt;
+use std::sync::Arc;
+
+/// Price snapshot repository for MongoDB operations
+#[derive(Debug, Clone)]
+pub struct PriceRepository {
+    client: Arc<MongoDbClient>,
+}
+
+impl PriceRepository {
+    /// Create a new PriceRepository instance
+    pub fn new(client: Arc<MongoDbClient>) -> Self {
+        Self { client }
+    }
+
+    /// Replace the snapshot of a token with a newer one
+    pub async fn upsert(&self, snapshot: &PriceSnapshot) -> Result<()> {
+        let collection = self.client.collection::<PriceSnapshot>("token_prices");
+        let filter = doc! {
+            "network_id": snapshot.network_id as i64,
+            "address": &snapshot.address
+        };
+        let update = doc! {
+            "$set": {
+                "price_usd": snapshot.price_usd,
+                "source": bson::to_bson(&snapshot.source)?,
+                "fetched_at": snapshot.fetched_at as i64,
+            }
+        };
+
+        collection.update_one(filter, update).upsert(true).await?;
+        Ok(())
+    }
+
+    /// Find the snapshots of a network
+    pub async fn find_by_network_id(&self, network_id: u64) -> Result<Vec<PriceSnapshot>> {
+        let collection = self.client.collection::<PriceSnapshot>("token_prices");
+        let filter = doc! { "network_id": network_id as i64 };
+
+        Ok(collection.find(filter).await?.try_collect().await?)
+    }
+
+    /// Find the snapshot of a token by network_id and address
+    pub async fn find_by_address(
+        &self,
+        network_id: u64,
+        address: &Address,
+    ) -> Result<Option<PriceSnapshot>> {
+        let collection = self.client.collection::<PriceSnapshot>("token_prices");
+        let filter = doc! {
+            "network_id": network_id as i64,
+            "address": address_to_string(address)
+        };
+
+        Ok(collection.find_one(filter).await?)
+    }
+}
@@ -0,0 +1,49 @@
+use crate::database::models::utils::address_to_string;
+use crate::database::models::ProfitTokenConfig;
+use crate::database::mongodb::MongoDbClient;
+use alloy::primitives::Address;
+use anyhow::Result;
+use bson::doc;
+use futures::TryStreamExt;
+use std::sync::Arc;
+
+/// Profit token repository for MongoDB operations
+#[derive(Debug, Clone)]
+pub struct ProfitTokenRepository {
+    client: Arc<MongoDbClient>,
+}
+
+impl ProfitTokenRepository {
+    /// Create a new ProfitTokenRepository instance
+    pub fn new(client: Arc<MongoDbClient>) -> Self {
+        Self { client }
+    }
+
+    /// Find the profit tokens of a network
+    pub async fn find_by_network_id(&self, network_id: u64) -> Result<Vec<ProfitTokenConfig>> {
+        let collection = self.client.collection::<ProfitTokenConfig>("profit_tokens");
+        let filter = doc! { "network_id": network_id as i64 };
+
+        Ok(collection
+            .find(filter)
+            .sort(doc! { "address": 1 })
+            .await?
+            .try_collect()
+            .await?)
+    }
+
+    /// Find a profit token by network_id and address
+    pub async fn find_by_address(
+        &self,
+        network_id: u64,
+        address: &Address,
+    ) -> Result<Option<ProfitTokenConfig>> {
+        let collection = self.client.collection::<ProfitTokenConfig>("profit_tokens");
+        let filter = doc! {
+            "network_id": network_id as i64,
+            "address": address_to_string(address)
+        };
+
+        Ok(collection.find_one(filter).await?)
+    }
+}
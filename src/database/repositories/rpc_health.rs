@@ -0,0 +1,64 @@
+use crate::database::models::RpcHealth;
+use crate::database::mongodb::MongoDbClient;
+use anyhow::Result;
+use bson::doc;
+use futures::TryStreamExt;
+use std::sync::Arc;
+
+/// RPC health repository for MongoDB operations
+#[derive(Debug, Clone)]
+pub struct RpcHealthRepository {
+    client: Arc<MongoDbClient>,
+}
+
+impl RpcHealthRepository {
+    /// Create a new RpcHealthRepository instance
+    pub fn new(client: Arc<MongoDbClient>) -> Self {
+        Self { client }
+    }
+
+    /// Replace the health of an endpoint with the result of a newer check
+    pub async fn upsert(&self, health: &RpcHealth) -> Result<()> {
+        let collection = self.client.collection::<RpcHealth>("rpc_health");
+        let filter = doc! {
+            "network_id": health.network_id as i64,
+            "url": &health.url
+        };
+        let update = doc! {
+            "$set": {
+                "kind": bson::to_bson(&health.kind)?,
+                "healthy": health.healthy,
+                "head_block": health.head_block.map(|block| block as i64),
+                "lag": health.lag.map(|lag| lag as i64),
+                "latency_ms": health.latency_ms.map(|latency| latency as i64),
+                "error": &health.error,
+                "recent": &health.recent,
+                "error_rate": health.error_rate,
+                "last_ok_at": health.last_ok_at.map(|at| at as i64),
+                "checked_at": health.checked_at as i64,
+            }
+        };
+
+        collection.update_one(filter, update).upsert(true).await?;
+        Ok(())
+    }
+
+    /// Find the health of the endpoints of a network
+    pub async fn find_by_network_id(&self, network_id: u64) -> Result<Vec<RpcHealth>> {
+        let collection = self.client.collection::<RpcHealth>("rpc_health");
+        let filter = doc! { "network_id": network_id as i64 };
+
+        Ok(collection.find(filter).await?.try_collect().await?)
+    }
+
+    /// Delete the health of the endpoints of a network that are no longer configured
+    pub async fn delete_other_urls(&self, network_id: u64, urls: &[String]) -> Result<u64> {
+        let collection = self.client.collection::<RpcHealth>("rpc_health");
+        let filter = doc! {
+            "network_id": network_id as i64,
+            "url": { "$nin": urls }
+        };
+
+        Ok(collection.delete_many(filter).await?.deleted_count)
+    }
+}
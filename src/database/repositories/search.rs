@@ -0,0 +1,136 @@
+use anyhow::Result;
+use bson::{doc, oid::ObjectId, Bson, Document, Regex};
+use futures::TryStreamExt;
+use mongodb::options::{Collation, CollationStrength};
+use mongodb::Collection;
+use serde::de::DeserializeOwned;
+use std::collections::HashSet;
+
+/// Collation comparing strings regardless of case
+/// The name and symbol indexes are created with it, so queries using it can be served by them
+pub(crate) fn case_insensitive() -> Collation {
+    Collation::builder()
+        .locale("en".to_string())
+        .strength(CollationStrength::Secondary)
+        .build()
+}
+
+/// Case-sensitive regex matching the addresses starting with `prefix`
+/// Addresses are stored in lowercase, so the prefix is lowercased and the regex stays
+/// anchored and case-sensitive, which lets MongoDB scan only the matching range of the index.
+pub(crate) fn address_prefix_regex(prefix: &str) -> Bson {
+    Bson::RegularExpression(Regex {
+        pattern: format!("^{}", regex::escape(&prefix.to_lowercase())),
+        options: String::new(),
+    })
+}
+
+/// Range of the strings starting with `prefix`
+/// Used with `case_insensitive()` to match names and symbols regardless of case on an index,
+/// which a case-insensitive regex cannot do. U+FFFF sorts after every character.
+pub(crate) fn prefix_range(prefix: &str) -> Document {
+    doc! { "$gte": prefix, "$lt": format!("{}\u{ffff}", prefix) }
+}
+
+/// Prefix condition of a search, with the collation its string comparisons need
+pub(crate) struct PrefixFilter {
+    pub filter: Document,
+    pub collation: Option<Collation>,
+}
+
+/// Search the documents of `collection` that are not soft-deleted
+///
+/// Documents matching `prefix_filters` come first, in the order of the filters, followed by
+/// the full-text matches of `text`, most relevant first, which requires a text index on the
+/// collection.
+pub(crate) async fn search<T>(
+    collection: &Collection<T>,
+    prefix_filters: Vec<PrefixFilter>,
+    text: Option<&str>,
+    limit: u64,
+) -> Result<Vec<T>>
+where
+    T: DeserializeOwned + Send + Sync,
+{
+    let collection = collection.clone_with_type::<Document>();
+    let mut documents: Vec<Document> = Vec::new();
+    let mut seen: HashSet<ObjectId> = HashSet::new();
+
+    for prefix_filter in prefix_filters {
+        if (documents.len() as u64) >= limit {
+            break;
+        }
+        let mut find = collection
+            .find(doc! { "$and": [prefix_filter.filter, { "deleted_at": null }] })
+            .sort(doc! { "_id": 1 })
+            .limit(limit as i64);
+        if let Some(collation) = prefix_filter.collation {
+            find = find.collation(collation);
+        }
+        let mut cursor = find.await?;
+        while let Some(document) = cursor.try_next().await? {
+            if (documents.len() as u64) >= limit {
+                break;
+            }
+            if let Ok(id) = document.get_object_id("_id") {
+                if seen.insert(id) {
+                    documents.push(document);
+                }
+            }
+        }
+    }
+
+    if let Some(text) = text.filter(|_| (documents.len() as u64) < limit) {
+        let mut cursor = collection
+            .find(doc! { "$text": { "$search": text }, "deleted_at": null })
+            .sort(doc! { "score": { "$meta": "textScore" } })
+            .limit(limit as i64)
+            .await?;
+
+        while let Some(document) = cursor.try_next().await? {
+            if (documents.len() as u64) >= limit {
+                break;
+            }
+            if let Ok(id) = document.get_object_id("_id") {
+                if seen.insert(id) {
+                    documents.push(document);
+                }
+            }
+        }
+    }
+
+    Ok(documents
+        .into_iter()
+        .map(bson::from_document)
+        .collect::<Result<Vec<T>, _>>()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::utils::address_to_string;
+    use alloy::primitives::address;
+
+    #[test]
+    fn test_address_prefix_regex() {
+        // The regex relies on addresses being stored in lowercase
+        assert_eq!(
+            address_to_string(&address!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48")),
+            "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+        );
+
+        let Bson::RegularExpression(regex) = address_prefix_regex("0xA0b8.") else {
+            panic!("expected a regular expression");
+        };
+        assert_eq!(regex.pattern, "^0xa0b8\\.");
+        assert_eq!(regex.options, "");
+    }
+
+    #[test]
+    fn test_prefix_range() {
+        assert_eq!(
+            prefix_range("USD"),
+            doc! { "$gte": "USD", "$lt": "USD\u{ffff}" }
+        );
+    }
+}
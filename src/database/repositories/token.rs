@@ -1,6 +1,10 @@
 use crate::database::models::token::Token;
 use crate::database::models::utils::address_to_string;
-use crate::database::mongodb::MongoDbClient;
+use crate::database::mongodb::{is_duplicate_key_error, MongoDbClient};
+use crate::database::repositories::list::{find_page, ListQuery, Page};
+use crate::database::repositories::search::{
+    address_prefix_regex, case_insensitive, prefix_range, search, PrefixFilter,
+};
 use alloy::primitives::Address;
 use anyhow::Result;
 use bson::doc;
@@ -72,8 +76,14 @@ impl TokenRepository {
             return Ok((addr_str, false));
         }
 
-        // Insert new token
-        collection.insert_one(token.clone()).await?;
+        // Insert new token, the unique index rejects a concurrent insert of the same token
+        if let Err(e) = collection.insert_one(token.clone()).await {
+            if is_duplicate_key_error(&e) {
+                debug!("Token {} already exists", addr_str);
+                return Ok((addr_str, false));
+            }
+            return Err(e.into());
+        }
         info!("Inserted new token: {}", addr_str);
 
         Ok((addr_str, true))
@@ -111,6 +121,100 @@ impl TokenRepository {
         Ok(tokens)
     }
 
+    /// Find the tokens of a network among the given addresses
+    pub async fn find_by_addresses(
+        &self,
+        network_id: u64,
+        addresses: &[Address],
+    ) -> Result<Vec<Token>> {
+        let collection = self.client.collection::<Token>("tokens");
+        let addr_strs: Vec<String> = addresses.iter().map(address_to_string).collect();
+        let filter = doc! {
+            "network_id": network_id as i64,
+            "address": { "$in": addr_strs }
+        };
+
+        Ok(collection.find(filter).await?.try_collect().await?)
+    }
+
+    /// Filter on the tokens of a network missing their name, symbol or decimals
+    fn missing_metadata_filter(network_id: u64) -> bson::Document {
+        doc! {
+            "network_id": network_id as i64,
+            "deleted_at": null,
+            "$or": [
+                { "name": null },
+                { "symbol": null },
+                { "decimals": null }
+            ]
+        }
+    }
+
+    /// Find up to `limit` tokens of a network missing their name, symbol or decimals
+    pub async fn find_missing_metadata(&self, network_id: u64, limit: u64) -> Result<Vec<Token>> {
+        let collection = self.client.collection::<Token>("tokens");
+
+        Ok(collection
+            .find(Self::missing_metadata_filter(network_id))
+            .sort(doc! { "_id": 1 })
+            .limit(limit as i64)
+            .await?
+            .try_collect()
+            .await?)
+    }
+
+    /// Count the tokens of a network missing their name, symbol or decimals
+    pub async fn count_missing_metadata(&self, network_id: u64) -> Result<u64> {
+        let collection = self.client.collection::<Token>("tokens");
+        Ok(collection
+            .count_documents(Self::missing_metadata_filter(network_id))
+            .await?)
+    }
+
+    /// Find one page of tokens, of a single network if `network_id` is set
+    pub async fn find_page(
+        &self,
+        network_id: Option<u64>,
+        query: &ListQuery,
+    ) -> Result<Page<Token>> {
+        let collection = self.client.collection::<Token>("tokens");
+        let mut filter = doc! {};
+        if let Some(network_id) = network_id {
+            filter.insert("network_id", network_id as i64);
+        }
+
+        find_page(
+            &collection,
+            filter,
+            query,
+            &["network_id", "address", "symbol"],
+        )
+        .await
+    }
+
+    /// Search tokens whose symbol or name starts with `query` regardless of case, then tokens
+    /// whose address starts with `query`, then tokens whose symbol or name contains its words
+    pub async fn search(&self, query: &str, limit: u64) -> Result<Vec<Token>> {
+        let collection = self.client.collection::<Token>("tokens");
+        let prefix_filters = vec![
+            PrefixFilter {
+                filter: doc! {
+                    "$or": [
+                        { "symbol": prefix_range(query) },
+                        { "name": prefix_range(query) }
+                    ]
+                },
+                collation: Some(case_insensitive()),
+            },
+            PrefixFilter {
+                filter: doc! { "address": address_prefix_regex(query) },
+                collation: None,
+            },
+        ];
+
+        search(&collection, prefix_filters, Some(query), limit).await
+    }
+
     /// Bulk insert tokens if they don't exist yet
     /// Returns (inserted_count, updated_count)
     pub async fn bulk_insert_or_update(&self, tokens: Vec<Token>) -> Result<(usize, usize)> {
@@ -260,4 +364,24 @@ impl TokenRepository {
 
         Ok(count)
     }
+
+    /// Find tokens updated since the given timestamp
+    /// Uses updated_at field, or created_at if updated_at is not set
+    pub async fn find_updated_since(&self, since_timestamp: u64) -> Result<Vec<Token>> {
+        let collection = self.client.collection::<Token>("tokens");
+        // Find documents where updated_at > since_timestamp OR (updated_at doesn't exist AND created_at > since_timestamp)
+        let filter = doc! {
+            "$or": [
+                { "updated_at": { "$gt": since_timestamp as i64 } },
+                {
+                    "$and": [
+                        { "updated_at": { "$exists": false } },
+                        { "created_at": { "$gt": since_timestamp as i64 } }
+                    ]
+                }
+            ]
+        };
+
+        Ok(collection.find(filter).await?.try_collect().await?)
+    }
 }
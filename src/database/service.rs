@@ -8,7 +8,10 @@ use super::mongodb::MongoDbClient;
 use super::repositories::{NetworkRepository, PathRepository, PoolRepository, TokenRepository};
 use crate::bot::models::path::SingleChainPathsWithAnchorToken;
 use crate::config::MongoDbConfig;
-use crate::database::repositories::ConfigRepository;
+use crate::database::repositories::{
+    ApiKeyRepository, CheckpointRepository, ConfigRepository, ConfigSnapshotRepository,
+    OpportunityRepository, PriceRepository, ProfitTokenRepository, RpcHealthRepository,
+};
 
 /// MongoDB service for managing database operations
 ///
@@ -28,6 +31,13 @@ pub struct MongoDbService {
     pool_repo: PoolRepository,
     path_repo: PathRepository,
     config_repo: ConfigRepository,
+    checkpoint_repo: CheckpointRepository,
+    opportunity_repo: OpportunityRepository,
+    api_key_repo: ApiKeyRepository,
+    profit_token_repo: ProfitTokenRepository,
+    price_repo: PriceRepository,
+    config_snapshot_repo: ConfigSnapshotRepository,
+    rpc_health_repo: RpcHealthRepository,
 }
 
 impl MongoDbService {
@@ -44,6 +54,13 @@ impl MongoDbService {
         let pool_repo = PoolRepository::new(client.clone());
         let path_repo = PathRepository::new(client.clone());
         let config_repo = ConfigRepository::new(client.clone());
+        let checkpoint_repo = CheckpointRepository::new(client.clone());
+        let opportunity_repo = OpportunityRepository::new(client.clone());
+        let api_key_repo = ApiKeyRepository::new(client.clone());
+        let profit_token_repo = ProfitTokenRepository::new(client.clone());
+        let price_repo = PriceRepository::new(client.clone());
+        let config_snapshot_repo = ConfigSnapshotRepository::new(client.clone());
+        let rpc_health_repo = RpcHealthRepository::new(client.clone());
 
         Ok(Self {
             _client: client,
@@ -52,6 +69,13 @@ impl MongoDbService {
             pool_repo,
             path_repo,
             config_repo,
+            checkpoint_repo,
+            opportunity_repo,
+            api_key_repo,
+            profit_token_repo,
+            price_repo,
+            config_snapshot_repo,
+            rpc_health_repo,
         })
     }
 
@@ -88,6 +112,41 @@ impl MongoDbService {
         &self.config_repo
     }
 
+    /// Get indexer checkpoint repository for advanced operations
+    pub fn get_checkpoint_repo(&self) -> &CheckpointRepository {
+        &self.checkpoint_repo
+    }
+
+    /// Get opportunity repository for advanced operations
+    pub fn get_opportunity_repo(&self) -> &OpportunityRepository {
+        &self.opportunity_repo
+    }
+
+    /// Get API key repository for advanced operations
+    pub fn get_api_key_repo(&self) -> &ApiKeyRepository {
+        &self.api_key_repo
+    }
+
+    /// Get profit token repository for advanced operations
+    pub fn get_profit_token_repo(&self) -> &ProfitTokenRepository {
+        &self.profit_token_repo
+    }
+
+    /// Get price snapshot repository for advanced operations
+    pub fn get_price_repo(&self) -> &PriceRepository {
+        &self.price_repo
+    }
+
+    /// Get config snapshot repository for advanced operations
+    pub fn get_config_snapshot_repo(&self) -> &ConfigSnapshotRepository {
+        &self.config_snapshot_repo
+    }
+
+    /// Get RPC health repository for advanced operations
+    pub fn get_rpc_health_repo(&self) -> &RpcHealthRepository {
+        &self.rpc_health_repo
+    }
+
     /// Find network by chain ID
     pub async fn find_network(&self, chain_id: u64) -> Result<Option<Network>> {
         self.network_repo.find_by_chain_id(chain_id).await
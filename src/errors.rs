@@ -19,6 +19,12 @@ pub enum ApiError {
 
     #[display(fmt = "Unauthorized: {}", _0)]
     Unauthorized(String),
+
+    #[display(fmt = "Forbidden: {}", _0)]
+    Forbidden(String),
+
+    #[display(fmt = "Upstream error: {}", _0)]
+    UpstreamError(String), // an RPC node or other upstream service failed
 }
 
 impl From<MongoError> for ApiError {
@@ -55,6 +61,12 @@ impl ResponseError for ApiError {
             ApiError::Unauthorized(ref message) => HttpResponse::Unauthorized().json(json!({
                 "error": message
             })),
+            ApiError::Forbidden(ref message) => HttpResponse::Forbidden().json(json!({
+                "error": message
+            })),
+            ApiError::UpstreamError(ref message) => HttpResponse::BadGateway().json(json!({
+                "error": message
+            })),
         }
     }
 }
@@ -0,0 +1,80 @@
+use actix_web::{web, HttpResponse};
+use log::{error, info};
+use mongodb::Database;
+
+use crate::{
+    errors::ApiError,
+    handlers::analytics::{
+        dto::{ProfitQuery, TopPathsQuery},
+        service::AnalyticsService,
+    },
+};
+
+/// GET /analytics/profit - Returns profit rollups of opportunities
+///
+/// # Arguments
+/// * `db` - Database connection
+/// * `query` - Query parameters with the grouping (source_chain, target_chain, path, hour, day, week) and filters
+///
+/// # Returns
+/// JSON array of ProfitGroupResponse objects
+pub async fn get_profit_handler(
+    db: web::Data<Database>,
+    query: web::Query<ProfitQuery>,
+) -> Result<HttpResponse, ApiError> {
+    info!("Handling GET /analytics/profit request");
+
+    match AnalyticsService::get_profit(&db, query.into_inner()).await {
+        Ok(groups) => {
+            info!(
+                "Successfully aggregated profit into {} groups",
+                groups.len()
+            );
+            Ok(HttpResponse::Ok().json(groups))
+        }
+        Err(e) => {
+            error!("Failed to aggregate profit: {}", e);
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to aggregate profit: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
+
+/// GET /analytics/top-paths - Returns the most profitable paths
+///
+/// # Arguments
+/// * `db` - Database connection
+/// * `query` - Query parameters with the filters and number of paths
+///
+/// # Returns
+/// JSON array of TopPathResponse objects, highest total profit first
+pub async fn get_top_paths_handler(
+    db: web::Data<Database>,
+    query: web::Query<TopPathsQuery>,
+) -> Result<HttpResponse, ApiError> {
+    info!("Handling GET /analytics/top-paths request");
+
+    match AnalyticsService::get_top_paths(&db, query.into_inner()).await {
+        Ok(paths) => {
+            info!("Successfully retrieved {} top paths", paths.len());
+            Ok(HttpResponse::Ok().json(paths))
+        }
+        Err(e) => {
+            error!("Failed to retrieve top paths: {}", e);
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to retrieve top paths: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
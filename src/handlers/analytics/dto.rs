@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+/// Response model for one group of opportunities in a profit rollup
+/// Only the field matching the requested grouping is set
+#[derive(Debug, Serialize)]
+pub struct ProfitGroupResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_chain_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_chain_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bucket_start: Option<u64>, // Unix timestamp of the start of the time bucket
+    pub count: u64,
+    pub succeeded: u64,
+    pub success_rate: f64, // succeeded / count
+    pub total_profit_usd: f64,
+    pub average_profit_usd: Option<f64>, // over opportunities with a realized profit
+    pub total_gas_usd: f64,
+}
+
+/// Response model for a path in the most profitable paths ranking
+#[derive(Debug, Serialize)]
+pub struct TopPathResponse {
+    pub path_id: String,
+    pub source_chain_id: u64,
+    pub target_chain_id: u64,
+    pub anchor_token: String,
+    pub count: u64,
+    pub succeeded: u64,
+    pub success_rate: f64,
+    pub total_profit_usd: f64,
+    pub average_profit_usd: Option<f64>,
+    pub total_gas_usd: f64,
+}
+
+/// Query parameters for profit rollups
+/// `group_by` is one of source_chain, target_chain, path, hour, day or week
+/// `from`/`to` are Unix timestamps in seconds, matched against `created_at`
+#[derive(Debug, Deserialize)]
+pub struct ProfitQuery {
+    pub group_by: String,
+    pub source_chain_id: Option<u64>,
+    pub target_chain_id: Option<u64>,
+    pub path_id: Option<String>,
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+}
+
+/// Query parameters for the most profitable paths ranking
+#[derive(Debug, Deserialize)]
+pub struct TopPathsQuery {
+    pub source_chain_id: Option<u64>,
+    pub target_chain_id: Option<u64>,
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+    pub limit: Option<u64>, // defaults to 10, at most 100
+}
@@ -0,0 +1,6 @@
+pub mod analytics;
+pub mod dto;
+pub mod service;
+
+pub use analytics::*;
+pub use dto::*;
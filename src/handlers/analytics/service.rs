@@ -0,0 +1,344 @@
+use futures::TryStreamExt;
+use log::debug;
+use mongodb::bson::{doc, Bson, Document};
+use mongodb::Database;
+
+use crate::{
+    database::models::{Opportunity, OpportunityFilter, OpportunityStatus},
+    handlers::analytics::dto::{ProfitGroupResponse, ProfitQuery, TopPathResponse, TopPathsQuery},
+};
+
+/// Default number of paths in the top paths ranking
+const DEFAULT_TOP_PATHS: u64 = 10;
+
+/// Maximum number of paths in the top paths ranking
+const MAX_TOP_PATHS: u64 = 100;
+
+/// Seconds in an hour, a day and a week
+const HOUR: i64 = 3_600;
+const DAY: i64 = 86_400;
+const WEEK: i64 = 604_800;
+
+/// Offset of the first Monday after the Unix epoch (a Thursday), so weeks start on Monday
+const WEEK_OFFSET: i64 = 4 * DAY;
+
+/// Field the opportunities are grouped by in a profit rollup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GroupBy {
+    SourceChain,
+    TargetChain,
+    Path,
+    Hour,
+    Day,
+    Week,
+}
+
+impl GroupBy {
+    fn parse(s: &str) -> anyhow::Result<Self> {
+        match s.to_lowercase().as_str() {
+            "source_chain" => Ok(Self::SourceChain),
+            "target_chain" => Ok(Self::TargetChain),
+            "path" => Ok(Self::Path),
+            "hour" => Ok(Self::Hour),
+            "day" => Ok(Self::Day),
+            "week" => Ok(Self::Week),
+            _ => Err(anyhow::anyhow!(
+                "Invalid group_by '{}': expected source_chain, target_chain, path, hour, day or week",
+                s
+            )),
+        }
+    }
+
+    /// Expression computing the group key of an opportunity
+    fn key_expression(&self) -> Bson {
+        match self {
+            Self::SourceChain => Bson::String("$source_chain_id".to_string()),
+            Self::TargetChain => Bson::String("$target_chain_id".to_string()),
+            Self::Path => Bson::String("$path_id".to_string()),
+            Self::Hour => Self::bucket_expression(HOUR, 0),
+            Self::Day => Self::bucket_expression(DAY, 0),
+            Self::Week => Self::bucket_expression(WEEK, WEEK_OFFSET),
+        }
+    }
+
+    /// `created_at` rounded down to the start of its bucket
+    fn bucket_expression(size: i64, offset: i64) -> Bson {
+        Bson::Document(doc! {
+            "$subtract": [
+                "$created_at",
+                { "$mod": [{ "$subtract": ["$created_at", offset] }, size] }
+            ]
+        })
+    }
+
+    fn is_time_bucket(&self) -> bool {
+        matches!(self, Self::Hour | Self::Day | Self::Week)
+    }
+}
+
+/// Service layer for opportunity analytics
+pub struct AnalyticsService;
+
+impl AnalyticsService {
+    /// Build the `$match` stage shared by all rollups
+    fn build_match(filter: &OpportunityFilter) -> anyhow::Result<Document> {
+        Ok(doc! { "$match": filter.to_document()? })
+    }
+
+    /// Accumulators shared by all `$group` stages
+    fn group_accumulators(key: Bson) -> Document {
+        doc! {
+            "_id": key,
+            "count": { "$sum": 1 },
+            "succeeded": {
+                "$sum": {
+                    "$cond": [{ "$eq": ["$status", OpportunityStatus::Succeeded.as_str()] }, 1, 0]
+                }
+            },
+            "total_profit_usd": { "$sum": { "$ifNull": ["$profit_usd", 0.0] } },
+            "average_profit_usd": { "$avg": "$profit_usd" },
+            "total_gas_usd": { "$sum": { "$ifNull": ["$gas_usd", 0.0] } },
+        }
+    }
+
+    /// Read a numeric aggregation result, whatever BSON number type MongoDB picked
+    fn get_f64(document: &Document, key: &str) -> Option<f64> {
+        match document.get(key)? {
+            Bson::Double(v) => Some(*v),
+            Bson::Int32(v) => Some(*v as f64),
+            Bson::Int64(v) => Some(*v as f64),
+            _ => None,
+        }
+    }
+
+    fn get_u64(document: &Document, key: &str) -> Option<u64> {
+        match document.get(key)? {
+            Bson::Int32(v) => Some(*v as u64),
+            Bson::Int64(v) => Some(*v as u64),
+            Bson::Double(v) => Some(*v as u64),
+            _ => None,
+        }
+    }
+
+    fn success_rate(count: u64, succeeded: u64) -> f64 {
+        if count == 0 {
+            0.0
+        } else {
+            succeeded as f64 / count as f64
+        }
+    }
+
+    /// Get profit rollups of opportunities grouped by chain, path or time bucket
+    /// Time buckets are returned oldest first, other groupings by total profit, highest first
+    ///
+    /// # Arguments
+    /// * `db` - Database reference
+    /// * `query` - Grouping and filters
+    ///
+    /// # Returns
+    /// * `Ok(Vec<ProfitGroupResponse>)` - One entry per group
+    /// * `Err(anyhow::Error)` - Error if the query is invalid or the aggregation fails
+    pub async fn get_profit(
+        db: &Database,
+        query: ProfitQuery,
+    ) -> anyhow::Result<Vec<ProfitGroupResponse>> {
+        debug!("Aggregating profit with query: {:?}", query);
+
+        let group_by = GroupBy::parse(&query.group_by)?;
+        let sort = if group_by.is_time_bucket() {
+            doc! { "_id": 1 }
+        } else {
+            doc! { "total_profit_usd": -1, "_id": 1 }
+        };
+        let pipeline = vec![
+            Self::build_match(&OpportunityFilter {
+                source_chain_id: query.source_chain_id,
+                target_chain_id: query.target_chain_id,
+                path_id: query.path_id.as_deref(),
+                from: query.from,
+                to: query.to,
+                ..Default::default()
+            })?,
+            doc! { "$group": Self::group_accumulators(group_by.key_expression()) },
+            doc! { "$sort": sort },
+        ];
+
+        let collection = db.collection::<Opportunity>("opportunities");
+        let mut cursor = collection.aggregate(pipeline).await?;
+        let mut groups = Vec::new();
+
+        while let Some(document) = cursor.try_next().await? {
+            let count = Self::get_u64(&document, "count").unwrap_or(0);
+            let succeeded = Self::get_u64(&document, "succeeded").unwrap_or(0);
+            let mut group = ProfitGroupResponse {
+                source_chain_id: None,
+                target_chain_id: None,
+                path_id: None,
+                bucket_start: None,
+                count,
+                succeeded,
+                success_rate: Self::success_rate(count, succeeded),
+                total_profit_usd: Self::get_f64(&document, "total_profit_usd").unwrap_or(0.0),
+                average_profit_usd: Self::get_f64(&document, "average_profit_usd"),
+                total_gas_usd: Self::get_f64(&document, "total_gas_usd").unwrap_or(0.0),
+            };
+            match group_by {
+                GroupBy::SourceChain => group.source_chain_id = Self::get_u64(&document, "_id"),
+                GroupBy::TargetChain => group.target_chain_id = Self::get_u64(&document, "_id"),
+                GroupBy::Path => {
+                    group.path_id = document.get_str("_id").ok().map(|s| s.to_string())
+                }
+                GroupBy::Hour | GroupBy::Day | GroupBy::Week => {
+                    group.bucket_start = Self::get_u64(&document, "_id")
+                }
+            }
+            groups.push(group);
+        }
+
+        debug!("Aggregated profit into {} groups", groups.len());
+        Ok(groups)
+    }
+
+    /// Get the most profitable paths by total realized profit
+    ///
+    /// # Arguments
+    /// * `db` - Database reference
+    /// * `query` - Filters and number of paths
+    ///
+    /// # Returns
+    /// * `Ok(Vec<TopPathResponse>)` - Paths, highest total profit first
+    /// * `Err(anyhow::Error)` - Error if the query is invalid or the aggregation fails
+    pub async fn get_top_paths(
+        db: &Database,
+        query: TopPathsQuery,
+    ) -> anyhow::Result<Vec<TopPathResponse>> {
+        debug!("Aggregating top paths with query: {:?}", query);
+
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_TOP_PATHS)
+            .clamp(1, MAX_TOP_PATHS);
+
+        let mut match_stage = Self::build_match(&OpportunityFilter {
+            source_chain_id: query.source_chain_id,
+            target_chain_id: query.target_chain_id,
+            from: query.from,
+            to: query.to,
+            ..Default::default()
+        })?;
+        // Opportunities recorded without a path cannot be ranked
+        match_stage
+            .get_document_mut("$match")?
+            .insert("path_id", doc! { "$ne": Bson::Null });
+
+        let mut group = Self::group_accumulators(Bson::String("$path_id".to_string()));
+        group.insert("source_chain_id", doc! { "$first": "$source_chain_id" });
+        group.insert("target_chain_id", doc! { "$first": "$target_chain_id" });
+        group.insert("anchor_token", doc! { "$first": "$anchor_token" });
+
+        let pipeline = vec![
+            match_stage,
+            doc! { "$group": group },
+            doc! { "$sort": { "total_profit_usd": -1, "_id": 1 } },
+            doc! { "$limit": limit as i64 },
+        ];
+
+        let collection = db.collection::<Opportunity>("opportunities");
+        let mut cursor = collection.aggregate(pipeline).await?;
+        let mut paths = Vec::new();
+
+        while let Some(document) = cursor.try_next().await? {
+            let count = Self::get_u64(&document, "count").unwrap_or(0);
+            let succeeded = Self::get_u64(&document, "succeeded").unwrap_or(0);
+            paths.push(TopPathResponse {
+                path_id: document.get_str("_id").unwrap_or_default().to_string(),
+                source_chain_id: Self::get_u64(&document, "source_chain_id").unwrap_or(0),
+                target_chain_id: Self::get_u64(&document, "target_chain_id").unwrap_or(0),
+                anchor_token: document
+                    .get_str("anchor_token")
+                    .unwrap_or_default()
+                    .to_string(),
+                count,
+                succeeded,
+                success_rate: Self::success_rate(count, succeeded),
+                total_profit_usd: Self::get_f64(&document, "total_profit_usd").unwrap_or(0.0),
+                average_profit_usd: Self::get_f64(&document, "average_profit_usd"),
+                total_gas_usd: Self::get_f64(&document, "total_gas_usd").unwrap_or(0.0),
+            });
+        }
+
+        debug!("Retrieved {} top paths", paths.len());
+        Ok(paths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_by_parse() {
+        assert_eq!(GroupBy::parse("Day").unwrap(), GroupBy::Day);
+        assert_eq!(GroupBy::parse("path").unwrap(), GroupBy::Path);
+        assert!(GroupBy::parse("month").is_err());
+        assert!(GroupBy::Week.is_time_bucket());
+        assert!(!GroupBy::SourceChain.is_time_bucket());
+    }
+
+    #[test]
+    fn test_group_keys() {
+        let bucket = |size: i64, offset: i64| {
+            Bson::Document(doc! {
+                "$subtract": [
+                    "$created_at",
+                    { "$mod": [{ "$subtract": ["$created_at", offset] }, size] }
+                ]
+            })
+        };
+        // Weeks start on Monday, 4 days after the Unix epoch
+        for (group_by, key) in [
+            (
+                GroupBy::SourceChain,
+                Bson::String("$source_chain_id".to_string()),
+            ),
+            (
+                GroupBy::TargetChain,
+                Bson::String("$target_chain_id".to_string()),
+            ),
+            (GroupBy::Path, Bson::String("$path_id".to_string())),
+            (GroupBy::Hour, bucket(3_600, 0)),
+            (GroupBy::Day, bucket(86_400, 0)),
+            (GroupBy::Week, bucket(604_800, 345_600)),
+        ] {
+            let group = AnalyticsService::group_accumulators(group_by.key_expression());
+            assert_eq!(group.get("_id"), Some(&key), "{:?}", group_by);
+        }
+    }
+
+    #[test]
+    fn test_build_match() {
+        let filter = OpportunityFilter {
+            source_chain_id: Some(1),
+            path_id: Some("path-1"),
+            from: Some(100),
+            ..Default::default()
+        };
+        assert_eq!(
+            AnalyticsService::build_match(&filter).unwrap(),
+            doc! {
+                "$match": {
+                    "source_chain_id": 1_i64,
+                    "path_id": "path-1",
+                    "created_at": { "$gte": 100_i64 },
+                }
+            }
+        );
+
+        let filter = OpportunityFilter {
+            from: Some(300),
+            to: Some(200),
+            ..Default::default()
+        };
+        assert!(AnalyticsService::build_match(&filter).is_err());
+    }
+}
@@ -0,0 +1,130 @@
+use actix_web::{web, HttpResponse};
+use log::{error, info};
+use mongodb::bson::oid::ObjectId;
+use mongodb::Database;
+
+use crate::{
+    errors::ApiError,
+    handlers::{
+        api_key::{dto::CreateApiKeyRequest, service::ApiKeyService},
+        config::auth::AdminApiKey,
+    },
+};
+
+/// GET /api-keys - Returns all API keys (without the keys themselves)
+/// Requires an API key with the admin scope via X-API-Key header
+///
+/// # Returns
+/// JSON array of ApiKeyResponse objects
+pub async fn get_api_keys_handler(
+    _api_key: AdminApiKey,
+    db: web::Data<Database>,
+) -> Result<HttpResponse, ApiError> {
+    info!("Handling GET /api-keys request");
+
+    match ApiKeyService::get_api_keys(&db).await {
+        Ok(api_keys) => {
+            info!("Successfully retrieved {} API keys", api_keys.len());
+            Ok(HttpResponse::Ok().json(api_keys))
+        }
+        Err(e) => {
+            error!("Failed to retrieve API keys: {}", e);
+            Err(ApiError::DatabaseError(format!(
+                "Failed to retrieve API keys: {}",
+                e
+            )))
+        }
+    }
+}
+
+/// POST /api-keys - Creates an API key
+/// Requires an API key with the admin scope via X-API-Key header
+///
+/// # Arguments
+/// * `api_key` - Admin API key from X-API-Key header (validated by extractor)
+/// * `db` - Database connection
+/// * `body` - CreateApiKeyRequest containing name, scopes and expiry
+///
+/// # Returns
+/// JSON object of CreatedApiKeyResponse, the only response that contains the key
+pub async fn create_api_key_handler(
+    api_key: AdminApiKey,
+    db: web::Data<Database>,
+    body: web::Json<CreateApiKeyRequest>,
+) -> Result<HttpResponse, ApiError> {
+    info!("Handling POST /api-keys request");
+
+    match ApiKeyService::create_api_key(&db, body.into_inner()).await {
+        Ok(created) => {
+            info!(
+                "API key '{}' ({}) created by '{}'",
+                created.api_key.name, created.api_key.id, api_key.0.name
+            );
+            Ok(HttpResponse::Created().json(created))
+        }
+        Err(e) => {
+            error!("Failed to create API key: {}", e);
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to create API key: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
+
+/// DELETE /api-keys/{id} - Revokes an API key
+/// Requires an API key with the admin scope via X-API-Key header
+///
+/// # Arguments
+/// * `api_key` - Admin API key from X-API-Key header (validated by extractor)
+/// * `db` - Database connection
+/// * `path` - Path parameters containing id
+///
+/// # Returns
+/// JSON object of ApiKeyResponse containing the revoked API key
+pub async fn revoke_api_key_handler(
+    api_key: AdminApiKey,
+    db: web::Data<Database>,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let id_str = path.into_inner();
+    info!("Handling DELETE /api-keys/{} request", id_str);
+
+    let id = match ObjectId::parse_str(&id_str) {
+        Ok(id) => id,
+        Err(e) => {
+            error!("Invalid ObjectId format: {}", e);
+            return Err(ApiError::BadRequest(format!("Invalid ID format: {}", e)));
+        }
+    };
+
+    match ApiKeyService::revoke_api_key(&db, &id).await {
+        Ok(revoked) => {
+            info!(
+                "API key '{}' ({}) revoked by '{}'",
+                revoked.name, id_str, api_key.0.name
+            );
+            Ok(HttpResponse::Ok().json(revoked))
+        }
+        Err(e) => {
+            error!("Failed to revoke API key {}: {}", id_str, e);
+            if e.to_string().contains("not found") {
+                Err(ApiError::NotFound(format!(
+                    "API key with id {} not found",
+                    id_str
+                )))
+            } else if e.to_string().contains("already revoked") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to revoke API key: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use crate::database::models::ApiKeyScope;
+
+/// Response model for API key endpoints, never contains the key itself
+#[derive(Debug, Serialize)]
+pub struct ApiKeyResponse {
+    pub id: String, // MongoDB ObjectId as string
+    pub name: String,
+    pub key_prefix: String,
+    pub scopes: Vec<ApiKeyScope>,
+    pub active: bool, // neither revoked nor expired
+    pub expires_at: Option<u64>,
+    pub revoked_at: Option<u64>,
+    pub last_used_at: Option<u64>,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+/// Response model for a newly created API key
+/// `key` is only ever returned here, only its hash is stored
+#[derive(Debug, Serialize)]
+pub struct CreatedApiKeyResponse {
+    #[serde(flatten)]
+    pub api_key: ApiKeyResponse,
+    pub key: String,
+}
+
+/// Request model for creating an API key
+#[derive(Debug, Deserialize)]
+pub struct CreateApiKeyRequest {
+    pub name: String,
+    pub scopes: Vec<ApiKeyScope>,
+    pub expires_at: Option<u64>, // Unix timestamp in seconds, never expires if absent
+}
@@ -0,0 +1,6 @@
+pub mod api_key;
+pub mod dto;
+pub mod service;
+
+pub use api_key::*;
+pub use dto::*;
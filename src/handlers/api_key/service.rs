@@ -0,0 +1,168 @@
+use chrono::Utc;
+use futures::TryStreamExt;
+use log::debug;
+use mongodb::bson::{doc, oid::ObjectId};
+use mongodb::Database;
+use rand::RngCore;
+
+use crate::{
+    database::models::ApiKeyRecord,
+    handlers::api_key::dto::{ApiKeyResponse, CreateApiKeyRequest, CreatedApiKeyResponse},
+};
+
+/// Number of random bytes in a generated API key
+const API_KEY_BYTES: usize = 32;
+
+/// Service layer for API key management
+pub struct ApiKeyService;
+
+impl ApiKeyService {
+    /// Generate a new random API key (hex encoded)
+    fn generate_key() -> String {
+        let mut bytes = [0u8; API_KEY_BYTES];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        hex::encode(bytes)
+    }
+
+    /// Get all API keys, newest first
+    ///
+    /// # Arguments
+    /// * `db` - Database reference
+    ///
+    /// # Returns
+    /// * `Ok(Vec<ApiKeyResponse>)` - List of API keys
+    /// * `Err(anyhow::Error)` - Error if database operation fails
+    pub async fn get_api_keys(db: &Database) -> anyhow::Result<Vec<ApiKeyResponse>> {
+        debug!("Fetching all API keys");
+
+        let collection = db.collection::<ApiKeyRecord>("api_keys");
+        let mut cursor = collection
+            .find(doc! {})
+            .sort(doc! { "created_at": -1 })
+            .await?;
+        let mut api_keys = Vec::new();
+
+        while let Some(api_key) = cursor.try_next().await? {
+            api_keys.push(Self::map_to_response(api_key));
+        }
+
+        debug!("Retrieved {} API keys from database", api_keys.len());
+        Ok(api_keys)
+    }
+
+    /// Create an API key
+    ///
+    /// # Arguments
+    /// * `db` - Database reference
+    /// * `request` - CreateApiKeyRequest containing name, scopes and expiry
+    ///
+    /// # Returns
+    /// * `Ok(CreatedApiKeyResponse)` - Created API key, including the key itself
+    /// * `Err(anyhow::Error)` - Error if validation or database operation fails
+    pub async fn create_api_key(
+        db: &Database,
+        request: CreateApiKeyRequest,
+    ) -> anyhow::Result<CreatedApiKeyResponse> {
+        debug!("Creating API key '{}'", request.name);
+
+        let name = request.name.trim().to_string();
+        if name.is_empty() {
+            return Err(anyhow::anyhow!("Invalid name: must not be empty"));
+        }
+        if request.scopes.is_empty() {
+            return Err(anyhow::anyhow!("Invalid scopes: at least one is required"));
+        }
+        if let Some(expires_at) = request.expires_at {
+            if expires_at <= Utc::now().timestamp() as u64 {
+                return Err(anyhow::anyhow!(
+                    "Invalid expires_at: {} is in the past",
+                    expires_at
+                ));
+            }
+        }
+
+        let mut scopes = request.scopes;
+        scopes.sort_by_key(|scope| scope.as_str());
+        scopes.dedup();
+
+        let key = Self::generate_key();
+        let api_key = ApiKeyRecord::new(name, &key, scopes, request.expires_at);
+
+        let collection = db.collection::<ApiKeyRecord>("api_keys");
+        let result = collection.insert_one(&api_key).await?;
+        let id = result.inserted_id.as_object_id().unwrap();
+
+        let created = collection.find_one(doc! { "_id": id }).await?.unwrap();
+
+        debug!("API key created successfully with id: {}", id);
+        Ok(CreatedApiKeyResponse {
+            api_key: Self::map_to_response(created),
+            key,
+        })
+    }
+
+    /// Revoke an API key, it can no longer authenticate
+    ///
+    /// # Arguments
+    /// * `db` - Database reference
+    /// * `id` - The ObjectId of the API key to revoke
+    ///
+    /// # Returns
+    /// * `Ok(ApiKeyResponse)` - Revoked API key
+    /// * `Err(anyhow::Error)` - Error if database operation fails, key not found or already revoked
+    pub async fn revoke_api_key(db: &Database, id: &ObjectId) -> anyhow::Result<ApiKeyResponse> {
+        debug!("Revoking API key with id: {}", id);
+
+        let collection = db.collection::<ApiKeyRecord>("api_keys");
+        let filter = doc! { "_id": id };
+
+        let existing = collection
+            .find_one(filter.clone())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("API key with id {} not found", id))?;
+        if existing.revoked_at.is_some() {
+            return Err(anyhow::anyhow!("API key with id {} is already revoked", id));
+        }
+
+        let now = Utc::now().timestamp();
+        collection
+            .update_one(
+                filter.clone(),
+                doc! { "$set": { "revoked_at": now, "updated_at": now } },
+            )
+            .await?;
+
+        let api_key = collection.find_one(filter).await?.unwrap();
+
+        debug!("API key revoked successfully: {}", id);
+        Ok(Self::map_to_response(api_key))
+    }
+
+    /// Map ApiKeyRecord model to ApiKeyResponse DTO
+    ///
+    /// # Arguments
+    /// * `api_key` - ApiKeyRecord model from database
+    ///
+    /// # Returns
+    /// ApiKeyResponse DTO
+    fn map_to_response(api_key: ApiKeyRecord) -> ApiKeyResponse {
+        let id = api_key
+            .id
+            .map(|oid| oid.to_hex())
+            .unwrap_or_else(|| "unknown".to_string());
+        let active = api_key.is_active(Utc::now().timestamp() as u64);
+
+        ApiKeyResponse {
+            id,
+            name: api_key.name,
+            key_prefix: api_key.key_prefix,
+            scopes: api_key.scopes,
+            active,
+            expires_at: api_key.expires_at,
+            revoked_at: api_key.revoked_at,
+            last_used_at: api_key.last_used_at,
+            created_at: api_key.created_at,
+            updated_at: api_key.updated_at,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use actix_web::{web, HttpResponse};
+use log::{error, info};
+use mongodb::Database;
+
+use crate::{
+    errors::ApiError,
+    handlers::audit::{dto::AuditQuery, service::AuditService},
+};
+
+/// GET /audit - Returns a page of the audit log of configuration changes, newest first
+///
+/// # Arguments
+/// * `db` - Database connection
+/// * `query` - Query parameters with the filters (actor, action, entity, time window) and pagination
+///
+/// # Returns
+/// JSON object of AuditListResponse
+pub async fn get_audit_log_handler(
+    db: web::Data<Database>,
+    query: web::Query<AuditQuery>,
+) -> Result<HttpResponse, ApiError> {
+    info!("Handling GET /audit request");
+
+    match AuditService::get_audit_entries(&db, query.into_inner()).await {
+        Ok(audit_log) => {
+            info!(
+                "Successfully retrieved {} of {} audit log entries",
+                audit_log.entries.len(),
+                audit_log.total
+            );
+            Ok(HttpResponse::Ok().json(audit_log))
+        }
+        Err(e) => {
+            error!("Failed to retrieve audit log: {}", e);
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to retrieve audit log: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use crate::database::models::{AuditAction, AuditEntity, FieldChange};
+
+/// Response model for audit log endpoints
+#[derive(Debug, Serialize)]
+pub struct AuditEntryResponse {
+    pub id: String, // MongoDB ObjectId as string
+    pub actor: String,
+    pub action: AuditAction,
+    pub entity: AuditEntity,
+    pub entity_id: String,
+    pub changes: Vec<FieldChange>,
+    pub created_at: u64,
+}
+
+/// Response model for a page of audit log entries
+#[derive(Debug, Serialize)]
+pub struct AuditListResponse {
+    pub entries: Vec<AuditEntryResponse>,
+    pub total: u64, // number of entries matching the filters
+    pub page: u64,
+    pub limit: u64,
+}
+
+/// Query parameters for listing audit log entries
+/// `from`/`to` are Unix timestamps in seconds, matched against `created_at`
+#[derive(Debug, Deserialize)]
+pub struct AuditQuery {
+    pub actor: Option<String>,
+    pub action: Option<String>,
+    pub entity: Option<String>,
+    pub entity_id: Option<String>,
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+    pub page: Option<u64>,  // starts at 1
+    pub limit: Option<u64>, // defaults to 50, at most 500
+}
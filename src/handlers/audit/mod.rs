@@ -0,0 +1,6 @@
+pub mod audit;
+pub mod dto;
+pub mod service;
+
+pub use audit::*;
+pub use dto::*;
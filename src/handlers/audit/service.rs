@@ -0,0 +1,198 @@
+use futures::TryStreamExt;
+use log::{debug, error};
+use mongodb::bson::{doc, Document};
+use mongodb::Database;
+use serde::Serialize;
+use std::str::FromStr;
+
+use crate::{
+    database::changes::{Record, RecordChange},
+    database::models::{diff_fields, AuditAction, AuditEntity, AuditEntry},
+    database::repositories::{page_offset, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE},
+    handlers::audit::dto::{AuditEntryResponse, AuditListResponse, AuditQuery},
+    services::{Event, EventBus},
+};
+
+/// Service layer for the audit log of configuration changes
+pub struct AuditService;
+
+impl AuditService {
+    /// Record a change made by `actor`, with the diff between `before` and `after`
+    /// `before` is `None` for a creation and `after` is `None` for a hard delete
+    ///
+    /// The change is already applied when this is called, so a failure to write the
+    /// entry is logged instead of failing the request. The change is also published to
+    /// the WebSocket clients.
+    pub async fn record<T: Serialize>(
+        db: &Database,
+        actor: &str,
+        action: AuditAction,
+        entity: AuditEntity,
+        entity_id: &str,
+        before: Option<&T>,
+        after: Option<&T>,
+    ) {
+        let changes = match (serde_json::to_value(before), serde_json::to_value(after)) {
+            (Ok(before), Ok(after)) => {
+                EventBus::global().publish(Event::from_change(
+                    action, entity, entity_id, &before, &after,
+                ));
+                diff_fields(&before, &after)
+            }
+            (Err(e), _) | (_, Err(e)) => {
+                error!(
+                    "Failed to serialize {} {} for the audit log: {}",
+                    entity, entity_id, e
+                );
+                return;
+            }
+        };
+
+        let entry = AuditEntry::new(
+            actor.to_string(),
+            action,
+            entity,
+            entity_id.to_string(),
+            changes,
+        );
+        match db
+            .collection::<AuditEntry>("audit_log")
+            .insert_one(&entry)
+            .await
+        {
+            Ok(_) => debug!("Audit: '{}' {} {} {}", actor, action, entity, entity_id),
+            Err(e) => error!(
+                "Failed to record {} of {} {} by '{}' in the audit log: {}",
+                action, entity, entity_id, actor, e
+            ),
+        }
+    }
+
+    /// Record changes made with `apply_changes`, one entry per change
+    pub async fn record_changes<T: Record>(
+        db: &Database,
+        actor: &str,
+        changes: &[RecordChange<T>],
+    ) {
+        for change in changes {
+            Self::record(
+                db,
+                actor,
+                change.action,
+                T::ENTITY,
+                &change.after.audit_id(),
+                change.before.as_ref(),
+                Some(&change.after),
+            )
+            .await;
+        }
+    }
+
+    /// Build the MongoDB filter matching the query parameters
+    fn build_filter(query: &AuditQuery) -> anyhow::Result<Document> {
+        let mut filter = Document::new();
+
+        if let Some(actor) = &query.actor {
+            filter.insert("actor", actor);
+        }
+        if let Some(action) = &query.action {
+            filter.insert("action", AuditAction::from_str(action)?.as_str());
+        }
+        if let Some(entity) = &query.entity {
+            filter.insert("entity", AuditEntity::from_str(entity)?.as_str());
+        }
+        if let Some(entity_id) = &query.entity_id {
+            filter.insert("entity_id", entity_id);
+        }
+
+        if let (Some(from), Some(to)) = (query.from, query.to) {
+            if from > to {
+                return Err(anyhow::anyhow!(
+                    "Invalid time window: from {} is after to {}",
+                    from,
+                    to
+                ));
+            }
+        }
+        let mut created_at = Document::new();
+        if let Some(from) = query.from {
+            created_at.insert("$gte", from as i64);
+        }
+        if let Some(to) = query.to {
+            created_at.insert("$lte", to as i64);
+        }
+        if !created_at.is_empty() {
+            filter.insert("created_at", created_at);
+        }
+
+        Ok(filter)
+    }
+
+    /// Get a page of audit log entries, newest first
+    ///
+    /// # Arguments
+    /// * `db` - Database reference
+    /// * `query` - Filters and pagination
+    ///
+    /// # Returns
+    /// * `Ok(AuditListResponse)` - Page of entries with the total count
+    /// * `Err(anyhow::Error)` - Error if the query is invalid or the database operation fails
+    pub async fn get_audit_entries(
+        db: &Database,
+        query: AuditQuery,
+    ) -> anyhow::Result<AuditListResponse> {
+        debug!("Fetching audit log with query: {:?}", query);
+
+        let page = query.page.unwrap_or(1);
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE);
+        let skip = page_offset(page, limit)?;
+        let filter = Self::build_filter(&query)?;
+
+        let collection = db.collection::<AuditEntry>("audit_log");
+        let total = collection.count_documents(filter.clone()).await?;
+        let mut cursor = collection
+            .find(filter)
+            .sort(doc! { "created_at": -1, "_id": -1 })
+            .skip(skip)
+            .limit(limit as i64)
+            .await?;
+        let mut entries = Vec::new();
+
+        while let Some(entry) = cursor.try_next().await? {
+            entries.push(Self::map_to_response(entry));
+        }
+
+        debug!(
+            "Retrieved {} of {} audit log entries from database",
+            entries.len(),
+            total
+        );
+        Ok(AuditListResponse {
+            entries,
+            total,
+            page,
+            limit,
+        })
+    }
+
+    /// Map AuditEntry model to AuditEntryResponse DTO
+    fn map_to_response(entry: AuditEntry) -> AuditEntryResponse {
+        let id = entry
+            .id
+            .map(|oid| oid.to_hex())
+            .unwrap_or_else(|| "unknown".to_string());
+
+        AuditEntryResponse {
+            id,
+            actor: entry.actor,
+            action: entry.action,
+            entity: entry.entity,
+            entity_id: entry.entity_id,
+            changes: entry.changes,
+            created_at: entry.created_at,
+        }
+    }
+}
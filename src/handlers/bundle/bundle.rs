@@ -0,0 +1,107 @@
+use crate::{
+    database::MongoDbService,
+    errors::ApiError,
+    handlers::{
+        bundle::{
+            dto::{ConfigBundle, ImportQuery},
+            service::BundleService,
+        },
+        config::auth::ApiKey,
+    },
+};
+use actix_web::{web, HttpResponse};
+use log::{error, info};
+use mongodb::Database;
+
+/// GET /export - Returns the active networks, tokens, pools and paths and the config as a bundle
+///
+/// # Arguments
+/// * `db` - Database connection
+///
+/// # Returns
+/// JSON object of ConfigBundle, to be imported into another environment with POST /import
+pub async fn export_handler(db: web::Data<Database>) -> Result<HttpResponse, ApiError> {
+    info!("Handling GET /export request");
+
+    match BundleService::export_bundle(&db).await {
+        Ok(bundle) => {
+            info!(
+                "Successfully exported {} networks, {} tokens, {} pools and {} paths",
+                bundle.networks.len(),
+                bundle.tokens.len(),
+                bundle.pools.len(),
+                bundle.paths.len()
+            );
+            Ok(HttpResponse::Ok().json(bundle))
+        }
+        Err(e) => {
+            error!("Failed to export config bundle: {}", e);
+            Err(ApiError::DatabaseError(format!(
+                "Failed to export config bundle: {}",
+                e
+            )))
+        }
+    }
+}
+
+/// POST /import - Imports a bundle exported with GET /export
+/// Requires API key authentication via X-API-Key header
+///
+/// # Arguments
+/// * `api_key` - API key from X-API-Key header (validated by extractor), recorded in the audit log
+/// * `db_service` - MongoDB service
+/// * `db` - Database connection
+/// * `query` - Query parameters: mode (merge or replace) and dry_run
+/// * `body` - ConfigBundle to import
+///
+/// # Returns
+/// JSON object of ImportResponse with the records created, updated, deleted and skipped,
+/// with a 500 status when the import was only partly applied
+pub async fn import_handler(
+    api_key: ApiKey,
+    db_service: web::Data<MongoDbService>,
+    db: web::Data<Database>,
+    query: web::Query<ImportQuery>,
+    body: web::Json<ConfigBundle>,
+) -> Result<HttpResponse, ApiError> {
+    info!("Handling POST /import request");
+
+    match BundleService::import_bundle(
+        &db_service,
+        &db,
+        &api_key.name,
+        body.into_inner(),
+        query.into_inner(),
+    )
+    .await
+    {
+        Ok(import) if import.partial => {
+            error!(
+                "Partly imported config bundle in {:?} mode: {}",
+                import.mode,
+                import.error.as_deref().unwrap_or_default()
+            );
+            Ok(HttpResponse::InternalServerError().json(import))
+        }
+        Ok(import) => {
+            info!(
+                "Successfully imported config bundle in {:?} mode (dry_run: {})",
+                import.mode, import.dry_run
+            );
+            Ok(HttpResponse::Ok().json(import))
+        }
+        Err(e) => {
+            error!("Failed to import config bundle: {}", e);
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else if e.to_string().contains("RPC request failed") {
+                Err(ApiError::UpstreamError(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to import config bundle: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+
+use crate::handlers::{network::dto::CreateNetworkRequest, path::dto::CreatePathRequest};
+
+/// Token of a config bundle
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BundleToken {
+    pub network_id: u64,
+    pub address: String,
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub decimals: Option<u8>,
+    #[serde(default)]
+    pub blacklisted: bool,
+    #[serde(default)]
+    pub profit_token: bool,
+}
+
+/// Pool of a config bundle
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BundlePool {
+    pub network_id: u64,
+    pub address: String,
+}
+
+/// Config of a config bundle
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BundleConfig {
+    pub max_amount_usd: f64,
+    pub recheck_interval: u64,
+}
+
+/// Self-contained copy of the active networks, tokens, pools and paths and of the config,
+/// exported from one environment and imported into another
+/// Records are identified by chain_id or network_id and address, paths by their legs
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConfigBundle {
+    pub version: u32, // bundle format version
+    #[serde(default)]
+    pub exported_at: u64,
+    #[serde(default)]
+    pub networks: Vec<CreateNetworkRequest>,
+    #[serde(default)]
+    pub tokens: Vec<BundleToken>,
+    #[serde(default)]
+    pub pools: Vec<BundlePool>,
+    #[serde(default)]
+    pub paths: Vec<CreatePathRequest>,
+    pub config: Option<BundleConfig>,
+}
+
+/// How an import treats the records missing from the bundle
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportMode {
+    #[default]
+    Merge, // left as they are
+    Replace, // soft-deleted
+}
+
+/// Query parameters for POST /import
+#[derive(Debug, Deserialize)]
+pub struct ImportQuery {
+    #[serde(default)]
+    pub mode: ImportMode,
+    #[serde(default)]
+    pub dry_run: bool, // report the changes without making them
+}
+
+/// Changes made to one kind of record by an import
+/// Ids are the chain_id of networks, network_id:address of tokens and pools, and for paths
+/// the position in the bundle (created) or the ObjectId (deleted)
+/// Pools created for the paths of the bundle are listed with the pools
+#[derive(Debug, Default, Serialize)]
+pub struct ImportReport {
+    pub created: Vec<String>, // restored when soft-deleted
+    pub updated: Vec<String>,
+    pub deleted: Vec<String>, // replace mode only
+    pub skipped: usize,       // already up to date
+}
+
+/// Response model for POST /import
+/// When `partial`, the import failed midway and the reports are those of the changes made
+#[derive(Debug, Serialize)]
+pub struct ImportResponse {
+    pub mode: ImportMode,
+    pub dry_run: bool,
+    pub transactional: bool, // changes made in a transaction, all or none
+    pub partial: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>, // why a partial import stopped
+    pub networks: ImportReport,
+    pub tokens: ImportReport,
+    pub pools: ImportReport,
+    pub paths: ImportReport,
+    pub config: ImportReport, // id "config"
+}
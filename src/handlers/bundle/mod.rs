@@ -0,0 +1,6 @@
+pub mod bundle;
+pub mod dto;
+pub mod service;
+
+pub use bundle::*;
+pub use dto::*;
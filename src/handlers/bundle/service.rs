@@ -0,0 +1,1009 @@
+use alloy::primitives::Address;
+use chrono::Utc;
+use futures::{future::join_all, TryStreamExt};
+use log::debug;
+use mongodb::bson::{doc, oid::ObjectId, Document};
+use mongodb::{ClientSession, Database};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::str::FromStr;
+
+use crate::{
+    database::changes::{apply_changes, RecordChange, Stamped},
+    database::models::{utils::address_to_string, AuditAction, Config, Network, Path, Pool, Token},
+    database::MongoDbService,
+    handlers::{
+        audit::service::AuditService,
+        bundle::dto::{
+            BundleConfig, BundlePool, BundleToken, ConfigBundle, ImportMode, ImportQuery,
+            ImportReport, ImportResponse,
+        },
+        network::{
+            dto::{CreateNetworkRequest, NetworkResponse},
+            service::NetworkService,
+        },
+        path::{dto::CreatePathRequest, service::PathService},
+        pool::service::PoolService,
+        token::service::TokenService,
+    },
+};
+
+/// Version of the config bundle format written by exports and accepted by imports
+pub const BUNDLE_VERSION: u32 = 1;
+
+/// Stored records an import is planned against, soft-deleted ones included
+struct StoredConfig {
+    networks: BTreeMap<u64, Network>,
+    tokens: BTreeMap<(u64, String), Token>,
+    pools: BTreeMap<(u64, String), Pool>,
+    paths: Vec<Path>,
+    config: Option<Config>,
+}
+
+impl StoredConfig {
+    async fn load(db: &Database) -> anyhow::Result<Self> {
+        Ok(Self {
+            networks: BundleService::load_all::<Network>(db, "networks")
+                .await?
+                .into_iter()
+                .map(|network| (network.chain_id, network))
+                .collect(),
+            tokens: BundleService::load_all::<Token>(db, "tokens")
+                .await?
+                .into_iter()
+                .map(|token| ((token.network_id, token.address.clone()), token))
+                .collect(),
+            pools: BundleService::load_all::<Pool>(db, "pools")
+                .await?
+                .into_iter()
+                .map(|pool| ((pool.network_id, pool.address.clone()), pool))
+                .collect(),
+            paths: BundleService::load_all(db, "paths").await?,
+            config: db.collection::<Config>("configs").find_one(doc! {}).await?,
+        })
+    }
+}
+
+/// Changes an import makes to one kind of record, with the id each one is reported under
+struct ImportChanges<T> {
+    ids: Vec<String>,
+    changes: Vec<RecordChange<T>>,
+    skipped: usize,
+}
+
+impl<T> Default for ImportChanges<T> {
+    fn default() -> Self {
+        Self {
+            ids: Vec::new(),
+            changes: Vec::new(),
+            skipped: 0,
+        }
+    }
+}
+
+impl<T> ImportChanges<T> {
+    fn push(&mut self, id: String, change: RecordChange<T>) {
+        self.ids.push(id);
+        self.changes.push(change);
+    }
+
+    /// Order the changes as they are made: creates, then restores and updates, then deletes
+    fn sort(&mut self) {
+        let rank = |action: AuditAction| match action {
+            AuditAction::Create => 0,
+            AuditAction::Delete => 2,
+            _ => 1,
+        };
+        let mut changes: Vec<(String, RecordChange<T>)> =
+            self.ids.drain(..).zip(self.changes.drain(..)).collect();
+        changes.sort_by_key(|(_, change)| rank(change.action));
+        (self.ids, self.changes) = changes.into_iter().unzip();
+    }
+
+    /// Changes before the deletes, and the deletes
+    fn split(&self) -> (&[RecordChange<T>], &[RecordChange<T>]) {
+        let deletes = self
+            .changes
+            .iter()
+            .position(|change| change.action == AuditAction::Delete)
+            .unwrap_or(self.changes.len());
+        self.changes.split_at(deletes)
+    }
+
+    /// Report of the first `applied` changes
+    fn report(&self, applied: usize) -> ImportReport {
+        let mut report = ImportReport {
+            skipped: self.skipped,
+            ..ImportReport::default()
+        };
+        for (id, change) in self.ids.iter().zip(&self.changes).take(applied) {
+            let ids = match change.action {
+                AuditAction::Update => &mut report.updated,
+                AuditAction::Delete => &mut report.deleted,
+                _ => &mut report.created,
+            };
+            ids.push(id.clone());
+        }
+        report
+    }
+}
+
+/// Changes an import makes, planned before the first write
+#[derive(Default)]
+struct ImportPlan {
+    networks: ImportChanges<Network>,
+    tokens: ImportChanges<Token>,
+    pools: ImportChanges<Pool>,
+    paths: ImportChanges<Path>,
+    config: ImportChanges<Config>,
+}
+
+impl ImportPlan {
+    fn sort(&mut self) {
+        self.networks.sort();
+        self.tokens.sort();
+        self.pools.sort();
+        self.paths.sort();
+    }
+}
+
+/// Number of changes of each kind made so far
+#[derive(Debug, Default)]
+struct ImportProgress {
+    networks: usize,
+    tokens: usize,
+    pools: usize,
+    paths: usize,
+    config: usize,
+}
+
+impl ImportProgress {
+    fn is_empty(&self) -> bool {
+        self.networks + self.tokens + self.pools + self.paths + self.config == 0
+    }
+}
+
+/// Service layer for moving the bot configuration between environments
+pub struct BundleService;
+
+impl BundleService {
+    /// Filter on the records that are not soft-deleted
+    fn active() -> Document {
+        doc! { "deleted_at": null }
+    }
+
+    /// Load every record of a collection, soft-deleted ones included
+    async fn load_all<T: DeserializeOwned + Send + Sync>(
+        db: &Database,
+        collection: &str,
+    ) -> anyhow::Result<Vec<T>> {
+        Ok(db
+            .collection::<T>(collection)
+            .find(doc! {})
+            .await?
+            .try_collect()
+            .await?)
+    }
+
+    /// Whether a field of `record` has another value in `existing`
+    /// Fields of `record` that are null are left out of the comparison when `ignore_null` is set
+    fn differs<A: Serialize, B: Serialize>(
+        existing: &A,
+        record: &B,
+        ignore_null: bool,
+    ) -> anyhow::Result<bool> {
+        let existing = serde_json::to_value(existing)?;
+        let record = serde_json::to_value(record)?;
+
+        Ok(record
+            .as_object()
+            .into_iter()
+            .flatten()
+            .any(|(field, value)| {
+                !(ignore_null && value.is_null())
+                    && existing.get(field).unwrap_or(&Value::Null) != value
+            }))
+    }
+
+    /// Export the active networks, tokens, pools and paths and the config as a bundle
+    ///
+    /// # Arguments
+    /// * `db` - Database reference
+    ///
+    /// # Returns
+    /// * `Ok(ConfigBundle)` - Bundle of the current configuration
+    /// * `Err(anyhow::Error)` - Error if database operation fails
+    pub async fn export_bundle(db: &Database) -> anyhow::Result<ConfigBundle> {
+        debug!("Exporting config bundle");
+
+        let networks: Vec<Network> = db
+            .collection::<Network>("networks")
+            .find(Self::active())
+            .sort(doc! { "chain_id": 1 })
+            .await?
+            .try_collect()
+            .await?;
+        let tokens: Vec<Token> = db
+            .collection::<Token>("tokens")
+            .find(Self::active())
+            .sort(doc! { "network_id": 1, "address": 1 })
+            .await?
+            .try_collect()
+            .await?;
+        let pools: Vec<BundlePool> = db
+            .collection::<BundlePool>("pools")
+            .find(Self::active())
+            .projection(doc! { "_id": 0, "network_id": 1, "address": 1 })
+            .sort(doc! { "network_id": 1, "address": 1 })
+            .await?
+            .try_collect()
+            .await?;
+        let paths: Vec<Path> = db
+            .collection::<Path>("paths")
+            .find(Self::active())
+            .sort(doc! { "_id": 1 })
+            .await?
+            .try_collect()
+            .await?;
+        let config = db.collection::<Config>("configs").find_one(doc! {}).await?;
+
+        Ok(ConfigBundle {
+            version: BUNDLE_VERSION,
+            exported_at: Utc::now().timestamp() as u64,
+            networks: networks
+                .into_iter()
+                .map(|network| CreateNetworkRequest {
+                    chain_id: network.chain_id,
+                    name: network.name,
+                    rpcs: network.rpcs,
+                    websocket_urls: network.websocket_urls,
+                    block_explorer: network.block_explorer,
+                    wrap_native: network.wrap_native,
+                    min_profit_usd: network.min_profit_usd,
+                    v2_factory_to_fee: network.v2_factory_to_fee,
+                    aero_factory_addresses: network.aero_factory_addresses,
+                    multicall_address: network.multicall_address,
+                    price_network: network.price_network,
+                    max_blocks_per_batch: network.max_blocks_per_batch,
+                    wait_time_fetch: network.wait_time_fetch,
+                })
+                .collect(),
+            tokens: tokens
+                .into_iter()
+                .map(|token| BundleToken {
+                    network_id: token.network_id,
+                    address: token.address,
+                    name: token.name,
+                    symbol: token.symbol,
+                    decimals: token.decimals,
+                    blacklisted: token.blacklisted,
+                    profit_token: token.profit_token,
+                })
+                .collect(),
+            pools,
+            paths: paths
+                .into_iter()
+                .map(|path| CreatePathRequest { paths: path.paths })
+                .collect(),
+            config: config.map(|config| BundleConfig {
+                max_amount_usd: config.max_amount_usd,
+                recheck_interval: config.recheck_interval,
+            }),
+        })
+    }
+
+    /// Validate a bundle before anything is written, normalizing token and pool addresses
+    /// Every problem is collected so the bundle can be fixed in one go
+    ///
+    /// A network referenced by a token, pool or path must be in the bundle, or in merge mode
+    /// an active network of the database.
+    fn validate_bundle(
+        bundle: &mut ConfigBundle,
+        networks: &BTreeMap<u64, Network>,
+        mode: ImportMode,
+    ) -> anyhow::Result<()> {
+        let mut errors = Vec::new();
+
+        if bundle.version != BUNDLE_VERSION {
+            errors.push(format!(
+                "unsupported version {}, expected {}",
+                bundle.version, BUNDLE_VERSION
+            ));
+        }
+
+        let mut chain_ids = HashSet::new();
+        for network in &bundle.networks {
+            if !chain_ids.insert(network.chain_id) {
+                errors.push(format!("network {} is listed twice", network.chain_id));
+            }
+            if let Err(e) = NetworkService::validate_network_addresses(network) {
+                errors.push(format!("network {}: {}", network.chain_id, e));
+            }
+        }
+        let known_network = |network_id: u64| {
+            chain_ids.contains(&network_id)
+                || (mode == ImportMode::Merge
+                    && networks
+                        .get(&network_id)
+                        .is_some_and(|network| network.deleted_at.is_none()))
+        };
+
+        let mut token_keys = HashSet::new();
+        for token in &mut bundle.tokens {
+            let id = format!("{}:{}", token.network_id, token.address);
+            match TokenService::validate_address(&token.address) {
+                Ok(address) => {
+                    token.address = address_to_string(&address);
+                    if !token_keys.insert((token.network_id, token.address.clone())) {
+                        errors.push(format!("token {} is listed twice", id));
+                    }
+                }
+                Err(e) => errors.push(format!("token {}: {}", id, e)),
+            }
+            for (field, value) in [("name", &token.name), ("symbol", &token.symbol)] {
+                if let Err(e) = TokenService::validate_label(field, value) {
+                    errors.push(format!("token {}: {}", id, e));
+                }
+            }
+            if !known_network(token.network_id) {
+                errors.push(format!("token {}: unknown network", id));
+            }
+        }
+
+        let mut pool_keys = HashSet::new();
+        for pool in &mut bundle.pools {
+            let id = format!("{}:{}", pool.network_id, pool.address);
+            match TokenService::validate_address(&pool.address) {
+                Ok(address) => {
+                    pool.address = address_to_string(&address);
+                    if !pool_keys.insert((pool.network_id, pool.address.clone())) {
+                        errors.push(format!("pool {} is listed twice", id));
+                    }
+                }
+                Err(e) => errors.push(format!("pool {}: {}", id, e)),
+            }
+            if !known_network(pool.network_id) {
+                errors.push(format!("pool {}: unknown network", id));
+            }
+        }
+
+        let mut path_legs = HashSet::new();
+        for (index, path) in bundle.paths.iter().enumerate() {
+            if path.paths.is_empty() {
+                errors.push(format!("paths[{}]: has no legs", index));
+            }
+            if let Err(e) = PathService::validate_path_addresses(&path.paths) {
+                errors.push(format!("paths[{}]: {}", index, e));
+            }
+            for leg in &path.paths {
+                if !known_network(leg.chain_id) {
+                    errors.push(format!(
+                        "paths[{}]: unknown network {}",
+                        index, leg.chain_id
+                    ));
+                }
+            }
+            if !path_legs.insert(serde_json::to_string(&path.paths)?) {
+                errors.push(format!("paths[{}] is listed twice", index));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!("Invalid bundle: {}", errors.join("; ")))
+        }
+    }
+
+    /// Import a bundle, creating, restoring and updating records to match it
+    ///
+    /// The import is planned before the first write, the same way with and without `dry_run`:
+    /// the whole bundle is validated and the path pools missing from the database and the
+    /// bundle are verified on-chain as on a path create. Records equal to the bundle are
+    /// skipped. In replace mode the active records missing from the bundle are soft-deleted.
+    /// With `dry_run` the planned changes are reported without being made.
+    ///
+    /// Pools of the bundle are not verified on-chain, they were verified where they were
+    /// exported from.
+    ///
+    /// The changes are made in a transaction when the deployment supports them. Otherwise a
+    /// failure after some changes is reported in the response as `partial`, with the changes
+    /// made.
+    ///
+    /// # Arguments
+    /// * `db_service` - MongoDB service
+    /// * `db` - Database reference
+    /// * `actor` - Name of the API key making the change
+    /// * `bundle` - The bundle to import
+    /// * `query` - Import mode and dry run flag
+    ///
+    /// # Returns
+    /// * `Ok(ImportResponse)` - Records created, updated, deleted and skipped per kind
+    /// * `Err(anyhow::Error)` - Error if the bundle is invalid, a path pool cannot be verified or
+    ///   the import fails with no change made
+    pub async fn import_bundle(
+        db_service: &MongoDbService,
+        db: &Database,
+        actor: &str,
+        mut bundle: ConfigBundle,
+        query: ImportQuery,
+    ) -> anyhow::Result<ImportResponse> {
+        debug!(
+            "Importing config bundle in {:?} mode, dry_run: {}",
+            query.mode, query.dry_run
+        );
+
+        let stored = StoredConfig::load(db).await?;
+        Self::validate_bundle(&mut bundle, &stored.networks, query.mode)?;
+        let now = Utc::now().timestamp() as u64;
+        let mut plan = Self::plan_import(bundle, &stored, query.mode, now)?;
+        Self::plan_path_pools(&mut plan, &stored, now).await?;
+        plan.sort();
+
+        let mut response = ImportResponse {
+            mode: query.mode,
+            dry_run: query.dry_run,
+            transactional: false,
+            partial: false,
+            error: None,
+            networks: plan.networks.report(plan.networks.changes.len()),
+            tokens: plan.tokens.report(plan.tokens.changes.len()),
+            pools: plan.pools.report(plan.pools.changes.len()),
+            paths: plan.paths.report(plan.paths.changes.len()),
+            config: plan.config.report(plan.config.changes.len()),
+        };
+        if query.dry_run {
+            return Ok(response);
+        }
+
+        let mut writes = db_service.get_client().start_writes().await?;
+        response.transactional = writes.is_transactional();
+        let mut applied = ImportProgress::default();
+        let result = match Self::apply(db, writes.session(), &plan, now, &mut applied).await {
+            Ok(()) => writes.commit().await,
+            Err(e) => {
+                writes.abort().await;
+                Err(e)
+            }
+        };
+        if let Err(e) = result {
+            if response.transactional || applied.is_empty() {
+                return Err(anyhow::anyhow!(
+                    "Failed to import config bundle, no change was made: {}",
+                    e
+                ));
+            }
+            response.partial = true;
+            response.error = Some(e.to_string());
+            response.networks = plan.networks.report(applied.networks);
+            response.tokens = plan.tokens.report(applied.tokens);
+            response.pools = plan.pools.report(applied.pools);
+            response.paths = plan.paths.report(applied.paths);
+            response.config = plan.config.report(applied.config);
+        }
+
+        let networks = &plan.networks.changes[..applied.networks];
+        AuditService::record_changes(db, actor, networks).await;
+        AuditService::record_changes(db, actor, &plan.tokens.changes[..applied.tokens]).await;
+        AuditService::record_changes(db, actor, &plan.pools.changes[..applied.pools]).await;
+        AuditService::record_changes(db, actor, &plan.paths.changes[..applied.paths]).await;
+        AuditService::record_changes(db, actor, &plan.config.changes[..applied.config]).await;
+        Self::enrich_tokens(db_service, &plan, &applied);
+
+        Ok(response)
+    }
+
+    /// Changes that make the stored records match a validated bundle
+    fn plan_import(
+        bundle: ConfigBundle,
+        stored: &StoredConfig,
+        mode: ImportMode,
+        now: u64,
+    ) -> anyhow::Result<ImportPlan> {
+        let mut plan = ImportPlan::default();
+
+        let chain_ids: HashSet<u64> = bundle.networks.iter().map(|n| n.chain_id).collect();
+        for network in bundle.networks {
+            let id = network.chain_id.to_string();
+            let existing = stored.networks.get(&network.chain_id);
+            if let Some(existing) = existing.filter(|e| e.deleted_at.is_none()) {
+                if !Self::differs(existing, &network, false)? {
+                    plan.networks.skipped += 1;
+                    continue;
+                }
+            }
+            let mut after = Network::new(
+                network.chain_id,
+                network.name,
+                network.rpcs,
+                network.websocket_urls,
+                network.wrap_native,
+                network.min_profit_usd,
+                network.block_explorer,
+                network.v2_factory_to_fee,
+                network.aero_factory_addresses,
+                network.multicall_address,
+                network.max_blocks_per_batch,
+                network.wait_time_fetch,
+            );
+            after.price_network = network.price_network;
+            plan.networks.push(
+                id,
+                Self::upsert(existing, after, now, |after, existing| {
+                    after.id = existing.id;
+                    after.created_at = existing.created_at;
+                }),
+            );
+        }
+
+        let token_keys: HashSet<(u64, String)> = bundle
+            .tokens
+            .iter()
+            .map(|t| (t.network_id, t.address.clone()))
+            .collect();
+        for token in bundle.tokens {
+            let id = format!("{}:{}", token.network_id, token.address);
+            let existing = stored
+                .tokens
+                .get(&(token.network_id, token.address.clone()));
+            if let Some(existing) = existing.filter(|e| e.deleted_at.is_none()) {
+                if !Self::differs(existing, &token, true)? {
+                    plan.tokens.skipped += 1;
+                    continue;
+                }
+            }
+            // Fields left out of the bundle keep their value
+            let mut after = match existing {
+                Some(existing) => existing.clone(),
+                None => Token::new(token.network_id, token.address, None, None, None),
+            };
+            after.name = token.name.or(after.name);
+            after.symbol = token.symbol.or(after.symbol);
+            after.decimals = token.decimals.or(after.decimals);
+            after.blacklisted = token.blacklisted;
+            after.profit_token = token.profit_token;
+            plan.tokens
+                .push(id, Self::upsert(existing, after, now, |_, _| {}));
+        }
+
+        let pool_keys: HashSet<(u64, String)> = bundle
+            .pools
+            .iter()
+            .map(|p| (p.network_id, p.address.clone()))
+            .collect();
+        for pool in bundle.pools {
+            let id = format!("{}:{}", pool.network_id, pool.address);
+            let existing = stored.pools.get(&(pool.network_id, pool.address.clone()));
+            if existing.is_some_and(|e| e.deleted_at.is_none()) {
+                plan.pools.skipped += 1;
+                continue;
+            }
+            let after = existing
+                .cloned()
+                .unwrap_or_else(|| Pool::new(pool.network_id, pool.address));
+            plan.pools
+                .push(id, Self::upsert(existing, after, now, |_, _| {}));
+        }
+
+        // Paths have no identity across environments, they are matched by their legs
+        let mut paths_by_legs: HashMap<String, &Path> = HashMap::new();
+        for path in &stored.paths {
+            let legs = serde_json::to_string(&path.paths)?;
+            if path.deleted_at.is_none() || !paths_by_legs.contains_key(&legs) {
+                paths_by_legs.insert(legs, path);
+            }
+        }
+        let mut kept_paths = HashSet::new();
+        for (index, path) in bundle.paths.into_iter().enumerate() {
+            let legs = serde_json::to_string(&path.paths)?;
+            let existing = paths_by_legs.get(&legs).copied();
+            if let Some(existing) = existing.filter(|e| e.deleted_at.is_none()) {
+                kept_paths.extend(existing.id);
+                plan.paths.skipped += 1;
+                continue;
+            }
+            let after = existing.cloned().unwrap_or_else(|| Path::new(path.paths));
+            plan.paths.push(
+                format!("paths[{}]", index),
+                Self::upsert(existing, after, now, |_, _| {}),
+            );
+        }
+
+        if let Some(bundle_config) = &bundle.config {
+            match &stored.config {
+                Some(existing) if !Self::differs(existing, bundle_config, false)? => {
+                    plan.config.skipped += 1;
+                }
+                existing => {
+                    let mut after = existing.clone().unwrap_or_else(|| {
+                        Config::new(bundle_config.max_amount_usd, bundle_config.recheck_interval)
+                    });
+                    after.max_amount_usd = bundle_config.max_amount_usd;
+                    after.recheck_interval = bundle_config.recheck_interval;
+                    after.updated_at = now;
+                    let change = match existing {
+                        Some(existing) => RecordChange {
+                            action: AuditAction::Update,
+                            before: Some(existing.clone()),
+                            after,
+                        },
+                        None => {
+                            after.id = Some(ObjectId::new());
+                            after.created_at = now;
+                            RecordChange {
+                                action: AuditAction::Create,
+                                before: None,
+                                after,
+                            }
+                        }
+                    };
+                    plan.config.push("config".to_string(), change);
+                }
+            }
+        }
+
+        if mode == ImportMode::Replace {
+            for path in stored.paths.iter().filter(|p| p.deleted_at.is_none()) {
+                let Some(id) = path.id.filter(|id| !kept_paths.contains(id)) else {
+                    continue;
+                };
+                plan.paths.push(id.to_hex(), Self::delete(path, now));
+            }
+            for (key, pool) in stored.pools.iter().filter(|(_, p)| p.deleted_at.is_none()) {
+                if !pool_keys.contains(key) {
+                    let id = format!("{}:{}", pool.network_id, pool.address);
+                    plan.pools.push(id, Self::delete(pool, now));
+                }
+            }
+            for (key, token) in stored.tokens.iter().filter(|(_, t)| t.deleted_at.is_none()) {
+                if !token_keys.contains(key) {
+                    let id = format!("{}:{}", token.network_id, token.address);
+                    plan.tokens.push(id, Self::delete(token, now));
+                }
+            }
+            for network in stored.networks.values().filter(|n| n.deleted_at.is_none()) {
+                if !chain_ids.contains(&network.chain_id) {
+                    let id = network.chain_id.to_string();
+                    plan.networks.push(id, Self::delete(network, now));
+                }
+            }
+        }
+
+        Ok(plan)
+    }
+
+    /// Create of a record missing from the database, or restore or update of an existing one
+    /// `keep` copies the fields of the existing record that the import does not set
+    fn upsert<T: Stamped>(
+        existing: Option<&T>,
+        mut after: T,
+        now: u64,
+        keep: impl FnOnce(&mut T, &T),
+    ) -> RecordChange<T> {
+        match existing {
+            Some(existing) => {
+                keep(&mut after, existing);
+                after.stamp(existing.id(), existing.created_at(), now, None);
+                RecordChange {
+                    action: if existing.deleted_at().is_some() {
+                        AuditAction::Undelete
+                    } else {
+                        AuditAction::Update
+                    },
+                    before: Some(existing.clone()),
+                    after,
+                }
+            }
+            None => {
+                after.stamp(Some(ObjectId::new()), now, now, None);
+                RecordChange {
+                    action: AuditAction::Create,
+                    before: None,
+                    after,
+                }
+            }
+        }
+    }
+
+    /// Soft delete of an active record
+    fn delete<T: Stamped>(existing: &T, now: u64) -> RecordChange<T> {
+        let mut after = existing.clone();
+        after.stamp(existing.id(), existing.created_at(), now, Some(now));
+        RecordChange {
+            action: AuditAction::Delete,
+            before: Some(existing.clone()),
+            after,
+        }
+    }
+
+    /// Verify on-chain the pools of the new paths that are neither stored nor in the bundle,
+    /// and plan their creation, as a path create does
+    ///
+    /// Pools are verified on the network the import leaves in place, which may be one of the
+    /// bundle not stored yet.
+    async fn plan_path_pools(
+        plan: &mut ImportPlan,
+        stored: &StoredConfig,
+        now: u64,
+    ) -> anyhow::Result<()> {
+        let mut networks: HashMap<u64, NetworkResponse> = stored
+            .networks
+            .values()
+            .filter(|network| network.deleted_at.is_none())
+            .map(|network| {
+                let response = NetworkService::map_to_response(network.clone(), HashMap::new());
+                (network.chain_id, response)
+            })
+            .collect();
+        for change in &plan.networks.changes {
+            let response = NetworkService::map_to_response(change.after.clone(), HashMap::new());
+            networks.insert(change.after.chain_id, response);
+        }
+
+        let planned: HashSet<(u64, String)> = plan
+            .pools
+            .changes
+            .iter()
+            .map(|change| (change.after.network_id, change.after.address.clone()))
+            .collect();
+        let mut missing: Vec<(String, u64, String)> = Vec::new();
+        let mut seen = HashSet::new();
+        for (id, change) in plan.paths.ids.iter().zip(&plan.paths.changes) {
+            if change.action != AuditAction::Create {
+                continue;
+            }
+            for leg in &change.after.paths {
+                for pool in leg.paths.iter().flatten() {
+                    let key = (leg.chain_id, address_to_string(&pool.pool));
+                    if stored.pools.contains_key(&key)
+                        || planned.contains(&key)
+                        || !seen.insert(key.clone())
+                    {
+                        continue;
+                    }
+                    missing.push((id.clone(), key.0, key.1));
+                }
+            }
+        }
+
+        let checks = missing.iter().map(|(_, network_id, address)| async {
+            match networks.get(network_id) {
+                Some(network) => PoolService::verify_pool_on_network(network, address)
+                    .await
+                    .map(|_| ()),
+                None => Err(anyhow::anyhow!("unknown network")),
+            }
+        });
+        let results = join_all(checks).await;
+
+        let mut errors = Vec::new();
+        for ((id, network_id, address), result) in missing.into_iter().zip(results) {
+            match result {
+                Ok(()) => {
+                    let pool_id = format!("{}:{}", network_id, address);
+                    let change = Self::upsert(None, Pool::new(network_id, address), now, |_, _| {});
+                    plan.pools.push(pool_id, change);
+                }
+                // An unreachable network says nothing about the bundle
+                Err(e) if e.to_string().contains("RPC request failed") => return Err(e),
+                Err(e) => errors.push(format!(
+                    "{}: pool {} on network {}: {}",
+                    id, address, network_id, e
+                )),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!("Invalid bundle: {}", errors.join("; ")))
+        }
+    }
+
+    /// Make the changes of an import plan, counting in `applied` the changes made
+    /// Creates, restores and updates of every kind are made before the deletes, and the
+    /// deletes of dependents before those of the networks they belong to
+    async fn apply(
+        db: &Database,
+        session: &mut ClientSession,
+        plan: &ImportPlan,
+        now: u64,
+        applied: &mut ImportProgress,
+    ) -> anyhow::Result<()> {
+        let (networks, network_deletes) = plan.networks.split();
+        let (tokens, token_deletes) = plan.tokens.split();
+        let (pools, pool_deletes) = plan.pools.split();
+        let (paths, path_deletes) = plan.paths.split();
+
+        apply_changes(db, session, networks, now, &mut applied.networks).await?;
+        apply_changes(db, session, tokens, now, &mut applied.tokens).await?;
+        apply_changes(db, session, pools, now, &mut applied.pools).await?;
+        apply_changes(db, session, paths, now, &mut applied.paths).await?;
+        apply_changes(db, session, &plan.config.changes, now, &mut applied.config).await?;
+
+        apply_changes(db, session, path_deletes, now, &mut applied.paths).await?;
+        apply_changes(db, session, pool_deletes, now, &mut applied.pools).await?;
+        apply_changes(db, session, token_deletes, now, &mut applied.tokens).await?;
+        apply_changes(db, session, network_deletes, now, &mut applied.networks).await
+    }
+
+    /// Fetch in the background the metadata of the tokens created or restored without a
+    /// name, symbol or decimals, and of the tokens of the paths created
+    fn enrich_tokens(db_service: &MongoDbService, plan: &ImportPlan, applied: &ImportProgress) {
+        let mut tokens_by_chain: BTreeMap<u64, Vec<Address>> = BTreeMap::new();
+        for change in &plan.tokens.changes[..applied.tokens] {
+            let token = &change.after;
+            if change.action == AuditAction::Delete
+                || (token.name.is_some() && token.symbol.is_some() && token.decimals.is_some())
+            {
+                continue;
+            }
+            if let Ok(address) = Address::from_str(&token.address) {
+                tokens_by_chain
+                    .entry(token.network_id)
+                    .or_default()
+                    .push(address);
+            }
+        }
+        for (network_id, addresses) in tokens_by_chain {
+            TokenService::spawn_enrich_tokens(db_service.clone(), network_id, addresses);
+        }
+
+        for change in &plan.paths.changes[..applied.paths] {
+            if change.action == AuditAction::Create {
+                PathService::enrich_path_tokens(db_service, &change.after.paths);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_differs() {
+        let existing = Token::new(
+            1,
+            "0xabc".to_string(),
+            Some("Wrapped Ether".to_string()),
+            None,
+            Some(18),
+        );
+        let mut token = BundleToken {
+            network_id: 1,
+            address: "0xabc".to_string(),
+            name: None,
+            symbol: None,
+            decimals: Some(18),
+            blacklisted: false,
+            profit_token: false,
+        };
+
+        // Fields left out of the bundle keep their value
+        assert!(!BundleService::differs(&existing, &token, true).unwrap());
+        assert!(BundleService::differs(&existing, &token, false).unwrap());
+
+        token.profit_token = true;
+        assert!(BundleService::differs(&existing, &token, true).unwrap());
+    }
+
+    fn stored(mut token: Token, mut pools: Vec<Pool>) -> StoredConfig {
+        token.id = Some(ObjectId::new());
+        for pool in &mut pools {
+            pool.id = Some(ObjectId::new());
+        }
+        StoredConfig {
+            networks: BTreeMap::new(),
+            tokens: BTreeMap::from([((token.network_id, token.address.clone()), token)]),
+            pools: pools
+                .into_iter()
+                .map(|pool| ((pool.network_id, pool.address.clone()), pool))
+                .collect(),
+            paths: vec![],
+            config: Some(Config::new(100.0, 60)),
+        }
+    }
+
+    fn bundle(tokens: Vec<BundleToken>, pools: &[&str]) -> ConfigBundle {
+        ConfigBundle {
+            version: BUNDLE_VERSION,
+            exported_at: 0,
+            networks: vec![],
+            tokens,
+            pools: pools
+                .iter()
+                .map(|address| BundlePool {
+                    network_id: 1,
+                    address: address.to_string(),
+                })
+                .collect(),
+            paths: vec![],
+            config: Some(BundleConfig {
+                max_amount_usd: 100.0,
+                recheck_interval: 60,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_plan_import() {
+        let token = Token::new(1, "0xabc".to_string(), None, None, Some(18));
+        let mut deleted = Pool::new(1, "0x2".to_string());
+        deleted.deleted_at = Some(10);
+        let pools = vec![
+            Pool::new(1, "0x1".to_string()),
+            deleted,
+            Pool::new(1, "0x3".to_string()),
+        ];
+        let stored = stored(token, pools);
+        let bundle = bundle(
+            vec![BundleToken {
+                network_id: 1,
+                address: "0xabc".to_string(),
+                name: Some("Wrapped Ether".to_string()),
+                symbol: None,
+                decimals: None,
+                blacklisted: false,
+                profit_token: true,
+            }],
+            &["0x1", "0x2", "0x4"],
+        );
+
+        let mut plan =
+            BundleService::plan_import(bundle, &stored, ImportMode::Replace, 20).unwrap();
+        plan.sort();
+
+        // Fields left out of the bundle keep their value
+        let update = &plan.tokens.changes[0];
+        assert_eq!(update.action, AuditAction::Update);
+        assert_eq!(update.after.decimals, Some(18));
+        assert_eq!(update.after.id, stored.tokens[&(1, "0xabc".to_string())].id);
+
+        // Creates, then restores, then deletes of the active pools missing from the bundle
+        let actions: Vec<AuditAction> = plan.pools.changes.iter().map(|c| c.action).collect();
+        assert_eq!(
+            actions,
+            vec![
+                AuditAction::Create,
+                AuditAction::Undelete,
+                AuditAction::Delete
+            ]
+        );
+        assert_eq!(plan.pools.ids, vec!["1:0x4", "1:0x2", "1:0x3"]);
+        assert_eq!(plan.pools.changes[2].after.deleted_at, Some(20));
+        assert_eq!(plan.pools.skipped, 1);
+
+        let report = plan.pools.report(plan.pools.changes.len());
+        assert_eq!(report.created, vec!["1:0x4", "1:0x2"]);
+        assert_eq!(report.deleted, vec!["1:0x3"]);
+        // A partial import reports the changes made before the failure
+        let report = plan.pools.report(1);
+        assert_eq!(report.created, vec!["1:0x4"]);
+        assert!(report.deleted.is_empty());
+
+        // The config is up to date
+        assert!(plan.config.changes.is_empty());
+        assert_eq!(plan.config.skipped, 1);
+    }
+
+    #[test]
+    fn test_plan_import_merge_keeps_missing_records() {
+        let token = Token::new(1, "0xabc".to_string(), None, None, None);
+        let stored = stored(token, vec![Pool::new(1, "0x1".to_string())]);
+        let mut bundle = bundle(vec![], &[]);
+        bundle.config = Some(BundleConfig {
+            max_amount_usd: 250.0,
+            recheck_interval: 60,
+        });
+
+        let plan = BundleService::plan_import(bundle, &stored, ImportMode::Merge, 20).unwrap();
+        assert!(plan.tokens.changes.is_empty());
+        assert!(plan.pools.changes.is_empty());
+        let config = &plan.config.changes[0];
+        assert_eq!(config.action, AuditAction::Update);
+        assert_eq!(config.after.max_amount_usd, 250.0);
+        assert_eq!(config.after.updated_at, 20);
+    }
+}
@@ -1,63 +1,157 @@
-use actix_web::{dev::Payload, web, Error, FromRequest, HttpRequest};
-use futures::future::{ready, Ready};
-use log::warn;
+use actix_web::{dev::Payload, web, FromRequest, HttpMessage, HttpRequest};
+use chrono::Utc;
+use futures::future::LocalBoxFuture;
+use log::{error, warn};
 use std::sync::Arc;
+use subtle::ConstantTimeEq;
 
 use crate::config::Config;
+use crate::database::models::{hash_api_key, ApiKeyScope};
+use crate::database::MongoDbService;
+use crate::errors::ApiError;
 
-/// API Key extractor for authentication
-pub struct ApiKey(pub String);
+/// Name reported for the key configured in `server.api_key`
+pub const ROOT_API_KEY_NAME: &str = "root";
 
-impl FromRequest for ApiKey {
-    type Error = Error;
-    type Future = Ready<Result<Self, Self::Error>>;
+/// Authenticated API key
+///
+/// As an extractor it requires the `write` scope, see `HardDeleteApiKey` and
+/// `AdminApiKey` for the other scopes. Keys are read from the X-API-Key header and
+/// checked against the `api_keys` collection; the key configured in `server.api_key`
+/// is a root key with every scope, used to create the first stored keys.
+#[derive(Debug, Clone)]
+pub struct ApiKey {
+    pub name: String,
+    pub scopes: Vec<ApiKeyScope>,
+}
 
-    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
-        // Get the API key from app data
-        let config = req
-            .app_data::<web::Data<Arc<Config>>>()
-            .map(|c| c.as_ref().clone());
+/// API key extractor requiring the `hard_delete` scope
+#[derive(Debug, Clone)]
+pub struct HardDeleteApiKey(pub ApiKey);
+
+/// API key extractor requiring the `admin` scope
+#[derive(Debug, Clone)]
+pub struct AdminApiKey(pub ApiKey);
+
+impl ApiKey {
+    /// Authenticate the request and check that the key grants `required`
+    pub async fn authenticate(req: &HttpRequest, required: ApiKeyScope) -> Result<Self, ApiError> {
+        let api_key = Self::identify(req).await?;
+        if !api_key.scopes.iter().any(|scope| scope.grants(required)) {
+            warn!(
+                "API key '{}' lacks the {} scope for {} {}",
+                api_key.name,
+                required,
+                req.method(),
+                req.path()
+            );
+            return Err(ApiError::Forbidden(format!(
+                "API key '{}' lacks the {} scope",
+                api_key.name, required
+            )));
+        }
+
+        Ok(api_key)
+    }
+
+    /// Resolve the key of the X-API-Key header, whatever its scopes
+    /// Reuses the key already authenticated by the auth middleware, if any
+    async fn identify(req: &HttpRequest) -> Result<Self, ApiError> {
+        if let Some(api_key) = req.extensions().get::<ApiKey>() {
+            return Ok(api_key.clone());
+        }
 
-        // Get the API key from the X-API-Key header
         let header_key = req
             .headers()
             .get("X-API-Key")
             .and_then(|v| v.to_str().ok())
-            .map(|s| s.to_string());
-
-        match (config, header_key) {
-            (Some(config), Some(header_key)) => {
-                // Check if API key is configured
-                if let Some(configured_key) = &config.server.api_key {
-                    if configured_key == &header_key {
-                        ready(Ok(ApiKey(header_key)))
-                    } else {
-                        warn!("Invalid API key provided");
-                        ready(Err(actix_web::error::ErrorUnauthorized("Invalid API key")))
-                    }
-                } else {
-                    // API key not configured, allow access (for development)
-                    warn!("API key not configured, allowing access");
-                    ready(Ok(ApiKey(header_key)))
-                }
-            }
-            (Some(config), None) => {
-                // API key required but not provided
-                if config.server.api_key.is_some() {
-                    warn!("API key required but not provided");
-                    ready(Err(actix_web::error::ErrorUnauthorized("API key required")))
-                } else {
-                    // API key not configured, allow access (for development)
-                    warn!("API key not configured, allowing access");
-                    ready(Ok(ApiKey("".to_string())))
-                }
-            }
-            _ => {
-                warn!("Config not found in app data");
-                ready(Err(actix_web::error::ErrorInternalServerError(
-                    "Configuration error",
-                )))
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string())
+            .ok_or_else(|| {
+                warn!("API key required but not provided");
+                ApiError::Unauthorized("API key required".to_string())
+            })?;
+
+        let config = req.app_data::<web::Data<Arc<Config>>>().ok_or_else(|| {
+            error!("Config not found in app data");
+            ApiError::InternalError
+        })?;
+        // Hashes have the same length whatever the keys, so comparing them takes the same time
+        let key_hash = hash_api_key(&header_key);
+        let is_root = config.server.api_key.as_deref().is_some_and(|root_key| {
+            bool::from(key_hash.as_bytes().ct_eq(hash_api_key(root_key).as_bytes()))
+        });
+        if is_root {
+            return Ok(Self {
+                name: ROOT_API_KEY_NAME.to_string(),
+                scopes: vec![ApiKeyScope::Admin],
+            });
+        }
+
+        let db_service = req.app_data::<web::Data<MongoDbService>>().ok_or_else(|| {
+            error!("MongoDbService not found in app data");
+            ApiError::InternalError
+        })?;
+        let api_key_repo = db_service.get_api_key_repo();
+        let now = Utc::now().timestamp() as u64;
+        let record = api_key_repo
+            .find_by_hash(&key_hash)
+            .await
+            .map_err(|e| ApiError::DatabaseError(format!("Failed to check API key: {}", e)))?
+            .filter(|record| record.is_active(now))
+            .ok_or_else(|| {
+                warn!("Invalid API key provided");
+                ApiError::Unauthorized("Invalid API key".to_string())
+            })?;
+
+        // `last_used_at` is only updated once a minute, not on every request
+        if let Some(id) = record.id.filter(|_| record.needs_touch(now)) {
+            if let Err(e) = api_key_repo.touch(&id).await {
+                warn!("Failed to record use of API key '{}': {}", record.name, e);
             }
         }
+
+        Ok(Self {
+            name: record.name,
+            scopes: record.scopes,
+        })
+    }
+}
+
+impl FromRequest for ApiKey {
+    type Error = ApiError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let req = req.clone();
+        Box::pin(async move { ApiKey::authenticate(&req, ApiKeyScope::Write).await })
+    }
+}
+
+impl FromRequest for HardDeleteApiKey {
+    type Error = ApiError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let req = req.clone();
+        Box::pin(async move {
+            ApiKey::authenticate(&req, ApiKeyScope::HardDelete)
+                .await
+                .map(HardDeleteApiKey)
+        })
+    }
+}
+
+impl FromRequest for AdminApiKey {
+    type Error = ApiError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let req = req.clone();
+        Box::pin(async move {
+            ApiKey::authenticate(&req, ApiKeyScope::Admin)
+                .await
+                .map(AdminApiKey)
+        })
     }
 }
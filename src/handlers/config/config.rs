@@ -37,20 +37,27 @@ pub async fn get_config_handler(db: web::Data<Database>) -> Result<HttpResponse,
 /// Requires API key authentication via X-API-Key header
 ///
 /// # Arguments
-/// * `_api_key` - API key from X-API-Key header (validated by extractor)
+/// * `api_key` - API key from X-API-Key header (validated by extractor), recorded in the audit log
 /// * `db` - Database connection
 /// * `body` - UpdateConfigRequest containing fields to update
 ///
 /// # Returns
 /// JSON object of ConfigResponse containing updated config information
 pub async fn update_config_handler(
-    _api_key: ApiKey,
+    api_key: ApiKey,
     db: web::Data<Database>,
     body: web::Json<crate::handlers::config::dto::UpdateConfigRequest>,
 ) -> Result<HttpResponse, ApiError> {
     info!("Handling PUT /config request");
 
-    match ConfigService::update_config(&db, body.max_amount_usd, body.recheck_interval).await {
+    match ConfigService::update_config(
+        &db,
+        &api_key.name,
+        body.max_amount_usd,
+        body.recheck_interval,
+    )
+    .await
+    {
         Ok(config) => {
             info!("Successfully updated config");
             Ok(HttpResponse::Ok().json(config))
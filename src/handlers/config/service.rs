@@ -2,7 +2,10 @@ use log::debug;
 use mongodb::bson::doc;
 use mongodb::Database;
 
-use crate::{database::models::Config, handlers::config::dto::ConfigResponse};
+use crate::{
+    database::models::{AuditAction, AuditEntity, Config},
+    handlers::{audit::service::AuditService, config::dto::ConfigResponse},
+};
 
 /// Service layer for config-related business logic
 pub struct ConfigService;
@@ -36,7 +39,7 @@ impl ConfigService {
     ///
     /// # Returns
     /// ConfigResponse DTO
-    fn map_to_response(config: Config) -> ConfigResponse {
+    pub(crate) fn map_to_response(config: Config) -> ConfigResponse {
         let id = config
             .id
             .map(|oid| oid.to_hex())
@@ -55,6 +58,7 @@ impl ConfigService {
     ///
     /// # Arguments
     /// * `db` - Database reference
+    /// * `actor` - Name of the API key making the change
     /// * `max_amount_usd` - Optional new max_amount_usd value
     /// * `recheck_interval` - Optional new recheck_interval value
     ///
@@ -63,6 +67,7 @@ impl ConfigService {
     /// * `Err(anyhow::Error)` - Error if database operation fails
     pub async fn update_config(
         db: &Database,
+        actor: &str,
         max_amount_usd: Option<f64>,
         recheck_interval: Option<u64>,
     ) -> anyhow::Result<ConfigResponse> {
@@ -70,6 +75,7 @@ impl ConfigService {
 
         let collection = db.collection::<Config>("configs");
         let filter = doc! {};
+        let existing = collection.find_one(filter.clone()).await?;
 
         let mut update_doc = mongodb::bson::Document::new();
         update_doc.insert("updated_at", chrono::Utc::now().timestamp() as i64);
@@ -90,11 +96,37 @@ impl ConfigService {
             let default_max_amount_usd = max_amount_usd.unwrap_or(1000.0);
             let default_recheck_interval = recheck_interval.unwrap_or(60);
             let new_config = Config::new(default_max_amount_usd, default_recheck_interval);
-            collection.insert_one(new_config.clone()).await?;
+            let result = collection.insert_one(new_config.clone()).await?;
+            let id = result
+                .inserted_id
+                .as_object_id()
+                .map(|oid| oid.to_hex())
+                .unwrap_or_default();
+            AuditService::record(
+                db,
+                actor,
+                AuditAction::Create,
+                AuditEntity::Config,
+                &id,
+                None,
+                Some(&new_config),
+            )
+            .await;
             Ok(Self::map_to_response(new_config))
         } else {
             // Get updated config
             let config = collection.find_one(filter).await?.unwrap();
+            let id = config.id.map(|oid| oid.to_hex()).unwrap_or_default();
+            AuditService::record(
+                db,
+                actor,
+                AuditAction::Update,
+                AuditEntity::Config,
+                &id,
+                existing.as_ref(),
+                Some(&config),
+            )
+            .await;
             Ok(Self::map_to_response(config))
         }
     }
@@ -0,0 +1,216 @@
+use crate::{
+    database::MongoDbService,
+    errors::ApiError,
+    handlers::{
+        config::auth::ApiKey,
+        config_snapshot::{dto::CreateConfigSnapshotRequest, service::ConfigSnapshotService},
+    },
+};
+use actix_web::{web, HttpResponse};
+use log::{error, info};
+use mongodb::Database;
+
+/// POST /config/snapshots - Takes a snapshot of the active networks, pools and paths and of the config
+/// Requires API key authentication via X-API-Key header
+///
+/// # Arguments
+/// * `api_key` - API key from X-API-Key header (validated by extractor), recorded in the audit log
+/// * `db_service` - MongoDB service
+/// * `db` - Database connection
+/// * `body` - CreateConfigSnapshotRequest with an optional description
+///
+/// # Returns
+/// JSON object of ConfigSnapshotSummaryResponse containing the new version
+pub async fn create_config_snapshot_handler(
+    api_key: ApiKey,
+    db_service: web::Data<MongoDbService>,
+    db: web::Data<Database>,
+    body: web::Json<CreateConfigSnapshotRequest>,
+) -> Result<HttpResponse, ApiError> {
+    info!("Handling POST /config/snapshots request");
+
+    match ConfigSnapshotService::create_snapshot(&db_service, &db, &api_key.name, body.into_inner())
+        .await
+    {
+        Ok(snapshot) => {
+            info!(
+                "Successfully took config snapshot version {}",
+                snapshot.version
+            );
+            Ok(HttpResponse::Created().json(snapshot))
+        }
+        Err(e) => {
+            error!("Failed to take config snapshot: {}", e);
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to take config snapshot: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
+
+/// GET /config/snapshots - Returns the config snapshots without their content, newest first
+///
+/// # Arguments
+/// * `db_service` - MongoDB service
+///
+/// # Returns
+/// JSON array of ConfigSnapshotSummaryResponse
+pub async fn get_config_snapshots_handler(
+    db_service: web::Data<MongoDbService>,
+) -> Result<HttpResponse, ApiError> {
+    info!("Handling GET /config/snapshots request");
+
+    match ConfigSnapshotService::get_snapshots(&db_service).await {
+        Ok(snapshots) => {
+            info!(
+                "Successfully retrieved {} config snapshots",
+                snapshots.len()
+            );
+            Ok(HttpResponse::Ok().json(snapshots))
+        }
+        Err(e) => {
+            error!("Failed to retrieve config snapshots: {}", e);
+            Err(ApiError::DatabaseError(format!(
+                "Failed to retrieve config snapshots: {}",
+                e
+            )))
+        }
+    }
+}
+
+/// GET /config/snapshots/{version} - Returns a config snapshot with its content
+///
+/// # Arguments
+/// * `db_service` - MongoDB service
+/// * `path` - Path parameter containing the version
+///
+/// # Returns
+/// JSON object of ConfigSnapshotResponse
+pub async fn get_config_snapshot_handler(
+    db_service: web::Data<MongoDbService>,
+    path: web::Path<u64>,
+) -> Result<HttpResponse, ApiError> {
+    let version = path.into_inner();
+    info!("Handling GET /config/snapshots/{} request", version);
+
+    match ConfigSnapshotService::get_snapshot(&db_service, version).await {
+        Ok(Some(snapshot)) => {
+            info!("Successfully retrieved config snapshot version {}", version);
+            Ok(HttpResponse::Ok().json(snapshot))
+        }
+        Ok(None) => {
+            info!("Config snapshot version {} not found", version);
+            Err(ApiError::NotFound(format!(
+                "Config snapshot version {} not found",
+                version
+            )))
+        }
+        Err(e) => {
+            error!("Failed to retrieve config snapshot {}: {}", version, e);
+            Err(ApiError::DatabaseError(format!(
+                "Failed to retrieve config snapshot: {}",
+                e
+            )))
+        }
+    }
+}
+
+/// GET /config/snapshots/{version}/diff/{other} - Returns the changes from one snapshot to another
+///
+/// # Arguments
+/// * `db_service` - MongoDB service
+/// * `path` - Path parameters containing the version to compare from and the version to compare to
+///
+/// # Returns
+/// JSON object of ConfigSnapshotDiffResponse
+pub async fn diff_config_snapshots_handler(
+    db_service: web::Data<MongoDbService>,
+    path: web::Path<(u64, u64)>,
+) -> Result<HttpResponse, ApiError> {
+    let (from, to) = path.into_inner();
+    info!(
+        "Handling GET /config/snapshots/{}/diff/{} request",
+        from, to
+    );
+
+    match ConfigSnapshotService::diff_snapshots(&db_service, from, to).await {
+        Ok(diff) => {
+            info!("Successfully compared config snapshots {} and {}", from, to);
+            Ok(HttpResponse::Ok().json(diff))
+        }
+        Err(e) => {
+            error!(
+                "Failed to compare config snapshots {} and {}: {}",
+                from, to, e
+            );
+            if e.to_string().contains("not found") {
+                Err(ApiError::NotFound(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to compare config snapshots: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
+
+/// POST /config/snapshots/{version}/restore - Puts the configuration back in the state of a snapshot
+/// Requires API key authentication via X-API-Key header
+///
+/// # Arguments
+/// * `api_key` - API key from X-API-Key header (validated by extractor), recorded in the audit log
+/// * `db_service` - MongoDB service
+/// * `db` - Database connection
+/// * `path` - Path parameter containing the version
+///
+/// # Returns
+/// JSON object of RestoreConfigSnapshotResponse with the changes made and the backup version,
+/// with a 500 status when the restore was only partly applied
+pub async fn restore_config_snapshot_handler(
+    api_key: ApiKey,
+    db_service: web::Data<MongoDbService>,
+    db: web::Data<Database>,
+    path: web::Path<u64>,
+) -> Result<HttpResponse, ApiError> {
+    let version = path.into_inner();
+    info!(
+        "Handling POST /config/snapshots/{}/restore request",
+        version
+    );
+
+    match ConfigSnapshotService::restore_snapshot(&db_service, &db, &api_key.name, version).await {
+        Ok(restore) if restore.partial => {
+            error!(
+                "Partly restored config snapshot version {} (backup version {}): {}",
+                version,
+                restore.backup_version,
+                restore.error.as_deref().unwrap_or_default()
+            );
+            Ok(HttpResponse::InternalServerError().json(restore))
+        }
+        Ok(restore) => {
+            info!(
+                "Successfully restored config snapshot version {} (backup version {})",
+                version, restore.backup_version
+            );
+            Ok(HttpResponse::Ok().json(restore))
+        }
+        Err(e) => {
+            error!("Failed to restore config snapshot {}: {}", version, e);
+            if e.to_string().contains("not found") {
+                Err(ApiError::NotFound(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to restore config snapshot: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
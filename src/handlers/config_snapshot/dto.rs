@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+
+use crate::database::models::{FieldChange, SnapshotPool};
+use crate::handlers::{
+    config::dto::ConfigResponse, network::dto::NetworkResponse, path::dto::PathResponse,
+};
+
+/// Request model for taking a config snapshot
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct CreateConfigSnapshotRequest {
+    pub description: Option<String>, // at most 500 characters
+}
+
+/// Response model for a config snapshot without its content
+#[derive(Debug, Serialize)]
+pub struct ConfigSnapshotSummaryResponse {
+    pub id: String, // MongoDB ObjectId as string
+    pub version: u64,
+    pub description: Option<String>,
+    pub created_by: String,
+    pub networks: u64, // number of networks in the snapshot
+    pub pools: u64,
+    pub paths: u64,
+    pub created_at: u64,
+}
+
+/// Response model for a config snapshot with its content
+/// Network `checkpoints` are not part of snapshots and are always empty
+#[derive(Debug, Serialize)]
+pub struct ConfigSnapshotResponse {
+    pub id: String, // MongoDB ObjectId as string
+    pub version: u64,
+    pub description: Option<String>,
+    pub created_by: String,
+    pub networks: Vec<NetworkResponse>,
+    pub pools: Vec<SnapshotPool>,
+    pub paths: Vec<PathResponse>,
+    pub config: Option<ConfigResponse>,
+    pub created_at: u64,
+}
+
+/// Record whose fields differ between two snapshots
+#[derive(Debug, Serialize)]
+pub struct ChangedRecord {
+    pub id: String,
+    pub changes: Vec<FieldChange>,
+}
+
+/// Differences of one kind of record between two snapshots
+/// Ids are the chain_id of networks, network_id:address of pools and the ObjectId of paths
+#[derive(Debug, Default, Serialize)]
+pub struct RecordDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<ChangedRecord>,
+}
+
+/// Response model for the differences between two snapshots
+#[derive(Debug, Serialize)]
+pub struct ConfigSnapshotDiffResponse {
+    pub from: u64,
+    pub to: u64,
+    pub networks: RecordDiff,
+    pub pools: RecordDiff,
+    pub paths: RecordDiff,
+    pub config: Vec<FieldChange>,
+}
+
+/// Changes made to one kind of record by a restore
+#[derive(Debug, Default, Serialize)]
+pub struct RestoreCounts {
+    pub created: usize,   // missing from the database, inserted again
+    pub updated: usize,   // fields set back to the snapshot
+    pub undeleted: usize, // soft-deleted, restored with the snapshot fields
+    pub deleted: usize,   // active but not in the snapshot, soft-deleted
+    pub unchanged: usize,
+}
+
+/// Response model for restoring a snapshot
+/// When `partial`, the restore failed midway and the counts are those of the changes made
+#[derive(Debug, Serialize)]
+pub struct RestoreConfigSnapshotResponse {
+    pub version: u64,
+    pub backup_version: u64, // snapshot of the configuration before the restore
+    pub transactional: bool, // changes made in a transaction, all or none
+    pub partial: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>, // why a partial restore stopped
+    pub networks: RestoreCounts,
+    pub pools: RestoreCounts,
+    pub paths: RestoreCounts,
+    pub config_updated: bool,
+}
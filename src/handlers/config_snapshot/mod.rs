@@ -0,0 +1,6 @@
+pub mod config_snapshot;
+pub mod dto;
+pub mod service;
+
+pub use config_snapshot::*;
+pub use dto::*;
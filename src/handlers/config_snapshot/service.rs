@@ -0,0 +1,803 @@
+use chrono::Utc;
+use futures::TryStreamExt;
+use log::debug;
+use mongodb::bson::{doc, oid::ObjectId, Document};
+use mongodb::{ClientSession, Database};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{
+    database::changes::{apply_changes, Record, RecordChange, Stamped},
+    database::models::{
+        diff_fields, AuditAction, AuditEntity, Config, ConfigSnapshot, ConfigSnapshotSummary,
+        Network, Path, Pool, SnapshotPool,
+    },
+    database::MongoDbService,
+    handlers::{
+        audit::service::AuditService,
+        config::service::ConfigService,
+        config_snapshot::dto::{
+            ChangedRecord, ConfigSnapshotDiffResponse, ConfigSnapshotResponse,
+            ConfigSnapshotSummaryResponse, CreateConfigSnapshotRequest, RecordDiff,
+            RestoreConfigSnapshotResponse, RestoreCounts,
+        },
+        network::service::NetworkService,
+        path::service::PathService,
+    },
+};
+
+/// Maximum length of a snapshot description
+const MAX_DESCRIPTION_LENGTH: usize = 500;
+
+/// Changes a restore makes to one kind of record, creates first, then updates and undeletes,
+/// then deletes
+struct RecordPlan<T> {
+    changes: Vec<RecordChange<T>>,
+    unchanged: usize,
+}
+
+impl<T> Default for RecordPlan<T> {
+    fn default() -> Self {
+        Self {
+            changes: Vec::new(),
+            unchanged: 0,
+        }
+    }
+}
+
+/// Changes a restore makes, planned before the first write
+struct RestorePlan {
+    networks: RecordPlan<Network>,
+    pools: RecordPlan<Pool>,
+    paths: RecordPlan<Path>,
+    config: Vec<RecordChange<Config>>, // one change at most
+}
+
+/// Number of changes of each plan made so far
+#[derive(Debug, Default)]
+struct RestoreProgress {
+    networks: usize,
+    pools: usize,
+    paths: usize,
+    config: usize,
+}
+
+impl RestoreProgress {
+    fn is_empty(&self) -> bool {
+        self.networks + self.pools + self.paths + self.config == 0
+    }
+}
+
+/// Network, pool or path put back in the state of a snapshot by a restore
+/// Its id and timestamps are not restored
+trait Restorable: Stamped {
+    /// Identity of the record across snapshots
+    fn key(&self) -> String;
+    /// Filters on the stored records with the identity of one of `records`
+    fn key_filters(records: &[Self]) -> Vec<Document>;
+}
+
+impl Restorable for Network {
+    fn key(&self) -> String {
+        self.chain_id.to_string()
+    }
+
+    fn key_filters(records: &[Self]) -> Vec<Document> {
+        let chain_ids: Vec<i64> = records
+            .iter()
+            .map(|network| network.chain_id as i64)
+            .collect();
+        vec![doc! { "chain_id": { "$in": chain_ids } }]
+    }
+}
+
+impl Restorable for Pool {
+    fn key(&self) -> String {
+        format!("{}:{}", self.network_id, self.address)
+    }
+
+    fn key_filters(records: &[Self]) -> Vec<Document> {
+        let mut addresses: BTreeMap<u64, Vec<&str>> = BTreeMap::new();
+        for pool in records {
+            addresses
+                .entry(pool.network_id)
+                .or_default()
+                .push(&pool.address);
+        }
+        addresses
+            .into_iter()
+            .map(|(network_id, addresses)| {
+                doc! { "network_id": network_id as i64, "address": { "$in": addresses } }
+            })
+            .collect()
+    }
+}
+
+impl Restorable for Path {
+    fn key(&self) -> String {
+        self.audit_id()
+    }
+
+    fn key_filters(records: &[Self]) -> Vec<Document> {
+        let ids: Vec<ObjectId> = records.iter().filter_map(|path| path.id).collect();
+        vec![doc! { "_id": { "$in": ids } }]
+    }
+}
+
+/// Service layer for versioned snapshots of the bot configuration
+pub struct ConfigSnapshotService;
+
+impl ConfigSnapshotService {
+    /// Filter on the records that are not soft-deleted
+    fn active() -> Document {
+        doc! { "deleted_at": null }
+    }
+
+    /// Copy the active networks, pools and paths and the config into a new snapshot
+    async fn capture(
+        db: &Database,
+        actor: &str,
+        description: Option<String>,
+    ) -> anyhow::Result<ConfigSnapshot> {
+        let networks: Vec<Network> = db
+            .collection::<Network>("networks")
+            .find(Self::active())
+            .sort(doc! { "chain_id": 1 })
+            .await?
+            .try_collect()
+            .await?;
+        let pools: Vec<SnapshotPool> = db
+            .collection::<SnapshotPool>("pools")
+            .find(Self::active())
+            .projection(doc! { "_id": 0, "network_id": 1, "address": 1 })
+            .sort(doc! { "network_id": 1, "address": 1 })
+            .await?
+            .try_collect()
+            .await?;
+        let paths: Vec<Path> = db
+            .collection::<Path>("paths")
+            .find(Self::active())
+            .sort(doc! { "_id": 1 })
+            .await?
+            .try_collect()
+            .await?;
+        let config = db.collection::<Config>("configs").find_one(doc! {}).await?;
+
+        Ok(ConfigSnapshot::new(
+            description,
+            actor.to_string(),
+            networks,
+            pools,
+            paths,
+            config,
+        ))
+    }
+
+    /// Capture and store the next snapshot, recording it in the audit log
+    async fn take_snapshot(
+        db_service: &MongoDbService,
+        db: &Database,
+        actor: &str,
+        description: Option<String>,
+    ) -> anyhow::Result<ConfigSnapshot> {
+        let snapshot = Self::capture(db, actor, description).await?;
+        let snapshot = db_service
+            .get_config_snapshot_repo()
+            .insert(snapshot)
+            .await?;
+        AuditService::record(
+            db,
+            actor,
+            AuditAction::Create,
+            AuditEntity::ConfigSnapshot,
+            &snapshot.version.to_string(),
+            None,
+            Some(&ConfigSnapshotSummary::from(&snapshot)),
+        )
+        .await;
+        Ok(snapshot)
+    }
+
+    /// Find a snapshot by version, failing if it does not exist
+    async fn find_snapshot(
+        db_service: &MongoDbService,
+        version: u64,
+    ) -> anyhow::Result<ConfigSnapshot> {
+        db_service
+            .get_config_snapshot_repo()
+            .find_by_version(version)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Config snapshot version {} not found", version))
+    }
+
+    /// Take a snapshot of the current configuration
+    ///
+    /// # Arguments
+    /// * `db_service` - MongoDB service
+    /// * `db` - Database reference
+    /// * `actor` - Name of the API key taking the snapshot
+    /// * `request` - CreateConfigSnapshotRequest with an optional description
+    ///
+    /// # Returns
+    /// * `Ok(ConfigSnapshotSummaryResponse)` - The new snapshot with its version
+    /// * `Err(anyhow::Error)` - Error if the description is too long or the database operation fails
+    pub async fn create_snapshot(
+        db_service: &MongoDbService,
+        db: &Database,
+        actor: &str,
+        request: CreateConfigSnapshotRequest,
+    ) -> anyhow::Result<ConfigSnapshotSummaryResponse> {
+        let description = request
+            .description
+            .map(|description| description.trim().to_string())
+            .filter(|description| !description.is_empty());
+        if let Some(description) = &description {
+            if description.chars().count() > MAX_DESCRIPTION_LENGTH {
+                return Err(anyhow::anyhow!(
+                    "Invalid description: at most {} characters",
+                    MAX_DESCRIPTION_LENGTH
+                ));
+            }
+        }
+
+        let snapshot = Self::take_snapshot(db_service, db, actor, description).await?;
+        debug!(
+            "Took config snapshot version {}: {} networks, {} pools, {} paths",
+            snapshot.version,
+            snapshot.networks.len(),
+            snapshot.pools.len(),
+            snapshot.paths.len()
+        );
+        Ok(Self::map_to_summary_response(ConfigSnapshotSummary::from(
+            &snapshot,
+        )))
+    }
+
+    /// Get the summaries of all snapshots, newest first
+    pub async fn get_snapshots(
+        db_service: &MongoDbService,
+    ) -> anyhow::Result<Vec<ConfigSnapshotSummaryResponse>> {
+        let summaries = db_service
+            .get_config_snapshot_repo()
+            .find_summaries()
+            .await?;
+        Ok(summaries
+            .into_iter()
+            .map(Self::map_to_summary_response)
+            .collect())
+    }
+
+    /// Get a snapshot with its content
+    ///
+    /// # Returns
+    /// * `Ok(Some(ConfigSnapshotResponse))` - Snapshot if found
+    /// * `Ok(None)` - No snapshot has this version
+    /// * `Err(anyhow::Error)` - Error if the database operation fails
+    pub async fn get_snapshot(
+        db_service: &MongoDbService,
+        version: u64,
+    ) -> anyhow::Result<Option<ConfigSnapshotResponse>> {
+        Ok(db_service
+            .get_config_snapshot_repo()
+            .find_by_version(version)
+            .await?
+            .map(Self::map_to_response))
+    }
+
+    /// Get the differences between two snapshots, from `from` to `to`
+    ///
+    /// # Returns
+    /// * `Ok(ConfigSnapshotDiffResponse)` - Records added, removed and changed by kind
+    /// * `Err(anyhow::Error)` - Error if a snapshot is not found or the database operation fails
+    pub async fn diff_snapshots(
+        db_service: &MongoDbService,
+        from: u64,
+        to: u64,
+    ) -> anyhow::Result<ConfigSnapshotDiffResponse> {
+        let before = Self::find_snapshot(db_service, from).await?;
+        let after = Self::find_snapshot(db_service, to).await?;
+
+        let network_key = |network: &Network| network.chain_id.to_string();
+        let pool_key = |pool: &SnapshotPool| format!("{}:{}", pool.network_id, pool.address);
+        let path_key = |path: &Path| path.audit_id();
+
+        Ok(ConfigSnapshotDiffResponse {
+            from,
+            to,
+            networks: Self::diff_records(
+                Self::keyed(&before.networks, network_key)?,
+                Self::keyed(&after.networks, network_key)?,
+            ),
+            pools: Self::diff_records(
+                Self::keyed(&before.pools, pool_key)?,
+                Self::keyed(&after.pools, pool_key)?,
+            ),
+            paths: Self::diff_records(
+                Self::keyed(&before.paths, path_key)?,
+                Self::keyed(&after.paths, path_key)?,
+            ),
+            config: diff_fields(
+                &serde_json::to_value(&before.config)?,
+                &serde_json::to_value(&after.config)?,
+            ),
+        })
+    }
+
+    /// Serialized records by their identity across snapshots
+    fn keyed<T: Serialize>(
+        records: &[T],
+        key: impl Fn(&T) -> String,
+    ) -> anyhow::Result<BTreeMap<String, Value>> {
+        records
+            .iter()
+            .map(|record| Ok((key(record), serde_json::to_value(record)?)))
+            .collect()
+    }
+
+    /// Records added, removed and changed between two keyed sets of records
+    fn diff_records(
+        before: BTreeMap<String, Value>,
+        mut after: BTreeMap<String, Value>,
+    ) -> RecordDiff {
+        let mut diff = RecordDiff::default();
+        for (id, old) in before {
+            match after.remove(&id) {
+                Some(new) => {
+                    let changes = diff_fields(&old, &new);
+                    if !changes.is_empty() {
+                        diff.changed.push(ChangedRecord { id, changes });
+                    }
+                }
+                None => diff.removed.push(id),
+            }
+        }
+        diff.added = after.into_keys().collect();
+        diff
+    }
+
+    /// Whether two records differ in a field a snapshot restores
+    fn differs<T: Serialize>(a: &T, b: &T) -> anyhow::Result<bool> {
+        Ok(!diff_fields(&serde_json::to_value(a)?, &serde_json::to_value(b)?).is_empty())
+    }
+
+    /// Restore a snapshot: the networks, pools and paths it holds are created, undeleted or
+    /// updated back to their snapshot state, the active ones it does not hold are soft-deleted,
+    /// and the config is set back
+    ///
+    /// The changes are planned before anything is written, then a snapshot of the current
+    /// configuration is taken, restoring it undoes the restore. The changes are made in a
+    /// transaction when the deployment supports them, so a failure leaves the configuration
+    /// unchanged. Otherwise they are made in bulk and a failure midway is reported in the
+    /// response as `partial`, with the counts of the changes made.
+    ///
+    /// # Arguments
+    /// * `db_service` - MongoDB service
+    /// * `db` - Database reference
+    /// * `actor` - Name of the API key making the change
+    /// * `version` - Version of the snapshot to restore
+    ///
+    /// # Returns
+    /// * `Ok(RestoreConfigSnapshotResponse)` - Changes made, by kind of record
+    /// * `Err(anyhow::Error)` - Error if the snapshot is not found or the restore fails with no
+    ///   change made, which is always the case in a transaction
+    pub async fn restore_snapshot(
+        db_service: &MongoDbService,
+        db: &Database,
+        actor: &str,
+        version: u64,
+    ) -> anyhow::Result<RestoreConfigSnapshotResponse> {
+        let snapshot = Self::find_snapshot(db_service, version).await?;
+        let now = Utc::now().timestamp() as u64;
+        let pools: Vec<Pool> = snapshot
+            .pools
+            .into_iter()
+            .map(|pool| Pool::new(pool.network_id, pool.address))
+            .collect();
+        let plan = RestorePlan {
+            networks: Self::plan_records(
+                Self::load_current(db, &snapshot.networks).await?,
+                snapshot.networks,
+                now,
+            )?,
+            pools: Self::plan_records(Self::load_current(db, &pools).await?, pools, now)?,
+            paths: Self::plan_records(
+                Self::load_current(db, &snapshot.paths).await?,
+                snapshot.paths,
+                now,
+            )?,
+            config: Self::plan_config(
+                db.collection::<Config>("configs").find_one(doc! {}).await?,
+                snapshot.config,
+                now,
+            )?
+            .into_iter()
+            .collect(),
+        };
+
+        let backup = Self::take_snapshot(
+            db_service,
+            db,
+            actor,
+            Some(format!("Before restoring version {}", version)),
+        )
+        .await?;
+        debug!(
+            "Restoring config snapshot version {}, backed up as version {}",
+            version, backup.version
+        );
+
+        let mut writes = db_service.get_client().start_writes().await?;
+        let transactional = writes.is_transactional();
+        let mut applied = RestoreProgress::default();
+        let result = match Self::apply(db, writes.session(), &plan, now, &mut applied).await {
+            Ok(()) => writes.commit().await,
+            Err(e) => {
+                writes.abort().await;
+                Err(e)
+            }
+        };
+        let error = match result {
+            Ok(()) => None,
+            Err(e) if transactional || applied.is_empty() => {
+                return Err(anyhow::anyhow!(
+                    "Failed to restore config snapshot version {}, no change was made: {}",
+                    version,
+                    e
+                ))
+            }
+            Err(e) => Some(e.to_string()),
+        };
+
+        AuditService::record_changes(db, actor, &plan.networks.changes[..applied.networks]).await;
+        AuditService::record_changes(db, actor, &plan.pools.changes[..applied.pools]).await;
+        AuditService::record_changes(db, actor, &plan.paths.changes[..applied.paths]).await;
+        AuditService::record_changes(db, actor, &plan.config[..applied.config]).await;
+
+        let response = RestoreConfigSnapshotResponse {
+            version,
+            backup_version: backup.version,
+            transactional,
+            partial: error.is_some(),
+            error,
+            networks: Self::count_changes(&plan.networks, applied.networks),
+            pools: Self::count_changes(&plan.pools, applied.pools),
+            paths: Self::count_changes(&plan.paths, applied.paths),
+            config_updated: applied.config > 0,
+        };
+        AuditService::record(
+            db,
+            actor,
+            AuditAction::Restore,
+            AuditEntity::ConfigSnapshot,
+            &version.to_string(),
+            None,
+            Some(&response),
+        )
+        .await;
+        Ok(response)
+    }
+
+    /// Stored records a restore of `records` can change: the active ones and the deleted ones
+    /// with the identity of a record of the snapshot
+    async fn load_current<T: Restorable>(db: &Database, records: &[T]) -> anyhow::Result<Vec<T>> {
+        let mut filters = vec![Self::active()];
+        filters.extend(T::key_filters(records));
+
+        Ok(db
+            .collection::<T>(T::COLLECTION)
+            .find(doc! { "$or": filters })
+            .await?
+            .try_collect()
+            .await?)
+    }
+
+    /// Changes that put one kind of record back in the state of a snapshot
+    fn plan_records<T: Restorable>(
+        current: Vec<T>,
+        records: Vec<T>,
+        now: u64,
+    ) -> anyhow::Result<RecordPlan<T>> {
+        let mut current: BTreeMap<String, T> = current
+            .into_iter()
+            .map(|record| (record.key(), record))
+            .collect();
+        let mut plan = RecordPlan::default();
+        let mut updates = Vec::new();
+
+        for mut record in records {
+            match current.remove(&record.key()) {
+                None => {
+                    // Keep the snapshot id so the record is the same in later snapshots
+                    let id = record.id().unwrap_or_else(ObjectId::new);
+                    record.stamp(Some(id), now, now, None);
+                    plan.changes.push(RecordChange {
+                        action: AuditAction::Create,
+                        before: None,
+                        after: record,
+                    });
+                }
+                Some(existing) => {
+                    let action = if existing.deleted_at().is_some() {
+                        AuditAction::Undelete
+                    } else if Self::differs(&existing, &record)? {
+                        AuditAction::Update
+                    } else {
+                        plan.unchanged += 1;
+                        continue;
+                    };
+                    record.stamp(existing.id(), existing.created_at(), now, None);
+                    updates.push(RecordChange {
+                        action,
+                        before: Some(existing),
+                        after: record,
+                    });
+                }
+            }
+        }
+        plan.changes.extend(updates);
+
+        for existing in current
+            .into_values()
+            .filter(|record| record.deleted_at().is_none())
+        {
+            let mut deleted = existing.clone();
+            deleted.stamp(existing.id(), existing.created_at(), now, Some(now));
+            plan.changes.push(RecordChange {
+                action: AuditAction::Delete,
+                before: Some(existing),
+                after: deleted,
+            });
+        }
+
+        Ok(plan)
+    }
+
+    /// Change that sets the config back to the snapshot, none if the snapshot has no config
+    /// or the config is the same
+    fn plan_config(
+        existing: Option<Config>,
+        config: Option<Config>,
+        now: u64,
+    ) -> anyhow::Result<Option<RecordChange<Config>>> {
+        let Some(mut config) = config else {
+            return Ok(None);
+        };
+        let action = match &existing {
+            Some(existing) => {
+                if !Self::differs(existing, &config)? {
+                    return Ok(None);
+                }
+                config.id = existing.id;
+                config.created_at = existing.created_at;
+                AuditAction::Update
+            }
+            None => {
+                config.id = Some(config.id.unwrap_or_else(ObjectId::new));
+                config.created_at = now;
+                AuditAction::Create
+            }
+        };
+        config.updated_at = now;
+
+        Ok(Some(RecordChange {
+            action,
+            before: existing,
+            after: config,
+        }))
+    }
+
+    /// Make the changes of a restore plan, counting in `applied` the changes made
+    async fn apply(
+        db: &Database,
+        session: &mut ClientSession,
+        plan: &RestorePlan,
+        now: u64,
+        applied: &mut RestoreProgress,
+    ) -> anyhow::Result<()> {
+        apply_changes(
+            db,
+            session,
+            &plan.networks.changes,
+            now,
+            &mut applied.networks,
+        )
+        .await?;
+        apply_changes(db, session, &plan.pools.changes, now, &mut applied.pools).await?;
+        apply_changes(db, session, &plan.paths.changes, now, &mut applied.paths).await?;
+        apply_changes(db, session, &plan.config, now, &mut applied.config).await
+    }
+
+    /// Counts of the first `applied` changes of a plan, the unchanged records included
+    fn count_changes<T>(plan: &RecordPlan<T>, applied: usize) -> RestoreCounts {
+        let mut counts = RestoreCounts {
+            unchanged: plan.unchanged,
+            ..RestoreCounts::default()
+        };
+        for change in &plan.changes[..applied] {
+            match change.action {
+                AuditAction::Create => counts.created += 1,
+                AuditAction::Undelete => counts.undeleted += 1,
+                AuditAction::Delete => counts.deleted += 1,
+                _ => counts.updated += 1,
+            }
+        }
+        counts
+    }
+
+    /// Map ConfigSnapshotSummary model to ConfigSnapshotSummaryResponse DTO
+    fn map_to_summary_response(summary: ConfigSnapshotSummary) -> ConfigSnapshotSummaryResponse {
+        let id = summary
+            .id
+            .map(|oid| oid.to_hex())
+            .unwrap_or_else(|| "unknown".to_string());
+
+        ConfigSnapshotSummaryResponse {
+            id,
+            version: summary.version,
+            description: summary.description,
+            created_by: summary.created_by,
+            networks: summary.networks,
+            pools: summary.pools,
+            paths: summary.paths,
+            created_at: summary.created_at,
+        }
+    }
+
+    /// Map ConfigSnapshot model to ConfigSnapshotResponse DTO
+    fn map_to_response(snapshot: ConfigSnapshot) -> ConfigSnapshotResponse {
+        let id = snapshot
+            .id
+            .map(|oid| oid.to_hex())
+            .unwrap_or_else(|| "unknown".to_string());
+
+        ConfigSnapshotResponse {
+            id,
+            version: snapshot.version,
+            description: snapshot.description,
+            created_by: snapshot.created_by,
+            networks: snapshot
+                .networks
+                .into_iter()
+                .map(|network| NetworkService::map_to_response(network, HashMap::new()))
+                .collect(),
+            pools: snapshot.pools,
+            paths: snapshot
+                .paths
+                .into_iter()
+                .map(PathService::map_to_response)
+                .collect(),
+            config: snapshot.config.map(ConfigService::map_to_response),
+            created_at: snapshot.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_diff_records() {
+        let before = BTreeMap::from([
+            (
+                "1".to_string(),
+                json!({ "chain_id": 1, "name": "Ethereum" }),
+            ),
+            (
+                "10".to_string(),
+                json!({ "chain_id": 10, "name": "Optimism" }),
+            ),
+            ("56".to_string(), json!({ "chain_id": 56, "name": "BSC" })),
+        ]);
+        let after = BTreeMap::from([
+            (
+                "1".to_string(),
+                json!({ "chain_id": 1, "name": "Ethereum" }),
+            ),
+            (
+                "10".to_string(),
+                json!({ "chain_id": 10, "name": "OP Mainnet" }),
+            ),
+            (
+                "8453".to_string(),
+                json!({ "chain_id": 8453, "name": "Base" }),
+            ),
+        ]);
+
+        let diff = ConfigSnapshotService::diff_records(before, after);
+        assert_eq!(diff.added, vec!["8453"]);
+        assert_eq!(diff.removed, vec!["56"]);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].id, "10");
+        assert_eq!(diff.changed[0].changes[0].field, "name");
+        assert_eq!(diff.changed[0].changes[0].after, json!("OP Mainnet"));
+    }
+
+    #[test]
+    fn test_restorable_keys() {
+        let pool = Pool::new(8453, "0xabc".to_string());
+        assert_eq!(pool.key(), "8453:0xabc");
+
+        let mut path = Path::new(vec![]);
+        let id = ObjectId::new();
+        path.stamp(Some(id), 1, 2, None);
+        assert_eq!(path.key(), id.to_hex());
+        assert_eq!(path.created_at(), 1);
+        assert_eq!(path.deleted_at(), None);
+    }
+
+    #[test]
+    fn test_plan_records() {
+        let now = 1_000;
+        let stored = |address: &str, deleted_at: Option<u64>| {
+            let mut pool = Pool::new(8453, address.to_string());
+            pool.stamp(Some(ObjectId::new()), 1, 1, deleted_at);
+            pool
+        };
+        let current = vec![
+            stored("0xa", None),
+            stored("0xb", Some(500)),
+            stored("0xc", None),
+            stored("0xd", None),
+        ];
+        let snapshot = vec![
+            Pool::new(8453, "0xb".to_string()),
+            Pool::new(8453, "0xe".to_string()),
+            Pool::new(8453, "0xa".to_string()),
+        ];
+
+        let plan = ConfigSnapshotService::plan_records(current.clone(), snapshot, now).unwrap();
+        let actions: Vec<(AuditAction, &str)> = plan
+            .changes
+            .iter()
+            .map(|change| (change.action, change.after.address.as_str()))
+            .collect();
+        // Creates first, then undeletes and updates, then deletes
+        assert_eq!(
+            actions,
+            vec![
+                (AuditAction::Create, "0xe"),
+                (AuditAction::Undelete, "0xb"),
+                (AuditAction::Delete, "0xc"),
+                (AuditAction::Delete, "0xd"),
+            ]
+        );
+        assert_eq!(plan.unchanged, 1);
+
+        let undelete = &plan.changes[1];
+        assert_eq!(undelete.after.id, current[1].id);
+        assert_eq!(undelete.after.created_at, 1);
+        assert_eq!(undelete.after.deleted_at, None);
+        assert_eq!(plan.changes[2].after.deleted_at, Some(now));
+        assert!(plan.changes[0].after.id.is_some());
+
+        let counts = ConfigSnapshotService::count_changes(&plan, 3);
+        assert_eq!(
+            (
+                counts.created,
+                counts.undeleted,
+                counts.deleted,
+                counts.unchanged
+            ),
+            (1, 1, 1, 1)
+        );
+    }
+
+    #[test]
+    fn test_pool_key_filters() {
+        let pools = vec![
+            Pool::new(8453, "0xa".to_string()),
+            Pool::new(1, "0xb".to_string()),
+            Pool::new(8453, "0xc".to_string()),
+        ];
+        assert_eq!(
+            Pool::key_filters(&pools),
+            vec![
+                doc! { "network_id": 1_i64, "address": { "$in": ["0xb"] } },
+                doc! { "network_id": 8453_i64, "address": { "$in": ["0xa", "0xc"] } },
+            ]
+        );
+    }
+}
@@ -1,7 +1,19 @@
 // Re-export handlers from submodules
+pub use super::analytics::*;
+pub use super::api_key::*;
+pub use super::audit::*;
+pub use super::bundle::*;
 pub use super::config::*;
+pub use super::config_snapshot::*;
+pub use super::indexer::*;
 pub use super::network::*;
+pub use super::opportunity::*;
 pub use super::path::*;
 pub use super::pool::*;
+pub use super::price::*;
+pub use super::profit_token::*;
+pub use super::rpc_health::*;
+pub use super::search::*;
+pub use super::sync::*;
 pub use super::token::*;
-// pub use super::opportunity::*;
+pub use super::ws::*;
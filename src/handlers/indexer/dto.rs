@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+// Response model for a background indexer
+#[derive(Debug, Serialize)]
+pub struct IndexerResponse {
+    pub network_id: u64,
+    pub indexer: String,
+    pub running: bool,
+    pub current_block: Option<u64>, // last block processed in this process
+    pub last_block: Option<u64>,    // last block persisted in the checkpoint
+    pub chain_head: Option<u64>,
+    pub lag: Option<u64>,
+    pub last_error: Option<String>,
+    pub last_run_at: Option<u64>,
+    pub backfill: Option<BackfillResponse>,
+}
+
+// Response model for the latest backfill of an indexer
+#[derive(Debug, Serialize)]
+pub struct BackfillResponse {
+    pub from_block: u64,
+    pub to_block: u64,
+    pub current_block: u64,
+    pub finished: bool,
+    pub error: Option<String>,
+    pub started_at: u64,
+    pub finished_at: Option<u64>,
+}
+
+// Request model for triggering a historical backfill
+#[derive(Debug, Deserialize)]
+pub struct BackfillRequest {
+    pub from_block: u64,
+    pub to_block: u64,
+}
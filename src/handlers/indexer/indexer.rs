@@ -0,0 +1,203 @@
+use actix_web::{web, HttpResponse};
+use log::{error, info};
+use mongodb::Database;
+
+use crate::{
+    errors::ApiError,
+    handlers::{
+        config::auth::ApiKey,
+        indexer::{dto::BackfillRequest, service::IndexerService},
+    },
+    services::IndexerManager,
+};
+
+/// Map an indexer control error to the matching API error
+fn map_control_error(action: &str, network_id: u64, e: anyhow::Error) -> ApiError {
+    error!(
+        "Failed to {} indexer for network {}: {}",
+        action, network_id, e
+    );
+    let message = e.to_string();
+    if message.contains("RPC request failed") || message.contains("has no usable RPC") {
+        ApiError::UpstreamError(message)
+    } else if message.contains("not found") {
+        ApiError::NotFound(message)
+    } else if message.contains("already")
+        || message.contains("Invalid block range")
+        || message.contains("is deleted")
+    {
+        ApiError::BadRequest(message)
+    } else {
+        ApiError::DatabaseError(format!("Failed to {} indexer: {}", action, e))
+    }
+}
+
+/// GET /indexers - Returns all indexers with their runtime status
+///
+/// # Arguments
+/// * `db` - Database connection
+/// * `manager` - Indexer manager
+///
+/// # Returns
+/// JSON array of IndexerResponse objects
+pub async fn get_indexers_handler(
+    db: web::Data<Database>,
+    manager: web::Data<IndexerManager>,
+) -> Result<HttpResponse, ApiError> {
+    info!("Handling GET /indexers request");
+
+    match IndexerService::get_indexers(&db, &manager).await {
+        Ok(indexers) => {
+            info!("Successfully retrieved {} indexers", indexers.len());
+            Ok(HttpResponse::Ok().json(indexers))
+        }
+        Err(e) => {
+            error!("Failed to retrieve indexers: {}", e);
+            Err(ApiError::DatabaseError(format!(
+                "Failed to retrieve indexers: {}",
+                e
+            )))
+        }
+    }
+}
+
+/// GET /indexers/network/{network_id} - Returns the indexer status of a network
+///
+/// # Arguments
+/// * `db` - Database connection
+/// * `manager` - Indexer manager
+/// * `path` - Path parameters containing network_id
+///
+/// # Returns
+/// JSON object of IndexerResponse
+pub async fn get_indexer_handler(
+    db: web::Data<Database>,
+    manager: web::Data<IndexerManager>,
+    path: web::Path<u64>,
+) -> Result<HttpResponse, ApiError> {
+    let network_id = path.into_inner();
+    info!("Handling GET /indexers/network/{} request", network_id);
+
+    match IndexerService::get_indexer(&db, &manager, network_id).await {
+        Ok(Some(indexer)) => {
+            info!("Successfully retrieved indexer for network {}", network_id);
+            Ok(HttpResponse::Ok().json(indexer))
+        }
+        Ok(None) => {
+            info!("Indexer for network {} not found", network_id);
+            Err(ApiError::NotFound(format!(
+                "Indexer for network {} not found",
+                network_id
+            )))
+        }
+        Err(e) => {
+            error!("Failed to retrieve indexer {}: {}", network_id, e);
+            Err(ApiError::DatabaseError(format!(
+                "Failed to retrieve indexer: {}",
+                e
+            )))
+        }
+    }
+}
+
+/// POST /indexers/network/{network_id}/pause - Pauses the indexer of a network
+/// Requires API key authentication via X-API-Key header
+///
+/// # Arguments
+/// * `_api_key` - API key from X-API-Key header (validated by extractor)
+/// * `db` - Database connection
+/// * `manager` - Indexer manager
+/// * `path` - Path parameters containing network_id
+///
+/// # Returns
+/// JSON object of IndexerResponse
+pub async fn pause_indexer_handler(
+    _api_key: ApiKey,
+    db: web::Data<Database>,
+    manager: web::Data<IndexerManager>,
+    path: web::Path<u64>,
+) -> Result<HttpResponse, ApiError> {
+    let network_id = path.into_inner();
+    info!(
+        "Handling POST /indexers/network/{}/pause request",
+        network_id
+    );
+
+    match IndexerService::pause_indexer(&db, &manager, network_id).await {
+        Ok(indexer) => {
+            info!("Successfully paused indexer for network {}", network_id);
+            Ok(HttpResponse::Ok().json(indexer))
+        }
+        Err(e) => Err(map_control_error("pause", network_id, e)),
+    }
+}
+
+/// POST /indexers/network/{network_id}/resume - Resumes (or starts) the indexer of a network
+/// Requires API key authentication via X-API-Key header
+///
+/// # Arguments
+/// * `_api_key` - API key from X-API-Key header (validated by extractor)
+/// * `db` - Database connection
+/// * `manager` - Indexer manager
+/// * `path` - Path parameters containing network_id
+///
+/// # Returns
+/// JSON object of IndexerResponse
+pub async fn resume_indexer_handler(
+    _api_key: ApiKey,
+    db: web::Data<Database>,
+    manager: web::Data<IndexerManager>,
+    path: web::Path<u64>,
+) -> Result<HttpResponse, ApiError> {
+    let network_id = path.into_inner();
+    info!(
+        "Handling POST /indexers/network/{}/resume request",
+        network_id
+    );
+
+    match IndexerService::resume_indexer(&db, &manager, network_id).await {
+        Ok(indexer) => {
+            info!("Successfully resumed indexer for network {}", network_id);
+            Ok(HttpResponse::Ok().json(indexer))
+        }
+        Err(e) => Err(map_control_error("resume", network_id, e)),
+    }
+}
+
+/// POST /indexers/network/{network_id}/backfill - Triggers a historical backfill
+/// Requires API key authentication via X-API-Key header
+///
+/// # Arguments
+/// * `_api_key` - API key from X-API-Key header (validated by extractor)
+/// * `db` - Database connection
+/// * `manager` - Indexer manager
+/// * `path` - Path parameters containing network_id
+/// * `body` - BackfillRequest containing the block range
+///
+/// # Returns
+/// 202 Accepted with the IndexerResponse, the backfill runs in the background
+pub async fn backfill_indexer_handler(
+    _api_key: ApiKey,
+    db: web::Data<Database>,
+    manager: web::Data<IndexerManager>,
+    path: web::Path<u64>,
+    body: web::Json<BackfillRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let network_id = path.into_inner();
+    info!(
+        "Handling POST /indexers/network/{}/backfill request",
+        network_id
+    );
+
+    match IndexerService::backfill(&db, &manager, network_id, body.from_block, body.to_block).await
+    {
+        Ok(indexer) => {
+            info!(
+                "Started backfill for network {} from block {} to {}",
+                network_id, body.from_block, body.to_block
+            );
+            Ok(HttpResponse::Accepted().json(indexer))
+        }
+        Err(e) => Err(map_control_error("backfill", network_id, e)),
+    }
+}
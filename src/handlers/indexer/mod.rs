@@ -0,0 +1,6 @@
+pub mod dto;
+pub mod indexer;
+pub mod service;
+
+pub use dto::*;
+pub use indexer::*;
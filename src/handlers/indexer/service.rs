@@ -0,0 +1,182 @@
+use futures::TryStreamExt;
+use log::debug;
+use mongodb::bson::doc;
+use mongodb::Database;
+use std::collections::HashMap;
+
+use crate::{
+    database::models::{checkpoint::POOL_INDEXER, IndexerCheckpoint},
+    handlers::indexer::dto::{BackfillResponse, IndexerResponse},
+    services::{IndexerManager, IndexerState},
+};
+
+/// Service layer for indexer-related business logic
+pub struct IndexerService;
+
+impl IndexerService {
+    /// Get all indexers known to the manager
+    ///
+    /// # Arguments
+    /// * `db` - Database reference
+    /// * `manager` - Indexer manager holding the runtime state
+    ///
+    /// # Returns
+    /// * `Ok(Vec<IndexerResponse>)` - List of indexers
+    /// * `Err(anyhow::Error)` - Error if database operation fails
+    pub async fn get_indexers(
+        db: &Database,
+        manager: &IndexerManager,
+    ) -> anyhow::Result<Vec<IndexerResponse>> {
+        debug!("Fetching all indexers");
+
+        let collection = db.collection::<IndexerCheckpoint>("indexer_checkpoints");
+        let filter = doc! { "indexer": POOL_INDEXER };
+        let mut cursor = collection.find(filter).await?;
+        let mut last_blocks = HashMap::new();
+        while let Some(checkpoint) = cursor.try_next().await? {
+            last_blocks.insert(checkpoint.network_id, checkpoint.last_block);
+        }
+
+        let indexers = manager
+            .get_states()
+            .into_iter()
+            .map(|(network_id, state)| {
+                Self::map_to_response(network_id, &state, last_blocks.get(&network_id).copied())
+            })
+            .collect();
+
+        Ok(indexers)
+    }
+
+    /// Get the indexer of a network
+    ///
+    /// # Arguments
+    /// * `db` - Database reference
+    /// * `manager` - Indexer manager holding the runtime state
+    /// * `network_id` - The chain_id of the network
+    ///
+    /// # Returns
+    /// * `Ok(Option<IndexerResponse>)` - Indexer if one was started for the network
+    /// * `Err(anyhow::Error)` - Error if database operation fails
+    pub async fn get_indexer(
+        db: &Database,
+        manager: &IndexerManager,
+        network_id: u64,
+    ) -> anyhow::Result<Option<IndexerResponse>> {
+        debug!("Fetching indexer for network {}", network_id);
+
+        let Some(state) = manager.get_state(network_id) else {
+            return Ok(None);
+        };
+
+        let collection = db.collection::<IndexerCheckpoint>("indexer_checkpoints");
+        let filter = doc! {
+            "network_id": network_id as i64,
+            "indexer": POOL_INDEXER
+        };
+        let last_block = collection
+            .find_one(filter)
+            .await?
+            .map(|checkpoint| checkpoint.last_block);
+
+        Ok(Some(Self::map_to_response(network_id, &state, last_block)))
+    }
+
+    /// Pause the indexer of a network
+    ///
+    /// # Returns
+    /// * `Ok(IndexerResponse)` - Paused indexer
+    /// * `Err(anyhow::Error)` - Error if the indexer is not found or not running
+    pub async fn pause_indexer(
+        db: &Database,
+        manager: &IndexerManager,
+        network_id: u64,
+    ) -> anyhow::Result<IndexerResponse> {
+        debug!("Pausing indexer for network {}", network_id);
+
+        manager.pause_network(network_id)?;
+        Self::get_indexer(db, manager, network_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Indexer for network {} not found", network_id))
+    }
+
+    /// Resume (or start) the indexer of a network
+    ///
+    /// # Returns
+    /// * `Ok(IndexerResponse)` - Resumed indexer
+    /// * `Err(anyhow::Error)` - Error if the network is not found or the indexer is running
+    pub async fn resume_indexer(
+        db: &Database,
+        manager: &IndexerManager,
+        network_id: u64,
+    ) -> anyhow::Result<IndexerResponse> {
+        debug!("Resuming indexer for network {}", network_id);
+
+        manager.start_network(network_id).await?;
+        Self::get_indexer(db, manager, network_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Indexer for network {} not found", network_id))
+    }
+
+    /// Trigger a historical backfill of `from_block..=to_block` on a network
+    ///
+    /// # Returns
+    /// * `Ok(IndexerResponse)` - Indexer with the started backfill
+    /// * `Err(anyhow::Error)` - Error if the range is invalid or a backfill is in progress
+    pub async fn backfill(
+        db: &Database,
+        manager: &IndexerManager,
+        network_id: u64,
+        from_block: u64,
+        to_block: u64,
+    ) -> anyhow::Result<IndexerResponse> {
+        debug!(
+            "Starting backfill for network {} from block {} to {}",
+            network_id, from_block, to_block
+        );
+
+        manager.backfill(network_id, from_block, to_block).await?;
+        Self::get_indexer(db, manager, network_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Indexer for network {} not found", network_id))
+    }
+
+    /// Map runtime indexer state to IndexerResponse DTO
+    ///
+    /// # Arguments
+    /// * `network_id` - The chain_id of the network
+    /// * `state` - Runtime state of the indexer
+    /// * `last_block` - Last block stored in the checkpoint
+    ///
+    /// # Returns
+    /// IndexerResponse DTO
+    fn map_to_response(
+        network_id: u64,
+        state: &IndexerState,
+        last_block: Option<u64>,
+    ) -> IndexerResponse {
+        let status = state.status();
+        let lag = status.lag();
+
+        IndexerResponse {
+            network_id,
+            indexer: POOL_INDEXER.to_string(),
+            running: state.is_running(),
+            current_block: status.current_block,
+            last_block,
+            chain_head: status.chain_head,
+            lag,
+            last_error: status.last_error,
+            last_run_at: status.last_run_at,
+            backfill: status.backfill.map(|backfill| BackfillResponse {
+                from_block: backfill.from_block,
+                to_block: backfill.to_block,
+                current_block: backfill.current_block,
+                finished: backfill.finished,
+                error: backfill.error,
+                started_at: backfill.started_at,
+                finished_at: backfill.finished_at,
+            }),
+        }
+    }
+}
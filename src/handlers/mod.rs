@@ -1,12 +1,38 @@
+pub mod analytics;
+pub mod api_key;
+pub mod audit;
+pub mod bundle;
 pub mod config;
+pub mod config_snapshot;
 pub mod handlers;
+pub mod indexer;
 pub mod network;
+pub mod opportunity;
 pub mod path;
 pub mod pool;
+pub mod price;
+pub mod profit_token;
+pub mod rpc_health;
+pub mod search;
+pub mod sync;
 pub mod token;
+pub mod ws;
 
+pub use analytics::*;
+pub use api_key::*;
+pub use audit::*;
+pub use bundle::*;
 pub use config::*;
+pub use config_snapshot::*;
+pub use indexer::*;
 pub use network::*;
+pub use opportunity::*;
 pub use path::*;
 pub use pool::*;
+pub use price::*;
+pub use profit_token::*;
+pub use rpc_health::*;
+pub use search::*;
+pub use sync::*;
 pub use token::*;
+pub use ws::*;
@@ -15,15 +15,17 @@ pub struct NetworkResponse {
     pub v2_factory_to_fee: Option<HashMap<String, u64>>,
     pub aero_factory_addresses: Option<Vec<String>>,
     pub multicall_address: Option<String>,
+    pub price_network: Option<String>,
     pub max_blocks_per_batch: u64,
     pub wait_time_fetch: u64,
+    pub checkpoints: HashMap<String, u64>, // indexer name -> last indexed block
     pub created_at: u64,
     pub updated_at: u64,
     pub deleted: bool,
 }
 
-// Request model for creating a new network
-#[derive(Debug, Deserialize)]
+// Request model for creating a new network, also the network format of config bundles
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CreateNetworkRequest {
     pub chain_id: u64,
     pub name: String,
@@ -35,6 +37,7 @@ pub struct CreateNetworkRequest {
     pub v2_factory_to_fee: Option<HashMap<String, u64>>,
     pub aero_factory_addresses: Option<Vec<String>>,
     pub multicall_address: Option<String>,
+    pub price_network: Option<String>, // GeckoTerminal network slug, prices are not fetched without it
     pub max_blocks_per_batch: u64,
     pub wait_time_fetch: u64,
 }
@@ -51,6 +54,7 @@ pub struct UpdateNetworkRequest {
     pub v2_factory_to_fee: Option<HashMap<String, u64>>,
     pub aero_factory_addresses: Option<Vec<String>>,
     pub multicall_address: Option<String>,
+    pub price_network: Option<String>,
     pub max_blocks_per_batch: Option<u64>,
     pub wait_time_fetch: Option<u64>,
 }
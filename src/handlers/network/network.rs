@@ -3,9 +3,10 @@ use log::{error, info};
 use mongodb::Database;
 
 use crate::{
+    database::{repositories::ListQuery, MongoDbService},
     errors::ApiError,
     handlers::{
-        config::auth::ApiKey,
+        config::auth::{ApiKey, HardDeleteApiKey},
         network::{
             dto::{CreateNetworkRequest, UpdateFactoriesRequest, UpdateNetworkRequest},
             service::NetworkService,
@@ -13,24 +14,40 @@ use crate::{
     },
 };
 
-/// GET /networks - Returns all networks
+/// GET /networks - Returns a page of networks with their indexer checkpoints
+///
+/// # Arguments
+/// * `db_service` - MongoDB service
+/// * `query` - Query parameters with pagination (limit, page or cursor), sort and filters
+///   (include_deleted, updated_since)
 ///
 /// # Returns
-/// JSON array of NetworkResponse objects containing network information
-pub async fn get_networks_handler(db: web::Data<Database>) -> Result<HttpResponse, ApiError> {
+/// JSON object of Page<NetworkResponse> with the total count
+pub async fn get_networks_handler(
+    db_service: web::Data<MongoDbService>,
+    query: web::Query<ListQuery>,
+) -> Result<HttpResponse, ApiError> {
     info!("Handling GET /networks request");
 
-    match NetworkService::get_networks_with_stats(&db).await {
+    match NetworkService::get_networks(&db_service, &query).await {
         Ok(networks) => {
-            info!("Successfully retrieved {} networks", networks.len());
+            info!(
+                "Successfully retrieved {} of {} networks",
+                networks.items.len(),
+                networks.total
+            );
             Ok(HttpResponse::Ok().json(networks))
         }
         Err(e) => {
             error!("Failed to retrieve networks: {}", e);
-            Err(ApiError::DatabaseError(format!(
-                "Failed to retrieve networks: {}",
-                e
-            )))
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to retrieve networks: {}",
+                    e
+                )))
+            }
         }
     }
 }
@@ -76,20 +93,20 @@ pub async fn get_network_by_chain_id_handler(
 /// Requires API key authentication via X-API-Key header
 ///
 /// # Arguments
-/// * `_api_key` - API key from X-API-Key header (validated by extractor)
+/// * `api_key` - API key from X-API-Key header (validated by extractor), recorded in the audit log
 /// * `db` - Database connection
 /// * `body` - CreateNetworkRequest containing network data
 ///
 /// # Returns
 /// JSON object of NetworkResponse containing created network information
 pub async fn create_network_handler(
-    _api_key: ApiKey,
+    api_key: ApiKey,
     db: web::Data<Database>,
     body: web::Json<CreateNetworkRequest>,
 ) -> Result<HttpResponse, ApiError> {
     info!("Handling POST /networks request");
 
-    match NetworkService::create_network(&db, body.into_inner()).await {
+    match NetworkService::create_network(&db, &api_key.name, body.into_inner()).await {
         Ok(network) => {
             info!(
                 "Successfully created network with chain_id: {}",
@@ -115,7 +132,7 @@ pub async fn create_network_handler(
 /// Requires API key authentication via X-API-Key header
 ///
 /// # Arguments
-/// * `_api_key` - API key from X-API-Key header (validated by extractor)
+/// * `api_key` - API key from X-API-Key header (validated by extractor), recorded in the audit log
 /// * `db` - Database connection
 /// * `path` - Path parameters containing chain_id
 /// * `body` - UpdateNetworkRequest containing fields to update
@@ -123,7 +140,7 @@ pub async fn create_network_handler(
 /// # Returns
 /// JSON object of NetworkResponse containing updated network information
 pub async fn update_network_handler(
-    _api_key: ApiKey,
+    api_key: ApiKey,
     db: web::Data<Database>,
     path: web::Path<u64>,
     body: web::Json<UpdateNetworkRequest>,
@@ -131,14 +148,16 @@ pub async fn update_network_handler(
     let chain_id = path.into_inner();
     info!("Handling PUT /networks/{} request", chain_id);
 
-    match NetworkService::update_network(&db, chain_id, body.into_inner()).await {
+    match NetworkService::update_network(&db, &api_key.name, chain_id, body.into_inner()).await {
         Ok(network) => {
             info!("Successfully updated network with chain_id: {}", chain_id);
             Ok(HttpResponse::Ok().json(network))
         }
         Err(e) => {
             error!("Failed to update network {}: {}", chain_id, e);
-            if e.to_string().contains("not found") {
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else if e.to_string().contains("not found") {
                 Err(ApiError::NotFound(format!(
                     "Network with chain_id {} not found",
                     chain_id
@@ -157,20 +176,20 @@ pub async fn update_network_handler(
 /// Requires API key authentication via X-API-Key header
 ///
 /// # Arguments
-/// * `_api_key` - API key from X-API-Key header (validated by extractor)
+/// * `api_key` - API key from X-API-Key header (validated by extractor), recorded in the audit log
 /// * `db` - Database connection
 /// * `path` - Path parameters containing chain_id
 ///
 /// # Returns
 /// JSON object of NetworkResponse containing undeleted network information
 pub async fn undelete_network_handler(
-    _api_key: ApiKey,
+    api_key: ApiKey,
     db: web::Data<Database>,
     path: web::Path<u64>,
 ) -> Result<HttpResponse, ApiError> {
     let chain_id = path.into_inner();
     info!("Handling POST /networks/{}/undelete request", chain_id);
-    match NetworkService::undelete_network(&db, chain_id).await {
+    match NetworkService::undelete_network(&db, &api_key.name, chain_id).await {
         Ok(network) => {
             info!("Successfully undeleted network with chain_id: {}", chain_id);
             Ok(HttpResponse::Ok().json(network))
@@ -196,7 +215,7 @@ pub async fn undelete_network_handler(
 /// Requires API key authentication via X-API-Key header
 ///
 /// # Arguments
-/// * `_api_key` - API key from X-API-Key header (validated by extractor)
+/// * `api_key` - API key from X-API-Key header (validated by extractor), recorded in the audit log
 /// * `db` - Database connection
 /// * `path` - Path parameters containing chain_id
 /// * `body` - UpdateFactoriesRequest containing both factory mappings
@@ -204,7 +223,7 @@ pub async fn undelete_network_handler(
 /// # Returns
 /// JSON object of NetworkResponse containing updated network information
 pub async fn update_factories_handler(
-    _api_key: ApiKey,
+    api_key: ApiKey,
     db: web::Data<Database>,
     path: web::Path<u64>,
     body: web::Json<UpdateFactoriesRequest>,
@@ -212,7 +231,7 @@ pub async fn update_factories_handler(
     let chain_id = path.into_inner();
     info!("Handling PUT /networks/{}/factories request", chain_id);
 
-    match NetworkService::update_factories(&db, chain_id, body.into_inner()).await {
+    match NetworkService::update_factories(&db, &api_key.name, chain_id, body.into_inner()).await {
         Ok(network) => {
             info!(
                 "Successfully updated factories for network with chain_id: {}",
@@ -241,21 +260,21 @@ pub async fn update_factories_handler(
 /// Requires API key authentication via X-API-Key header
 ///
 /// # Arguments
-/// * `_api_key` - API key from X-API-Key header (validated by extractor)
+/// * `api_key` - API key from X-API-Key header (validated by extractor), recorded in the audit log
 /// * `db` - Database connection
 /// * `path` - Path parameters containing chain_id
 ///
 /// # Returns
 /// 204 No Content on success
 pub async fn delete_network_handler(
-    _api_key: ApiKey,
+    api_key: ApiKey,
     db: web::Data<Database>,
     path: web::Path<u64>,
 ) -> Result<HttpResponse, ApiError> {
     let chain_id = path.into_inner();
     info!("Handling DELETE /networks/{} request", chain_id);
 
-    match NetworkService::delete_network(&db, chain_id).await {
+    match NetworkService::delete_network(&db, &api_key.name, chain_id).await {
         Ok(_) => {
             info!("Successfully deleted network with chain_id: {}", chain_id);
             Ok(HttpResponse::NoContent().finish())
@@ -279,16 +298,16 @@ pub async fn delete_network_handler(
 
 /// DELETE /networks/{chain_id}/hard - Hard deletes a network (permanently removes from database)
 /// Only works on networks that are already soft-deleted
-/// Requires API key authentication via X-API-Key header
+/// Requires an API key with the hard_delete scope via X-API-Key header
 pub async fn hard_delete_network_handler(
-    _api_key: ApiKey,
+    api_key: HardDeleteApiKey,
     db: web::Data<Database>,
     path: web::Path<u64>,
 ) -> Result<HttpResponse, ApiError> {
     let chain_id = path.into_inner();
     info!("Handling DELETE /networks/{}/hard request", chain_id);
 
-    match NetworkService::hard_delete_network(&db, chain_id).await {
+    match NetworkService::hard_delete_network(&db, &api_key.0.name, chain_id).await {
         Ok(()) => {
             info!(
                 "Successfully hard deleted network with chain_id: {}",
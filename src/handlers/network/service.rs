@@ -3,12 +3,19 @@ use futures::TryStreamExt;
 use log::debug;
 use mongodb::bson::doc;
 use mongodb::Database;
+use std::collections::HashMap;
 use std::str::FromStr;
+use url::Url;
 
 use crate::{
-    database::models::Network,
-    handlers::network::dto::{
-        CreateNetworkRequest, NetworkResponse, UpdateFactoriesRequest, UpdateNetworkRequest,
+    database::models::{AuditAction, AuditEntity, IndexerCheckpoint, Network},
+    database::repositories::{ListQuery, Page},
+    database::MongoDbService,
+    handlers::{
+        audit::service::AuditService,
+        network::dto::{
+            CreateNetworkRequest, NetworkResponse, UpdateFactoriesRequest, UpdateNetworkRequest,
+        },
     },
 };
 
@@ -22,8 +29,49 @@ impl NetworkService {
             .map_err(|e| anyhow::anyhow!("Invalid address format '{}': {}", address, e))
     }
 
-    /// Validate all addresses in a network request
-    fn validate_network_addresses(request: &CreateNetworkRequest) -> anyhow::Result<()> {
+    /// Validate RPC URLs, at least one http(s) URL is needed to build a provider
+    fn validate_rpcs(rpcs: &[String]) -> anyhow::Result<()> {
+        if rpcs.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Invalid rpcs: at least one RPC URL is required"
+            ));
+        }
+        for rpc in rpcs {
+            let url =
+                Url::parse(rpc).map_err(|e| anyhow::anyhow!("Invalid RPC URL '{}': {}", rpc, e))?;
+            if !matches!(url.scheme(), "http" | "https") {
+                return Err(anyhow::anyhow!(
+                    "Invalid RPC URL '{}': must use http or https",
+                    rpc
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate a GeckoTerminal network slug, lowercase letters, digits, `_` and `-`
+    fn validate_price_network(price_network: &str) -> anyhow::Result<()> {
+        if price_network.is_empty()
+            || !price_network
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+        {
+            return Err(anyhow::anyhow!(
+                "Invalid price_network '{}': expected a GeckoTerminal network slug such as eth or polygon_pos",
+                price_network
+            ));
+        }
+        Ok(())
+    }
+
+    /// Validate all addresses and RPC URLs in a network request
+    pub(crate) fn validate_network_addresses(request: &CreateNetworkRequest) -> anyhow::Result<()> {
+        Self::validate_rpcs(&request.rpcs)?;
+
+        if let Some(ref price_network) = request.price_network {
+            Self::validate_price_network(price_network)?;
+        }
+
         // Validate wrap_native
         Self::validate_address(&request.wrap_native)?;
 
@@ -49,8 +97,16 @@ impl NetworkService {
         Ok(())
     }
 
-    /// Validate addresses in an update request
+    /// Validate addresses and RPC URLs in an update request
     fn validate_update_addresses(request: &UpdateNetworkRequest) -> anyhow::Result<()> {
+        if let Some(ref rpcs) = request.rpcs {
+            Self::validate_rpcs(rpcs)?;
+        }
+
+        if let Some(ref price_network) = request.price_network {
+            Self::validate_price_network(price_network)?;
+        }
+
         if let Some(ref addr) = request.wrap_native {
             Self::validate_address(addr)?;
         }
@@ -88,28 +144,86 @@ impl NetworkService {
 
         Ok(())
     }
-    /// Get all networks
+
+    /// Load indexer checkpoints for a network as indexer name -> last indexed block
+    async fn get_checkpoints(db: &Database, chain_id: u64) -> anyhow::Result<HashMap<String, u64>> {
+        let collection = db.collection::<IndexerCheckpoint>("indexer_checkpoints");
+        let filter = doc! { "network_id": chain_id as i64 };
+        let mut cursor = collection.find(filter).await?;
+        let mut checkpoints = HashMap::new();
+
+        while let Some(checkpoint) = cursor.try_next().await? {
+            checkpoints.insert(checkpoint.indexer, checkpoint.last_block);
+        }
+
+        Ok(checkpoints)
+    }
+
+    /// Record a change of a network in the audit log
+    async fn audit(
+        db: &Database,
+        actor: &str,
+        action: AuditAction,
+        chain_id: u64,
+        before: Option<&Network>,
+        after: Option<&Network>,
+    ) {
+        AuditService::record(
+            db,
+            actor,
+            action,
+            AuditEntity::Network,
+            &chain_id.to_string(),
+            before,
+            after,
+        )
+        .await;
+    }
+
+    /// Map a network together with its stored checkpoints
+    async fn to_response(db: &Database, network: Network) -> anyhow::Result<NetworkResponse> {
+        let checkpoints = Self::get_checkpoints(db, network.chain_id).await?;
+        Ok(Self::map_to_response(network, checkpoints))
+    }
+
+    /// Get a page of networks with their indexer checkpoints
     ///
     /// # Arguments
-    /// * `db` - Database reference
+    /// * `db_service` - MongoDB service
+    /// * `query` - Pagination, sorting and filters
     ///
     /// # Returns
-    /// * `Ok(Vec<NetworkResponse>)` - List of networks
-    /// * `Err(anyhow::Error)` - Error if database operation fails
-    pub async fn get_networks_with_stats(db: &Database) -> anyhow::Result<Vec<NetworkResponse>> {
-        debug!("Fetching all networks (including deleted)");
-
-        let collection = db.collection::<Network>("networks");
-        // Return all networks, including deleted ones
-        let filter = doc! {};
-        let mut cursor = collection.find(filter).await?;
-        let mut networks = Vec::new();
-
-        while let Some(network) = cursor.try_next().await? {
-            networks.push(Self::map_to_response(network));
+    /// * `Ok(Page<NetworkResponse>)` - Page of networks with the total count
+    /// * `Err(anyhow::Error)` - Error if the query is invalid or the database operation fails
+    pub async fn get_networks(
+        db_service: &MongoDbService,
+        query: &ListQuery,
+    ) -> anyhow::Result<Page<NetworkResponse>> {
+        debug!("Fetching networks with query: {:?}", query);
+
+        let networks = db_service.get_network_repo().find_page(query).await?;
+
+        // Load all checkpoints once and group them by network
+        let mut checkpoints_by_network: HashMap<u64, HashMap<String, u64>> = HashMap::new();
+        for checkpoint in db_service.get_checkpoint_repo().find_all().await? {
+            checkpoints_by_network
+                .entry(checkpoint.network_id)
+                .or_default()
+                .insert(checkpoint.indexer, checkpoint.last_block);
         }
 
-        debug!("Retrieved {} networks from database", networks.len());
+        let networks = networks.map(|network| {
+            let checkpoints = checkpoints_by_network
+                .remove(&network.chain_id)
+                .unwrap_or_default();
+            Self::map_to_response(network, checkpoints)
+        });
+
+        debug!(
+            "Retrieved {} of {} networks from database",
+            networks.items.len(),
+            networks.total
+        );
         Ok(networks)
     }
 
@@ -117,10 +231,14 @@ impl NetworkService {
     ///
     /// # Arguments
     /// * `network` - Network model from database
+    /// * `checkpoints` - Indexer checkpoints of the network (indexer name -> last block)
     ///
     /// # Returns
     /// NetworkResponse DTO
-    fn map_to_response(network: Network) -> NetworkResponse {
+    pub(crate) fn map_to_response(
+        network: Network,
+        checkpoints: HashMap<String, u64>,
+    ) -> NetworkResponse {
         // Convert ObjectId to string
         let id = network
             .id
@@ -142,8 +260,10 @@ impl NetworkService {
             v2_factory_to_fee: network.v2_factory_to_fee,
             aero_factory_addresses: network.aero_factory_addresses,
             multicall_address: network.multicall_address,
+            price_network: network.price_network,
             max_blocks_per_batch: network.max_blocks_per_batch,
             wait_time_fetch: network.wait_time_fetch,
+            checkpoints,
             created_at: network.created_at,
             updated_at: network.updated_at,
             deleted,
@@ -176,7 +296,7 @@ impl NetworkService {
         let network = collection.find_one(filter).await?;
 
         if let Some(network) = network {
-            Ok(Some(Self::map_to_response(network)))
+            Ok(Some(Self::to_response(db, network).await?))
         } else {
             Ok(None)
         }
@@ -186,6 +306,7 @@ impl NetworkService {
     ///
     /// # Arguments
     /// * `db` - Database reference
+    /// * `actor` - Name of the API key making the change
     /// * `request` - CreateNetworkRequest containing network data
     ///
     /// # Returns
@@ -193,6 +314,7 @@ impl NetworkService {
     /// * `Err(anyhow::Error)` - Error if database operation fails
     pub async fn create_network(
         db: &Database,
+        actor: &str,
         request: CreateNetworkRequest,
     ) -> anyhow::Result<NetworkResponse> {
         debug!("Creating network with chain_id: {}", request.chain_id);
@@ -206,7 +328,7 @@ impl NetworkService {
         let filter = doc! { "chain_id": request.chain_id as i64 };
         let existing = collection.find_one(filter.clone()).await?;
 
-        if let Some(mut existing_network) = existing {
+        if let Some(existing_network) = existing {
             // Network exists, restore it and update with new data
             debug!(
                 "Network with chain_id {} exists, restoring and updating",
@@ -224,6 +346,7 @@ impl NetworkService {
                     "v2_factory_to_fee": bson::to_bson(&request.v2_factory_to_fee)?,
                     "aero_factory_addresses": bson::to_bson(&request.aero_factory_addresses)?,
                     "multicall_address": bson::to_bson(&request.multicall_address)?,
+                    "price_network": bson::to_bson(&request.price_network)?,
                     "max_blocks_per_batch": request.max_blocks_per_batch as i64,
                     "wait_time_fetch": request.wait_time_fetch as i64,
                     "updated_at": chrono::Utc::now().timestamp() as i64,
@@ -233,11 +356,20 @@ impl NetworkService {
 
             collection.update_one(filter.clone(), update).await?;
             let updated = collection.find_one(filter).await?.unwrap();
-            return Ok(Self::map_to_response(updated));
+            Self::audit(
+                db,
+                actor,
+                AuditAction::Create,
+                request.chain_id,
+                Some(&existing_network),
+                Some(&updated),
+            )
+            .await;
+            return Self::to_response(db, updated).await;
         }
 
         // Create new network
-        let network = Network::new(
+        let mut network = Network::new(
             request.chain_id,
             request.name,
             request.rpcs,
@@ -251,17 +383,28 @@ impl NetworkService {
             request.max_blocks_per_batch,
             request.wait_time_fetch,
         );
+        network.price_network = request.price_network;
 
         collection.insert_one(&network).await?;
-
-        debug!("Network created successfully: {}", request.chain_id);
-        Ok(Self::map_to_response(network))
+        Self::audit(
+            db,
+            actor,
+            AuditAction::Create,
+            network.chain_id,
+            None,
+            Some(&network),
+        )
+        .await;
+
+        debug!("Network created successfully: {}", network.chain_id);
+        Ok(Self::map_to_response(network, HashMap::new()))
     }
 
     /// Update an existing network
     ///
     /// # Arguments
     /// * `db` - Database reference
+    /// * `actor` - Name of the API key making the change
     /// * `chain_id` - The chain_id of the network to update
     /// * `request` - UpdateNetworkRequest containing fields to update
     ///
@@ -270,6 +413,7 @@ impl NetworkService {
     /// * `Err(anyhow::Error)` - Error if database operation fails or network not found
     pub async fn update_network(
         db: &Database,
+        actor: &str,
         chain_id: u64,
         request: UpdateNetworkRequest,
     ) -> anyhow::Result<NetworkResponse> {
@@ -282,13 +426,10 @@ impl NetworkService {
         let filter = doc! { "chain_id": chain_id as i64 };
 
         // Check if network exists
-        let existing = collection.find_one(filter.clone()).await?;
-        if existing.is_none() {
-            return Err(anyhow::anyhow!(
-                "Network with chain_id {} not found",
-                chain_id
-            ));
-        }
+        let existing = collection
+            .find_one(filter.clone())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Network with chain_id {} not found", chain_id))?;
 
         // Build update document
         let mut update_doc = mongodb::bson::Document::new();
@@ -326,6 +467,9 @@ impl NetworkService {
         if let Some(multicall_address) = request.multicall_address {
             update_doc.insert("multicall_address", multicall_address);
         }
+        if let Some(price_network) = request.price_network {
+            update_doc.insert("price_network", price_network);
+        }
         if let Some(max_blocks_per_batch) = request.max_blocks_per_batch {
             update_doc.insert("max_blocks_per_batch", max_blocks_per_batch as i64);
         }
@@ -338,15 +482,25 @@ impl NetworkService {
 
         // Get updated network
         let network = collection.find_one(filter).await?.unwrap();
+        Self::audit(
+            db,
+            actor,
+            AuditAction::Update,
+            chain_id,
+            Some(&existing),
+            Some(&network),
+        )
+        .await;
 
         debug!("Network updated successfully: {}", chain_id);
-        Ok(Self::map_to_response(network))
+        Self::to_response(db, network).await
     }
 
     /// Update both V2 factory to fee mapping and Aero factory addresses for a network
     ///
     /// # Arguments
     /// * `db` - Database reference
+    /// * `actor` - Name of the API key making the change
     /// * `chain_id` - The chain_id of the network to update
     /// * `request` - UpdateFactoriesRequest containing both factory mappings
     ///
@@ -355,6 +509,7 @@ impl NetworkService {
     /// * `Err(anyhow::Error)` - Error if database operation fails or network not found
     pub async fn update_factories(
         db: &Database,
+        actor: &str,
         chain_id: u64,
         request: UpdateFactoriesRequest,
     ) -> anyhow::Result<NetworkResponse> {
@@ -370,13 +525,10 @@ impl NetworkService {
         let filter = doc! { "chain_id": chain_id as i64 };
 
         // Check if network exists
-        let existing = collection.find_one(filter.clone()).await?;
-        if existing.is_none() {
-            return Err(anyhow::anyhow!(
-                "Network with chain_id {} not found",
-                chain_id
-            ));
-        }
+        let existing = collection
+            .find_one(filter.clone())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Network with chain_id {} not found", chain_id))?;
 
         // Build V2 factory to fee BSON document
         let mut bson_map = mongodb::bson::Document::new();
@@ -396,24 +548,34 @@ impl NetworkService {
 
         // Get updated network
         let network = collection.find_one(filter).await?.unwrap();
+        Self::audit(
+            db,
+            actor,
+            AuditAction::Update,
+            chain_id,
+            Some(&existing),
+            Some(&network),
+        )
+        .await;
 
         debug!(
             "Factories (V2 and Aero) updated successfully for network: {}",
             chain_id
         );
-        Ok(Self::map_to_response(network))
+        Self::to_response(db, network).await
     }
 
     /// Delete a network by chain_id
     ///
     /// # Arguments
     /// * `db` - Database reference
+    /// * `actor` - Name of the API key making the change
     /// * `chain_id` - The chain_id of the network to delete
     ///
     /// # Returns
     /// * `Ok(())` - Network deleted successfully
     /// * `Err(anyhow::Error)` - Error if database operation fails or network not found
-    pub async fn delete_network(db: &Database, chain_id: u64) -> anyhow::Result<()> {
+    pub async fn delete_network(db: &Database, actor: &str, chain_id: u64) -> anyhow::Result<()> {
         debug!("Soft deleting network with chain_id: {}", chain_id);
 
         let collection = db.collection::<Network>("networks");
@@ -427,13 +589,12 @@ impl NetworkService {
         };
 
         // Check if network exists and is not already deleted
-        let existing = collection.find_one(filter.clone()).await?;
-        if existing.is_none() {
-            return Err(anyhow::anyhow!(
+        let existing = collection.find_one(filter.clone()).await?.ok_or_else(|| {
+            anyhow::anyhow!(
                 "Network with chain_id {} not found or already deleted",
                 chain_id
-            ));
-        }
+            )
+        })?;
 
         // Soft delete: set deleted_at timestamp
         let update = doc! {
@@ -444,6 +605,19 @@ impl NetworkService {
         };
         collection.update_one(filter, update).await?;
 
+        let deleted = collection
+            .find_one(doc! { "chain_id": chain_id as i64 })
+            .await?;
+        Self::audit(
+            db,
+            actor,
+            AuditAction::Delete,
+            chain_id,
+            Some(&existing),
+            deleted.as_ref(),
+        )
+        .await;
+
         debug!("Network soft deleted successfully: {}", chain_id);
         Ok(())
     }
@@ -453,12 +627,17 @@ impl NetworkService {
     ///
     /// # Arguments
     /// * `db` - Database reference
+    /// * `actor` - Name of the API key making the change
     /// * `chain_id` - The chain_id of the network to hard delete
     ///
     /// # Returns
     /// * `Ok(())` - Successfully deleted
     /// * `Err(anyhow::Error)` - Error if network not found or not soft-deleted
-    pub async fn hard_delete_network(db: &Database, chain_id: u64) -> anyhow::Result<()> {
+    pub async fn hard_delete_network(
+        db: &Database,
+        actor: &str,
+        chain_id: u64,
+    ) -> anyhow::Result<()> {
         debug!("Hard deleting network with chain_id: {}", chain_id);
 
         let collection = db.collection::<Network>("networks");
@@ -469,17 +648,30 @@ impl NetworkService {
         };
 
         // Check if network exists and is soft-deleted
-        let existing = collection.find_one(filter.clone()).await?;
-        if existing.is_none() {
-            return Err(anyhow::anyhow!(
+        let existing = collection.find_one(filter.clone()).await?.ok_or_else(|| {
+            anyhow::anyhow!(
                 "Network with chain_id {} not found or not soft-deleted",
                 chain_id
-            ));
-        }
+            )
+        })?;
 
         // Hard delete: actually remove from database
         collection.delete_one(filter).await?;
 
+        // Drop indexer checkpoints so a re-created network starts from the chain head
+        db.collection::<IndexerCheckpoint>("indexer_checkpoints")
+            .delete_many(doc! { "network_id": chain_id as i64 })
+            .await?;
+        Self::audit(
+            db,
+            actor,
+            AuditAction::HardDelete,
+            chain_id,
+            Some(&existing),
+            None,
+        )
+        .await;
+
         debug!("Network hard deleted successfully: {}", chain_id);
         Ok(())
     }
@@ -488,25 +680,27 @@ impl NetworkService {
     ///
     /// # Arguments
     /// * `db` - Database reference
+    /// * `actor` - Name of the API key making the change
     /// * `chain_id` - The chain_id of the network to restore
     ///
     /// # Returns
     /// * `Ok(NetworkResponse)` - Restored network
     /// * `Err(anyhow::Error)` - Error if database operation fails or network not found
-    pub async fn undelete_network(db: &Database, chain_id: u64) -> anyhow::Result<NetworkResponse> {
+    pub async fn undelete_network(
+        db: &Database,
+        actor: &str,
+        chain_id: u64,
+    ) -> anyhow::Result<NetworkResponse> {
         debug!("Undelete (restore) network with chain_id: {}", chain_id);
 
         let collection = db.collection::<Network>("networks");
         let filter = doc! { "chain_id": chain_id as i64 };
 
         // Check if network exists
-        let existing = collection.find_one(filter.clone()).await?;
-        if existing.is_none() {
-            return Err(anyhow::anyhow!(
-                "Network with chain_id {} not found",
-                chain_id
-            ));
-        }
+        let existing = collection
+            .find_one(filter.clone())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Network with chain_id {} not found", chain_id))?;
 
         // Restore: set deleted_at to null
         let update = doc! {
@@ -519,8 +713,46 @@ impl NetworkService {
 
         // Get restored network
         let network = collection.find_one(filter).await?.unwrap();
+        Self::audit(
+            db,
+            actor,
+            AuditAction::Undelete,
+            chain_id,
+            Some(&existing),
+            Some(&network),
+        )
+        .await;
 
         debug!("Network restored successfully: {}", chain_id);
-        Ok(Self::map_to_response(network))
+        Self::to_response(db, network).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_rpcs() {
+        assert!(NetworkService::validate_rpcs(&["https://mainnet.base.org".to_string()]).is_ok());
+
+        for rpcs in [
+            vec![],
+            vec!["not a url".to_string()],
+            vec!["wss://mainnet.base.org".to_string()],
+        ] {
+            let error = NetworkService::validate_rpcs(&rpcs).unwrap_err();
+            assert!(error.to_string().starts_with("Invalid"));
+        }
+    }
+
+    #[test]
+    fn test_validate_price_network() {
+        for slug in ["eth", "polygon_pos", "zksync-era"] {
+            assert!(NetworkService::validate_price_network(slug).is_ok());
+        }
+        for slug in ["", "Ethereum", "polygon pos"] {
+            assert!(NetworkService::validate_price_network(slug).is_err());
+        }
     }
 }
@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+use crate::database::models::OpportunityStatus;
+
+/// Response model for opportunity API endpoints
+#[derive(Debug, Serialize)]
+pub struct OpportunityResponse {
+    pub id: String, // MongoDB ObjectId as string
+    pub source_chain_id: u64,
+    pub target_chain_id: u64,
+    pub path_id: Option<String>,
+    pub anchor_token: String,
+    pub amount_in: String,          // raw amount of the source anchor token
+    pub amount_out: Option<String>, // raw amount of the target anchor token
+    pub estimate_profit_usd: Option<f64>,
+    pub profit_usd: Option<f64>,
+    pub gas_usd: Option<f64>,
+    pub status: OpportunityStatus,
+    pub source_block_number: Option<u64>,
+    pub target_block_number: Option<u64>,
+    pub source_tx: Option<String>,
+    pub target_tx: Option<String>,
+    pub error: Option<String>,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+/// Response model for a page of opportunities
+#[derive(Debug, Serialize)]
+pub struct OpportunityListResponse {
+    pub opportunities: Vec<OpportunityResponse>,
+    pub total: u64, // number of opportunities matching the filters
+    pub page: u64,
+    pub limit: u64,
+}
+
+/// Query parameters for listing opportunities
+/// `from`/`to` are Unix timestamps in seconds, matched against `created_at`
+#[derive(Debug, Deserialize)]
+pub struct OpportunityQuery {
+    pub chain_id: Option<u64>, // matches either the source or the target chain
+    pub source_chain_id: Option<u64>,
+    pub target_chain_id: Option<u64>,
+    pub path_id: Option<String>,
+    pub status: Option<String>,
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+    pub page: Option<u64>,  // starts at 1
+    pub limit: Option<u64>, // defaults to 50, at most 500
+}
+
+/// Request model for recording a new opportunity
+#[derive(Debug, Deserialize)]
+pub struct CreateOpportunityRequest {
+    pub source_chain_id: u64,
+    pub target_chain_id: u64,
+    pub path_id: Option<String>,
+    pub anchor_token: String,
+    pub amount_in: String,
+    pub amount_out: Option<String>,
+    pub estimate_profit_usd: Option<f64>,
+    pub profit_usd: Option<f64>,
+    pub gas_usd: Option<f64>,
+    pub status: Option<OpportunityStatus>, // defaults to detected
+    pub source_block_number: Option<u64>,
+    pub target_block_number: Option<u64>,
+    pub source_tx: Option<String>,
+    pub target_tx: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Request model for updating the outcome of an opportunity
+#[derive(Debug, Deserialize)]
+pub struct UpdateOpportunityRequest {
+    pub amount_out: Option<String>,
+    pub profit_usd: Option<f64>,
+    pub gas_usd: Option<f64>,
+    pub status: Option<OpportunityStatus>,
+    pub target_block_number: Option<u64>,
+    pub source_tx: Option<String>,
+    pub target_tx: Option<String>,
+    pub error: Option<String>,
+}
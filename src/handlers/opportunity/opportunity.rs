@@ -0,0 +1,211 @@
+use actix_web::{web, HttpResponse};
+use log::{error, info};
+use mongodb::bson::oid::ObjectId;
+use mongodb::Database;
+
+use crate::{
+    errors::ApiError,
+    handlers::{
+        config::auth::ApiKey,
+        opportunity::{
+            dto::{CreateOpportunityRequest, OpportunityQuery, UpdateOpportunityRequest},
+            service::OpportunityService,
+        },
+    },
+    services::NotificationHandler,
+};
+
+/// Notify the outcome of an opportunity in the background, so the sinks never delay the response
+fn spawn_opportunity_notification(
+    notification_handler: web::Data<NotificationHandler>,
+    id: ObjectId,
+) {
+    actix_web::rt::spawn(async move {
+        notification_handler.notify_opportunity(&id).await;
+    });
+}
+
+/// GET /opportunities - Returns a page of opportunities, newest first
+///
+/// # Arguments
+/// * `db` - Database connection
+/// * `query` - Query parameters with the filters (chain, path, status, time window) and pagination
+///
+/// # Returns
+/// JSON object of OpportunityListResponse
+pub async fn get_opportunities_handler(
+    db: web::Data<Database>,
+    query: web::Query<OpportunityQuery>,
+) -> Result<HttpResponse, ApiError> {
+    info!("Handling GET /opportunities request");
+
+    match OpportunityService::get_opportunities(&db, query.into_inner()).await {
+        Ok(opportunities) => {
+            info!(
+                "Successfully retrieved {} of {} opportunities",
+                opportunities.opportunities.len(),
+                opportunities.total
+            );
+            Ok(HttpResponse::Ok().json(opportunities))
+        }
+        Err(e) => {
+            error!("Failed to retrieve opportunities: {}", e);
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to retrieve opportunities: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
+
+/// GET /opportunities/{id} - Returns a specific opportunity by ID
+///
+/// # Arguments
+/// * `db` - Database connection
+/// * `path` - Path parameters containing id
+///
+/// # Returns
+/// JSON object of OpportunityResponse
+pub async fn get_opportunity_by_id_handler(
+    db: web::Data<Database>,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let id_str = path.into_inner();
+    info!("Handling GET /opportunities/{} request", id_str);
+
+    let id = match ObjectId::parse_str(&id_str) {
+        Ok(id) => id,
+        Err(e) => {
+            error!("Invalid ObjectId format: {}", e);
+            return Err(ApiError::BadRequest(format!("Invalid ID format: {}", e)));
+        }
+    };
+
+    match OpportunityService::get_opportunity_by_id(&db, &id).await {
+        Ok(Some(opportunity)) => {
+            info!("Successfully retrieved opportunity with id: {}", id_str);
+            Ok(HttpResponse::Ok().json(opportunity))
+        }
+        Ok(None) => {
+            info!("Opportunity with id {} not found", id_str);
+            Err(ApiError::NotFound(format!(
+                "Opportunity with id {} not found",
+                id_str
+            )))
+        }
+        Err(e) => {
+            error!("Failed to retrieve opportunity {}: {}", id_str, e);
+            Err(ApiError::DatabaseError(format!(
+                "Failed to retrieve opportunity: {}",
+                e
+            )))
+        }
+    }
+}
+
+/// POST /opportunities - Records a new opportunity
+/// Requires API key authentication via X-API-Key header
+/// Opportunities recorded with a profitable final outcome are notified on the configured sinks
+///
+/// # Arguments
+/// * `_api_key` - API key from X-API-Key header (validated by extractor)
+/// * `db` - Database connection
+/// * `notification_handler` - Notification handler
+/// * `body` - CreateOpportunityRequest containing opportunity data
+///
+/// # Returns
+/// JSON object of OpportunityResponse containing the recorded opportunity
+pub async fn create_opportunity_handler(
+    _api_key: ApiKey,
+    db: web::Data<Database>,
+    notification_handler: web::Data<NotificationHandler>,
+    body: web::Json<CreateOpportunityRequest>,
+) -> Result<HttpResponse, ApiError> {
+    info!("Handling POST /opportunities request");
+
+    match OpportunityService::create_opportunity(&db, body.into_inner()).await {
+        Ok(opportunity) => {
+            info!(
+                "Successfully recorded opportunity with id: {}",
+                opportunity.id
+            );
+            if let Ok(id) = ObjectId::parse_str(&opportunity.id) {
+                spawn_opportunity_notification(notification_handler, id);
+            }
+            Ok(HttpResponse::Created().json(opportunity))
+        }
+        Err(e) => {
+            error!("Failed to record opportunity: {}", e);
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to record opportunity: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
+
+/// PUT /opportunities/{id} - Updates the outcome of an opportunity
+/// Requires API key authentication via X-API-Key header
+/// Status changes to a profitable final outcome are notified on the configured sinks
+///
+/// # Arguments
+/// * `_api_key` - API key from X-API-Key header (validated by extractor)
+/// * `db` - Database connection
+/// * `notification_handler` - Notification handler
+/// * `path` - Path parameters containing id
+/// * `body` - UpdateOpportunityRequest containing fields to update
+///
+/// # Returns
+/// JSON object of OpportunityResponse containing the updated opportunity
+pub async fn update_opportunity_handler(
+    _api_key: ApiKey,
+    db: web::Data<Database>,
+    notification_handler: web::Data<NotificationHandler>,
+    path: web::Path<String>,
+    body: web::Json<UpdateOpportunityRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let id_str = path.into_inner();
+    info!("Handling PUT /opportunities/{} request", id_str);
+
+    let id = match ObjectId::parse_str(&id_str) {
+        Ok(id) => id,
+        Err(e) => {
+            error!("Invalid ObjectId format: {}", e);
+            return Err(ApiError::BadRequest(format!("Invalid ID format: {}", e)));
+        }
+    };
+
+    match OpportunityService::update_opportunity(&db, &id, body.into_inner()).await {
+        Ok((opportunity, status_changed)) => {
+            info!("Successfully updated opportunity with id: {}", id_str);
+            if status_changed {
+                spawn_opportunity_notification(notification_handler, id);
+            }
+            Ok(HttpResponse::Ok().json(opportunity))
+        }
+        Err(e) => {
+            error!("Failed to update opportunity {}: {}", id_str, e);
+            if e.to_string().contains("not found") {
+                Err(ApiError::NotFound(format!(
+                    "Opportunity with id {} not found",
+                    id_str
+                )))
+            } else if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to update opportunity: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
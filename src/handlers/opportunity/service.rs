@@ -0,0 +1,291 @@
+use alloy::primitives::{Address, U256};
+use futures::TryStreamExt;
+use log::debug;
+use mongodb::bson::{doc, oid::ObjectId, Document};
+use mongodb::Database;
+use std::str::FromStr;
+
+use crate::{
+    database::models::utils::address_to_string,
+    database::models::{Opportunity, OpportunityFilter, OpportunityStatus},
+    database::repositories::{page_offset, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE},
+    handlers::opportunity::dto::{
+        CreateOpportunityRequest, OpportunityListResponse, OpportunityQuery, OpportunityResponse,
+        UpdateOpportunityRequest,
+    },
+    services::{Event, EventBus, EventTopic},
+};
+
+/// Service layer for opportunity-related business logic
+pub struct OpportunityService;
+
+impl OpportunityService {
+    /// Validate that a raw amount is a base-10 unsigned integer
+    fn validate_amount(amount: &str) -> anyhow::Result<()> {
+        U256::from_str_radix(amount, 10)
+            .map(|_| ())
+            .map_err(|e| anyhow::anyhow!("Invalid amount '{}': {}", amount, e))
+    }
+
+    /// Build the MongoDB filter matching the query parameters
+    fn build_filter(query: &OpportunityQuery) -> anyhow::Result<Document> {
+        OpportunityFilter {
+            chain_id: query.chain_id,
+            source_chain_id: query.source_chain_id,
+            target_chain_id: query.target_chain_id,
+            path_id: query.path_id.as_deref(),
+            status: query.status.as_deref(),
+            from: query.from,
+            to: query.to,
+        }
+        .to_document()
+    }
+
+    /// Get a page of opportunities, newest first
+    ///
+    /// # Arguments
+    /// * `db` - Database reference
+    /// * `query` - Filters and pagination
+    ///
+    /// # Returns
+    /// * `Ok(OpportunityListResponse)` - Page of opportunities with the total count
+    /// * `Err(anyhow::Error)` - Error if the query is invalid or the database operation fails
+    pub async fn get_opportunities(
+        db: &Database,
+        query: OpportunityQuery,
+    ) -> anyhow::Result<OpportunityListResponse> {
+        debug!("Fetching opportunities with query: {:?}", query);
+
+        let page = query.page.unwrap_or(1);
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE);
+        let skip = page_offset(page, limit)?;
+        let filter = Self::build_filter(&query)?;
+
+        let collection = db.collection::<Opportunity>("opportunities");
+        let total = collection.count_documents(filter.clone()).await?;
+        let mut cursor = collection
+            .find(filter)
+            .sort(doc! { "created_at": -1, "_id": -1 })
+            .skip(skip)
+            .limit(limit as i64)
+            .await?;
+        let mut opportunities = Vec::new();
+
+        while let Some(opportunity) = cursor.try_next().await? {
+            opportunities.push(Self::map_to_response(opportunity));
+        }
+
+        debug!(
+            "Retrieved {} of {} opportunities from database",
+            opportunities.len(),
+            total
+        );
+        Ok(OpportunityListResponse {
+            opportunities,
+            total,
+            page,
+            limit,
+        })
+    }
+
+    /// Get an opportunity by ID
+    ///
+    /// # Arguments
+    /// * `db` - Database reference
+    /// * `id` - The ObjectId of the opportunity to retrieve
+    ///
+    /// # Returns
+    /// * `Ok(Option<OpportunityResponse>)` - Opportunity if found
+    /// * `Err(anyhow::Error)` - Error if database operation fails
+    pub async fn get_opportunity_by_id(
+        db: &Database,
+        id: &ObjectId,
+    ) -> anyhow::Result<Option<OpportunityResponse>> {
+        debug!("Fetching opportunity with id: {}", id);
+
+        let collection = db.collection::<Opportunity>("opportunities");
+        let opportunity = collection.find_one(doc! { "_id": id }).await?;
+
+        Ok(opportunity.map(Self::map_to_response))
+    }
+
+    /// Record a new opportunity
+    ///
+    /// # Arguments
+    /// * `db` - Database reference
+    /// * `request` - CreateOpportunityRequest containing opportunity data
+    ///
+    /// # Returns
+    /// * `Ok(OpportunityResponse)` - Recorded opportunity
+    /// * `Err(anyhow::Error)` - Error if validation or database operation fails
+    pub async fn create_opportunity(
+        db: &Database,
+        request: CreateOpportunityRequest,
+    ) -> anyhow::Result<OpportunityResponse> {
+        debug!(
+            "Recording opportunity from chain {} to chain {}",
+            request.source_chain_id, request.target_chain_id
+        );
+
+        let anchor_token = Address::from_str(&request.anchor_token).map_err(|e| {
+            anyhow::anyhow!("Invalid address format '{}': {}", request.anchor_token, e)
+        })?;
+        Self::validate_amount(&request.amount_in)?;
+        if let Some(amount_out) = &request.amount_out {
+            Self::validate_amount(amount_out)?;
+        }
+        if let Some(path_id) = &request.path_id {
+            ObjectId::parse_str(path_id)
+                .map_err(|e| anyhow::anyhow!("Invalid path_id '{}': {}", path_id, e))?;
+        }
+
+        let mut opportunity = Opportunity::new(
+            request.source_chain_id,
+            request.target_chain_id,
+            request.path_id,
+            address_to_string(&anchor_token),
+            request.amount_in,
+            request.estimate_profit_usd,
+            request.source_block_number,
+        );
+        opportunity.amount_out = request.amount_out;
+        opportunity.profit_usd = request.profit_usd;
+        opportunity.gas_usd = request.gas_usd;
+        opportunity.status = request.status.unwrap_or(OpportunityStatus::Detected);
+        opportunity.target_block_number = request.target_block_number;
+        opportunity.source_tx = request.source_tx;
+        opportunity.target_tx = request.target_tx;
+        opportunity.error = request.error;
+
+        let collection = db.collection::<Opportunity>("opportunities");
+        let result = collection.insert_one(&opportunity).await?;
+        let id = result.inserted_id.as_object_id().unwrap();
+
+        let created = collection.find_one(doc! { "_id": id }).await?.unwrap();
+
+        debug!("Opportunity recorded successfully with id: {}", id);
+        let opportunity = Self::map_to_response(created);
+        Self::publish("create", &opportunity);
+        Ok(opportunity)
+    }
+
+    /// Update the outcome of an opportunity
+    ///
+    /// # Arguments
+    /// * `db` - Database reference
+    /// * `id` - The ObjectId of the opportunity to update
+    /// * `request` - UpdateOpportunityRequest containing fields to update
+    ///
+    /// # Returns
+    /// * `Ok((OpportunityResponse, bool))` - Updated opportunity, and whether its status changed
+    /// * `Err(anyhow::Error)` - Error if database operation fails or opportunity not found
+    pub async fn update_opportunity(
+        db: &Database,
+        id: &ObjectId,
+        request: UpdateOpportunityRequest,
+    ) -> anyhow::Result<(OpportunityResponse, bool)> {
+        debug!("Updating opportunity with id: {}", id);
+
+        let collection = db.collection::<Opportunity>("opportunities");
+        let filter = doc! { "_id": id };
+
+        let existing = collection
+            .find_one(filter.clone())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Opportunity with id {} not found", id))?;
+        let status_changed = request
+            .status
+            .is_some_and(|status| status != existing.status);
+
+        // Build update document
+        let mut update_doc = Document::new();
+        update_doc.insert("updated_at", chrono::Utc::now().timestamp());
+
+        if let Some(amount_out) = request.amount_out {
+            Self::validate_amount(&amount_out)?;
+            update_doc.insert("amount_out", amount_out);
+        }
+        if let Some(profit_usd) = request.profit_usd {
+            update_doc.insert("profit_usd", profit_usd);
+        }
+        if let Some(gas_usd) = request.gas_usd {
+            update_doc.insert("gas_usd", gas_usd);
+        }
+        if let Some(status) = request.status {
+            update_doc.insert("status", status.as_str());
+        }
+        if let Some(target_block_number) = request.target_block_number {
+            update_doc.insert("target_block_number", target_block_number as i64);
+        }
+        if let Some(source_tx) = request.source_tx {
+            update_doc.insert("source_tx", source_tx);
+        }
+        if let Some(target_tx) = request.target_tx {
+            update_doc.insert("target_tx", target_tx);
+        }
+        if let Some(error) = request.error {
+            update_doc.insert("error", error);
+        }
+
+        let update = doc! { "$set": update_doc };
+        collection.update_one(filter.clone(), update).await?;
+
+        let opportunity = collection.find_one(filter).await?.unwrap();
+
+        debug!("Opportunity updated successfully: {}", id);
+        let opportunity = Self::map_to_response(opportunity);
+        Self::publish("update", &opportunity);
+        Ok((opportunity, status_changed))
+    }
+
+    /// Publish a recorded or updated opportunity to the WebSocket clients
+    fn publish(action: &str, opportunity: &OpportunityResponse) {
+        let mut network_ids = vec![opportunity.source_chain_id, opportunity.target_chain_id];
+        network_ids.dedup();
+        EventBus::global().publish(Event::new(
+            EventTopic::Opportunity,
+            action,
+            &opportunity.id,
+            network_ids,
+            serde_json::to_value(opportunity).unwrap_or_default(),
+        ));
+    }
+
+    /// Map Opportunity model to OpportunityResponse DTO
+    ///
+    /// # Arguments
+    /// * `opportunity` - Opportunity model from database
+    ///
+    /// # Returns
+    /// OpportunityResponse DTO
+    fn map_to_response(opportunity: Opportunity) -> OpportunityResponse {
+        let id = opportunity
+            .id
+            .map(|oid| oid.to_hex())
+            .unwrap_or_else(|| "unknown".to_string());
+
+        OpportunityResponse {
+            id,
+            source_chain_id: opportunity.source_chain_id,
+            target_chain_id: opportunity.target_chain_id,
+            path_id: opportunity.path_id,
+            anchor_token: opportunity.anchor_token,
+            amount_in: opportunity.amount_in,
+            amount_out: opportunity.amount_out,
+            estimate_profit_usd: opportunity.estimate_profit_usd,
+            profit_usd: opportunity.profit_usd,
+            gas_usd: opportunity.gas_usd,
+            status: opportunity.status,
+            source_block_number: opportunity.source_block_number,
+            target_block_number: opportunity.target_block_number,
+            source_tx: opportunity.source_tx,
+            target_tx: opportunity.target_tx,
+            error: opportunity.error,
+            created_at: opportunity.created_at,
+            updated_at: opportunity.updated_at,
+        }
+    }
+}
@@ -10,8 +10,8 @@ pub struct PathResponse {
     pub deleted: bool,
 }
 
-/// Request model for creating a new path
-#[derive(Debug, Deserialize)]
+/// Request model for creating a new path, also the path format of config bundles
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CreatePathRequest {
     pub paths: Vec<crate::bot::models::path::SingleChainPathsWithAnchorToken>,
 }
@@ -21,3 +21,83 @@ pub struct CreatePathRequest {
 pub struct UpdatePathRequest {
     pub paths: Option<Vec<crate::bot::models::path::SingleChainPathsWithAnchorToken>>,
 }
+
+/// Request model for simulating a path
+#[derive(Debug, Deserialize)]
+pub struct SimulatePathRequest {
+    pub amount: f64,                  // amount of the source anchor token, in human units
+    pub source_chain_id: Option<u64>, // defaults to the first chain of the path
+}
+
+/// Response model for a simulated path
+#[derive(Debug, Serialize)]
+pub struct PathSimulationResponse {
+    pub path_id: String,
+    pub source_chain_id: u64,
+    pub anchor_token: String,
+    pub amount_in: String, // raw amount of the source anchor token
+    pub amount_in_usd: Option<f64>,
+    pub routes: Vec<RouteSimulationResponse>,
+}
+
+/// Simulation of one source path combined with one target path
+/// When the route cannot be quoted, `error` is set and the amounts are empty
+#[derive(Debug, Serialize)]
+pub struct RouteSimulationResponse {
+    pub target_chain_id: u64,
+    pub source_path_index: usize,
+    pub target_path_index: usize,
+    pub source_hops: Vec<HopSimulationResponse>,
+    pub target_hops: Vec<HopSimulationResponse>,
+    pub amount_out: Option<String>, // raw amount of the target anchor token
+    pub amount_out_usd: Option<f64>,
+    pub profit_usd: Option<f64>,
+    pub error: Option<String>,
+}
+
+/// Simulation of a single swap
+#[derive(Debug, Serialize)]
+pub struct HopSimulationResponse {
+    pub pool: String,
+    pub token_in: String,
+    pub token_out: String,
+    pub amount_in: String,
+    pub amount_out: String,
+    pub price_impact: f64, // fraction, 0.01 = 1%
+}
+
+/// Request model for finding the optimal input size of a path
+#[derive(Debug, Deserialize)]
+pub struct OptimizePathRequest {
+    pub source_chain_id: Option<u64>, // defaults to the first chain of the path
+}
+
+/// Response model for an optimized path
+#[derive(Debug, Serialize)]
+pub struct PathOptimizationResponse {
+    pub path_id: String,
+    pub source_chain_id: u64,
+    pub anchor_token: String,
+    pub max_amount_usd: f64,
+    pub max_amount_in: String, // max_amount_usd as a raw amount of the source anchor token
+    pub best_route: Option<usize>, // index in `routes` of the most profitable route
+    pub routes: Vec<RouteOptimizationResponse>,
+}
+
+/// Profit-maximizing input size of one source path combined with one target path
+/// When the route cannot be optimized, `error` is set and the amounts are empty
+#[derive(Debug, Serialize)]
+pub struct RouteOptimizationResponse {
+    pub target_chain_id: u64,
+    pub source_path_index: usize,
+    pub target_path_index: usize,
+    pub amount_in: Option<String>, // raw amount of the source anchor token
+    pub amount_in_usd: Option<f64>,
+    pub amount_out: Option<String>, // raw amount of the target anchor token
+    pub amount_out_usd: Option<f64>,
+    pub profit_usd: Option<f64>,
+    pub profitable: bool,
+    pub source_hops: Vec<HopSimulationResponse>,
+    pub target_hops: Vec<HopSimulationResponse>,
+    pub error: Option<String>,
+}
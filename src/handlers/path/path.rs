@@ -4,34 +4,51 @@ use mongodb::bson::oid::ObjectId;
 use mongodb::Database;
 
 use crate::{
+    database::{repositories::ListQuery, MongoDbService},
     errors::ApiError,
     handlers::{
-        config::auth::ApiKey,
+        config::auth::{ApiKey, HardDeleteApiKey},
         path::{
-            dto::{CreatePathRequest, UpdatePathRequest},
+            dto::{CreatePathRequest, OptimizePathRequest, SimulatePathRequest, UpdatePathRequest},
             service::PathService,
         },
     },
 };
 
-/// GET /paths - Returns all paths
+/// GET /paths - Returns a page of paths
+///
+/// # Arguments
+/// * `db_service` - MongoDB service
+/// * `query` - Query parameters with pagination (limit, page or cursor), sort and filters
+///   (include_deleted, updated_since)
 ///
 /// # Returns
-/// JSON array of PathResponse objects containing path information
-pub async fn get_paths_handler(db: web::Data<Database>) -> Result<HttpResponse, ApiError> {
+/// JSON object of Page<PathResponse> with the total count
+pub async fn get_paths_handler(
+    db_service: web::Data<MongoDbService>,
+    query: web::Query<ListQuery>,
+) -> Result<HttpResponse, ApiError> {
     info!("Handling GET /paths request");
 
-    match PathService::get_all_paths(&db).await {
+    match PathService::get_paths(&db_service, None, None, &query).await {
         Ok(paths) => {
-            info!("Successfully retrieved {} paths", paths.len());
+            info!(
+                "Successfully retrieved {} of {} paths",
+                paths.items.len(),
+                paths.total
+            );
             Ok(HttpResponse::Ok().json(paths))
         }
         Err(e) => {
             error!("Failed to retrieve paths: {}", e);
-            Err(ApiError::DatabaseError(format!(
-                "Failed to retrieve paths: {}",
-                e
-            )))
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to retrieve paths: {}",
+                    e
+                )))
+            }
         }
     }
 }
@@ -81,62 +98,84 @@ pub async fn get_path_by_id_handler(
     }
 }
 
-/// GET /paths/anchor-token/{anchor_token} - Returns paths by anchor token
+/// GET /paths/anchor-token/{anchor_token} - Returns a page of the paths using an anchor token
 ///
 /// # Arguments
-/// * `db` - Database connection
+/// * `db_service` - MongoDB service
 /// * `path` - Path parameters containing anchor_token
+/// * `query` - Query parameters with pagination (limit, page or cursor), sort and filters
+///   (include_deleted, updated_since)
 ///
 /// # Returns
-/// JSON array of PathResponse objects
+/// JSON object of Page<PathResponse> with the total count
 pub async fn get_paths_by_anchor_token_handler(
-    db: web::Data<Database>,
+    db_service: web::Data<MongoDbService>,
     path: web::Path<String>,
+    query: web::Query<ListQuery>,
 ) -> Result<HttpResponse, ApiError> {
     let anchor_token = path.into_inner();
     info!("Handling GET /paths/anchor-token/{} request", anchor_token);
 
-    match PathService::get_paths_by_anchor_token(&db, &anchor_token).await {
+    match PathService::get_paths(&db_service, None, Some(&anchor_token), &query).await {
         Ok(paths) => {
-            info!("Successfully retrieved {} paths", paths.len());
+            info!(
+                "Successfully retrieved {} of {} paths",
+                paths.items.len(),
+                paths.total
+            );
             Ok(HttpResponse::Ok().json(paths))
         }
         Err(e) => {
             error!("Failed to retrieve paths: {}", e);
-            Err(ApiError::DatabaseError(format!(
-                "Failed to retrieve paths: {}",
-                e
-            )))
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to retrieve paths: {}",
+                    e
+                )))
+            }
         }
     }
 }
 
-/// GET /paths/chain/{chain_id} - Returns paths by chain ID
+/// GET /paths/chain/{chain_id} - Returns a page of the paths with a leg on a chain
 ///
 /// # Arguments
-/// * `db` - Database connection
+/// * `db_service` - MongoDB service
 /// * `path` - Path parameters containing chain_id
+/// * `query` - Query parameters with pagination (limit, page or cursor), sort and filters
+///   (include_deleted, updated_since)
 ///
 /// # Returns
-/// JSON array of PathResponse objects
+/// JSON object of Page<PathResponse> with the total count
 pub async fn get_paths_by_chain_id_handler(
-    db: web::Data<Database>,
+    db_service: web::Data<MongoDbService>,
     path: web::Path<u64>,
+    query: web::Query<ListQuery>,
 ) -> Result<HttpResponse, ApiError> {
     let chain_id = path.into_inner();
     info!("Handling GET /paths/chain/{} request", chain_id);
 
-    match PathService::get_paths_by_chain_id(&db, chain_id).await {
+    match PathService::get_paths(&db_service, Some(chain_id), None, &query).await {
         Ok(paths) => {
-            info!("Successfully retrieved {} paths", paths.len());
+            info!(
+                "Successfully retrieved {} of {} paths",
+                paths.items.len(),
+                paths.total
+            );
             Ok(HttpResponse::Ok().json(paths))
         }
         Err(e) => {
             error!("Failed to retrieve paths: {}", e);
-            Err(ApiError::DatabaseError(format!(
-                "Failed to retrieve paths: {}",
-                e
-            )))
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to retrieve paths: {}",
+                    e
+                )))
+            }
         }
     }
 }
@@ -145,20 +184,20 @@ pub async fn get_paths_by_chain_id_handler(
 /// Requires API key authentication via X-API-Key header
 ///
 /// # Arguments
-/// * `_api_key` - API key from X-API-Key header (validated by extractor)
-/// * `db` - Database connection
+/// * `api_key` - API key from X-API-Key header (validated by extractor), recorded in the audit log
+/// * `db_service` - MongoDB service
 /// * `body` - CreatePathRequest containing path data
 ///
 /// # Returns
 /// JSON object of PathResponse containing created path information
 pub async fn create_path_handler(
-    _api_key: ApiKey,
-    db: web::Data<Database>,
+    api_key: ApiKey,
+    db_service: web::Data<MongoDbService>,
     body: web::Json<CreatePathRequest>,
 ) -> Result<HttpResponse, ApiError> {
     info!("Handling POST /paths request");
 
-    match PathService::create_path(&db, body.into_inner()).await {
+    match PathService::create_path(&db_service, &api_key.name, body.into_inner()).await {
         Ok(path) => {
             info!("Successfully created path with id: {}", path.id);
             Ok(HttpResponse::Created().json(path))
@@ -177,16 +216,16 @@ pub async fn create_path_handler(
 /// Requires API key authentication via X-API-Key header
 ///
 /// # Arguments
-/// * `_api_key` - API key from X-API-Key header (validated by extractor)
-/// * `db` - Database connection
+/// * `api_key` - API key from X-API-Key header (validated by extractor), recorded in the audit log
+/// * `db_service` - MongoDB service
 /// * `path` - Path parameters containing id
 /// * `body` - UpdatePathRequest containing fields to update
 ///
 /// # Returns
 /// JSON object of PathResponse containing updated path information
 pub async fn update_path_handler(
-    _api_key: ApiKey,
-    db: web::Data<Database>,
+    api_key: ApiKey,
+    db_service: web::Data<MongoDbService>,
     path: web::Path<String>,
     body: web::Json<UpdatePathRequest>,
 ) -> Result<HttpResponse, ApiError> {
@@ -201,7 +240,7 @@ pub async fn update_path_handler(
         }
     };
 
-    match PathService::update_path(&db, &id, body.into_inner()).await {
+    match PathService::update_path(&db_service, &api_key.name, &id, body.into_inner()).await {
         Ok(path) => {
             info!("Successfully updated path with id: {}", id_str);
             Ok(HttpResponse::Ok().json(path))
@@ -226,7 +265,7 @@ pub async fn update_path_handler(
 /// DELETE /paths/{id} - Soft deletes a path (sets deleted_at)
 /// Requires API key authentication via X-API-Key header
 pub async fn delete_path_handler(
-    _api_key: ApiKey,
+    api_key: ApiKey,
     db: web::Data<Database>,
     path: web::Path<String>,
 ) -> Result<HttpResponse, ApiError> {
@@ -241,7 +280,7 @@ pub async fn delete_path_handler(
         }
     };
 
-    match PathService::delete_path(&db, &id).await {
+    match PathService::delete_path(&db, &api_key.name, &id).await {
         Ok(()) => {
             info!("Successfully soft deleted path with id: {}", id_str);
             Ok(HttpResponse::NoContent().finish())
@@ -266,7 +305,7 @@ pub async fn delete_path_handler(
 /// POST /paths/{id}/undelete - Restores a soft-deleted path
 /// Requires API key authentication via X-API-Key header
 pub async fn undelete_path_handler(
-    _api_key: ApiKey,
+    api_key: ApiKey,
     db: web::Data<Database>,
     path: web::Path<String>,
 ) -> Result<HttpResponse, ApiError> {
@@ -281,7 +320,7 @@ pub async fn undelete_path_handler(
         }
     };
 
-    match PathService::undelete_path(&db, &id).await {
+    match PathService::undelete_path(&db, &api_key.name, &id).await {
         Ok(path) => {
             info!("Successfully restored path with id: {}", id_str);
             Ok(HttpResponse::Ok().json(path))
@@ -305,9 +344,9 @@ pub async fn undelete_path_handler(
 
 /// DELETE /paths/{id}/hard - Hard deletes a path (permanently removes from database)
 /// Only works on paths that are already soft-deleted
-/// Requires API key authentication via X-API-Key header
+/// Requires an API key with the hard_delete scope via X-API-Key header
 pub async fn hard_delete_path_handler(
-    _api_key: ApiKey,
+    api_key: HardDeleteApiKey,
     db: web::Data<Database>,
     path: web::Path<String>,
 ) -> Result<HttpResponse, ApiError> {
@@ -322,7 +361,7 @@ pub async fn hard_delete_path_handler(
         }
     };
 
-    match PathService::hard_delete_path(&db, &id).await {
+    match PathService::hard_delete_path(&db, &api_key.0.name, &id).await {
         Ok(()) => {
             info!("Successfully hard deleted path with id: {}", id_str);
             Ok(HttpResponse::NoContent().finish())
@@ -343,3 +382,110 @@ pub async fn hard_delete_path_handler(
         }
     }
 }
+
+/// POST /paths/{id}/simulate - Quotes a path on-chain for a given anchor token amount
+///
+/// # Arguments
+/// * `db` - Database connection
+/// * `path` - Path parameters containing id
+/// * `body` - SimulatePathRequest containing the amount and the source chain
+///
+/// # Returns
+/// JSON object of PathSimulationResponse with per-hop amounts, price impact and profit in USD
+pub async fn simulate_path_handler(
+    db: web::Data<Database>,
+    path: web::Path<String>,
+    body: web::Json<SimulatePathRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let id_str = path.into_inner();
+    info!("Handling POST /paths/{}/simulate request", id_str);
+
+    let id = match ObjectId::parse_str(&id_str) {
+        Ok(id) => id,
+        Err(e) => {
+            error!("Invalid ObjectId format: {}", e);
+            return Err(ApiError::BadRequest(format!("Invalid ID format: {}", e)));
+        }
+    };
+
+    match PathService::simulate_path(&db, &id, body.into_inner()).await {
+        Ok(simulation) => {
+            info!(
+                "Successfully simulated {} routes for path with id: {}",
+                simulation.routes.len(),
+                id_str
+            );
+            Ok(HttpResponse::Ok().json(simulation))
+        }
+        Err(e) => {
+            error!("Failed to simulate path {}: {}", id_str, e);
+            let message = e.to_string();
+            if message.contains("not found") {
+                Err(ApiError::NotFound(message))
+            } else if message.contains("Invalid")
+                || message.contains("Failed to identify and fetch pool")
+            {
+                Err(ApiError::BadRequest(message))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to simulate path: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
+
+/// POST /paths/{id}/optimize - Finds the profit-maximizing input size of a path
+/// The search is bounded by the `max_amount_usd` of the config
+///
+/// # Arguments
+/// * `db` - Database connection
+/// * `path` - Path parameters containing id
+/// * `body` - OptimizePathRequest containing the source chain
+///
+/// # Returns
+/// JSON object of PathOptimizationResponse with the optimal size and profit of each route
+pub async fn optimize_path_handler(
+    db: web::Data<Database>,
+    path: web::Path<String>,
+    body: web::Json<OptimizePathRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let id_str = path.into_inner();
+    info!("Handling POST /paths/{}/optimize request", id_str);
+
+    let id = match ObjectId::parse_str(&id_str) {
+        Ok(id) => id,
+        Err(e) => {
+            error!("Invalid ObjectId format: {}", e);
+            return Err(ApiError::BadRequest(format!("Invalid ID format: {}", e)));
+        }
+    };
+
+    match PathService::optimize_path(&db, &id, body.into_inner()).await {
+        Ok(optimization) => {
+            info!(
+                "Successfully optimized {} routes for path with id: {}",
+                optimization.routes.len(),
+                id_str
+            );
+            Ok(HttpResponse::Ok().json(optimization))
+        }
+        Err(e) => {
+            error!("Failed to optimize path {}: {}", id_str, e);
+            let message = e.to_string();
+            if message.contains("not found") {
+                Err(ApiError::NotFound(message))
+            } else if message.contains("Invalid")
+                || message.contains("Failed to identify and fetch pool")
+            {
+                Err(ApiError::BadRequest(message))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to optimize path: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
@@ -1,20 +1,80 @@
-use alloy::primitives::Address;
-use futures::TryStreamExt;
-use log::debug;
+use alloy::primitives::{Address, U256};
+use alloy::providers::Provider;
+use log::{debug, warn};
 use mongodb::bson::{self, doc, oid::ObjectId};
 use mongodb::Database;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::RwLock;
 
 use crate::{
+    bot::models::path::{
+        find_optimal_amount, simulate_route, HopQuote, PoolDirection, PoolPath, RouteQuote,
+        SingleChainPathsWithAnchorToken,
+    },
+    bot::models::pool::PoolRegistry,
+    bot::models::profit_token::{
+        price_updater::PriceUpdater, registry::MultichainProfitTokenRegistry, ProfitToken,
+        ProfitTokenRegistry,
+    },
+    bot::models::token::TokenRegistry,
+    bot::providers::{token_fetcher::get_or_fetch_token, try_create_provider},
     database::models::utils::address_to_string,
-    database::models::Path,
+    database::models::{AuditAction, AuditEntity, Path},
+    database::repositories::{ListQuery, Page},
+    database::MongoDbService,
     handlers::{
-        path::dto::{CreatePathRequest, PathResponse, UpdatePathRequest},
+        audit::service::AuditService,
+        config::service::ConfigService,
+        network::service::NetworkService,
+        path::dto::{
+            CreatePathRequest, HopSimulationResponse, OptimizePathRequest,
+            PathOptimizationResponse, PathResponse, PathSimulationResponse,
+            RouteOptimizationResponse, RouteSimulationResponse, SimulatePathRequest,
+            UpdatePathRequest,
+        },
         pool::service::PoolService,
+        price::service::PriceService,
+        profit_token::service::ProfitTokenService,
+        token::service::TokenService,
     },
 };
 
+/// On-chain state of all legs of a path, loaded for a simulation
+struct Simulation {
+    source: SimulationChain,
+    targets: Vec<SimulationChain>,
+    /// Anchor token prices of every leg, keyed by chain_id
+    profit_tokens: MultichainProfitTokenRegistry,
+}
+
+/// On-chain state of one leg of a path
+struct SimulationChain {
+    chain_id: u64,
+    anchor_token: Address,
+    paths: Vec<PoolPath>,
+    pools: PoolRegistry,
+    tokens: Arc<RwLock<TokenRegistry>>,
+}
+
+/// USD value of a raw token amount
+fn to_usd(amount: U256, decimals: u8, price: f64) -> f64 {
+    f64::from(amount) / 10_f64.powi(decimals as i32) * price
+}
+
+impl SimulationChain {
+    /// Decimals of a token loaded for this chain
+    async fn decimals(&self, token: &Address) -> anyhow::Result<u8> {
+        self.tokens
+            .read()
+            .await
+            .get_token(*token)
+            .map(|token| token.decimals)
+            .ok_or_else(|| anyhow::anyhow!("Token {} not loaded", token))
+    }
+}
+
 /// Service layer for path-related business logic
 pub struct PathService;
 
@@ -29,7 +89,7 @@ impl PathService {
     /// - Verifies addresses are not zero
     /// - Verifies first token_in equals anchor_token
     /// - Verifies each token_out equals the next token_in (path connectivity)
-    fn validate_path_addresses(
+    pub(crate) fn validate_path_addresses(
         paths: &[crate::bot::models::path::SingleChainPathsWithAnchorToken],
     ) -> anyhow::Result<()> {
         use alloy::primitives::Address as AlloyAddress;
@@ -125,27 +185,61 @@ impl PathService {
         Ok(())
     }
 
-    /// Get all paths
+    /// Fetch the metadata of the tokens used by the paths in the background, per network
+    pub(crate) fn enrich_path_tokens(
+        db_service: &MongoDbService,
+        paths: &[SingleChainPathsWithAnchorToken],
+    ) {
+        let mut tokens_by_chain: HashMap<u64, Vec<Address>> = HashMap::new();
+        for single_chain_path in paths {
+            let tokens = tokens_by_chain
+                .entry(single_chain_path.chain_id)
+                .or_default();
+            tokens.push(single_chain_path.anchor_token);
+            for pool_direction in single_chain_path.paths.iter().flatten() {
+                tokens.push(pool_direction.token_in);
+                tokens.push(pool_direction.token_out);
+            }
+        }
+
+        for (chain_id, tokens) in tokens_by_chain {
+            TokenService::spawn_enrich_tokens(db_service.clone(), chain_id, tokens);
+        }
+    }
+
+    /// Get a page of paths
     ///
     /// # Arguments
-    /// * `db` - Database reference
+    /// * `db_service` - MongoDB service
+    /// * `chain_id` - Optional chain ID one of the legs must be on
+    /// * `anchor_token` - Optional anchor token one of the legs must use
+    /// * `query` - Pagination, sorting and filters
     ///
     /// # Returns
-    /// * `Ok(Vec<PathResponse>)` - List of paths
-    /// * `Err(anyhow::Error)` - Error if database operation fails
-    pub async fn get_all_paths(db: &Database) -> anyhow::Result<Vec<PathResponse>> {
-        debug!("Fetching all paths");
+    /// * `Ok(Page<PathResponse>)` - Page of paths with the total count
+    /// * `Err(anyhow::Error)` - Error if the query is invalid or the database operation fails
+    pub async fn get_paths(
+        db_service: &MongoDbService,
+        chain_id: Option<u64>,
+        anchor_token: Option<&str>,
+        query: &ListQuery,
+    ) -> anyhow::Result<Page<PathResponse>> {
+        debug!(
+            "Fetching paths with chain_id: {:?}, anchor_token: {:?}, query: {:?}",
+            chain_id, anchor_token, query
+        );
 
-        let collection = db.collection::<Path>("paths");
-        // Return all paths (including deleted ones)
-        let mut cursor = collection.find(doc! {}).await?;
-        let mut paths = Vec::new();
-
-        while let Some(path) = cursor.try_next().await? {
-            paths.push(Self::map_to_response(path));
-        }
+        let paths = db_service
+            .get_path_repo()
+            .find_page(chain_id, anchor_token, query)
+            .await?
+            .map(Self::map_to_response);
 
-        debug!("Retrieved {} paths from database", paths.len());
+        debug!(
+            "Retrieved {} of {} paths from database",
+            paths.items.len(),
+            paths.total
+        );
         Ok(paths)
     }
 
@@ -176,90 +270,44 @@ impl PathService {
         }
     }
 
-    /// Get paths by anchor token
-    ///
-    /// # Arguments
-    /// * `db` - Database reference
-    /// * `anchor_token` - The anchor token address
-    ///
-    /// # Returns
-    /// * `Ok(Vec<PathResponse>)` - List of paths
-    /// * `Err(anyhow::Error)` - Error if database operation fails
-    pub async fn get_paths_by_anchor_token(
-        db: &Database,
-        anchor_token: &str,
-    ) -> anyhow::Result<Vec<PathResponse>> {
-        debug!("Fetching paths with anchor_token: {}", anchor_token);
-
-        let collection = db.collection::<Path>("paths");
-        // Return all paths (including deleted ones)
-        let filter = doc! {
-            "paths": {
-                "$elemMatch": {
-                    "anchor_token": anchor_token
-                }
-            }
-        };
-        let mut cursor = collection.find(filter).await?;
-        let mut paths = Vec::new();
-
-        while let Some(path) = cursor.try_next().await? {
-            paths.push(Self::map_to_response(path));
-        }
-
-        debug!("Retrieved {} paths from database", paths.len());
-        Ok(paths)
-    }
-
-    /// Get paths by chain ID
-    ///
-    /// # Arguments
-    /// * `db` - Database reference
-    /// * `chain_id` - The chain ID to filter by
-    ///
-    /// # Returns
-    /// * `Ok(Vec<PathResponse>)` - List of paths
-    /// * `Err(anyhow::Error)` - Error if database operation fails
-    pub async fn get_paths_by_chain_id(
+    /// Record a change of a path in the audit log
+    async fn audit(
         db: &Database,
-        chain_id: u64,
-    ) -> anyhow::Result<Vec<PathResponse>> {
-        debug!("Fetching paths with chain_id: {}", chain_id);
-
-        let collection = db.collection::<Path>("paths");
-        // Return all paths (including deleted ones)
-        let filter = doc! {
-            "paths": {
-                "$elemMatch": {
-                    "chain_id": chain_id as i64
-                }
-            }
-        };
-        let mut cursor = collection.find(filter).await?;
-        let mut paths = Vec::new();
-
-        while let Some(path) = cursor.try_next().await? {
-            paths.push(Self::map_to_response(path));
-        }
-
-        debug!("Retrieved {} paths from database", paths.len());
-        Ok(paths)
+        actor: &str,
+        action: AuditAction,
+        id: &ObjectId,
+        before: Option<&Path>,
+        after: Option<&Path>,
+    ) {
+        AuditService::record(
+            db,
+            actor,
+            action,
+            AuditEntity::Path,
+            &id.to_hex(),
+            before,
+            after,
+        )
+        .await;
     }
 
     /// Create a new path
     ///
     /// # Arguments
-    /// * `db` - Database reference
+    /// * `db_service` - MongoDB service
+    /// * `actor` - Name of the API key making the change
     /// * `request` - CreatePathRequest containing path data
     ///
     /// # Returns
     /// * `Ok(PathResponse)` - Created path
     /// * `Err(anyhow::Error)` - Error if database operation fails
     pub async fn create_path(
-        db: &Database,
+        db_service: &MongoDbService,
+        actor: &str,
         request: CreatePathRequest,
     ) -> anyhow::Result<PathResponse> {
         debug!("Creating new path");
+        let db = &db_service.get_client().database();
 
         // Validate all addresses in paths before processing
         Self::validate_path_addresses(&request.paths)?;
@@ -275,15 +323,26 @@ impl PathService {
 
         let filter = doc! { "_id": id };
         let created_path = collection.find_one(filter).await?.unwrap();
+        Self::audit(
+            db,
+            actor,
+            AuditAction::Create,
+            &id,
+            None,
+            Some(&created_path),
+        )
+        .await;
 
         debug!("Path created successfully with id: {}", id);
+        Self::enrich_path_tokens(db_service, &created_path.paths);
         Ok(Self::map_to_response(created_path))
     }
 
     /// Update an existing path
     ///
     /// # Arguments
-    /// * `db` - Database reference
+    /// * `db_service` - MongoDB service
+    /// * `actor` - Name of the API key making the change
     /// * `id` - The ObjectId of the path to update
     /// * `request` - UpdatePathRequest containing fields to update
     ///
@@ -291,11 +350,13 @@ impl PathService {
     /// * `Ok(PathResponse)` - Updated path
     /// * `Err(anyhow::Error)` - Error if database operation fails or path not found
     pub async fn update_path(
-        db: &Database,
+        db_service: &MongoDbService,
+        actor: &str,
         id: &ObjectId,
         request: UpdatePathRequest,
     ) -> anyhow::Result<PathResponse> {
         debug!("Updating path with id: {}", id);
+        let db = &db_service.get_client().database();
 
         let collection = db.collection::<Path>("paths");
         // Only update non-deleted paths
@@ -308,10 +369,10 @@ impl PathService {
         };
 
         // Check if path exists and is not deleted
-        let existing = collection.find_one(filter.clone()).await?;
-        if existing.is_none() {
-            return Err(anyhow::anyhow!("Path with id {} not found", id));
-        }
+        let existing = collection
+            .find_one(filter.clone())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Path with id {} not found", id))?;
 
         // Build update document
         let mut update_doc = mongodb::bson::Document::new();
@@ -331,13 +392,25 @@ impl PathService {
 
         // Get updated path
         let path = collection.find_one(filter).await?.unwrap();
+        Self::audit(
+            db,
+            actor,
+            AuditAction::Update,
+            id,
+            Some(&existing),
+            Some(&path),
+        )
+        .await;
 
         debug!("Path updated successfully: {}", id);
+        if let Some(paths) = &request.paths {
+            Self::enrich_path_tokens(db_service, paths);
+        }
         Ok(Self::map_to_response(path))
     }
 
     /// Soft delete a path by ID (set deleted_at instead of removing)
-    pub async fn delete_path(db: &Database, id: &ObjectId) -> anyhow::Result<()> {
+    pub async fn delete_path(db: &Database, actor: &str, id: &ObjectId) -> anyhow::Result<()> {
         debug!("Soft deleting path with id: {}", id);
 
         let collection = db.collection::<Path>("paths");
@@ -349,13 +422,10 @@ impl PathService {
             ]
         };
 
-        let existing = collection.find_one(filter.clone()).await?;
-        if existing.is_none() {
-            return Err(anyhow::anyhow!(
-                "Path with id {} not found or already deleted",
-                id
-            ));
-        }
+        let existing = collection
+            .find_one(filter.clone())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Path with id {} not found or already deleted", id))?;
 
         let update = doc! {
             "$set": {
@@ -366,6 +436,17 @@ impl PathService {
 
         collection.update_one(filter, update).await?;
 
+        let deleted = collection.find_one(doc! { "_id": id }).await?;
+        Self::audit(
+            db,
+            actor,
+            AuditAction::Delete,
+            id,
+            Some(&existing),
+            deleted.as_ref(),
+        )
+        .await;
+
         debug!("Path soft deleted successfully: {}", id);
         Ok(())
     }
@@ -375,12 +456,13 @@ impl PathService {
     ///
     /// # Arguments
     /// * `db` - Database reference
+    /// * `actor` - Name of the API key making the change
     /// * `id` - The ObjectId of the path to hard delete
     ///
     /// # Returns
     /// * `Ok(())` - Successfully deleted
     /// * `Err(anyhow::Error)` - Error if path not found or not soft-deleted
-    pub async fn hard_delete_path(db: &Database, id: &ObjectId) -> anyhow::Result<()> {
+    pub async fn hard_delete_path(db: &Database, actor: &str, id: &ObjectId) -> anyhow::Result<()> {
         debug!("Hard deleting path with id: {}", id);
 
         let collection = db.collection::<Path>("paths");
@@ -391,16 +473,22 @@ impl PathService {
         };
 
         // Check if path exists and is soft-deleted
-        let existing = collection.find_one(filter.clone()).await?;
-        if existing.is_none() {
-            return Err(anyhow::anyhow!(
-                "Path with id {} not found or not soft-deleted",
-                id
-            ));
-        }
+        let existing = collection
+            .find_one(filter.clone())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Path with id {} not found or not soft-deleted", id))?;
 
         // Hard delete: actually remove from database
         collection.delete_one(filter).await?;
+        Self::audit(
+            db,
+            actor,
+            AuditAction::HardDelete,
+            id,
+            Some(&existing),
+            None,
+        )
+        .await;
 
         debug!("Path hard deleted successfully: {}", id);
         Ok(())
@@ -410,22 +498,27 @@ impl PathService {
     ///
     /// # Arguments
     /// * `db` - Database reference
+    /// * `actor` - Name of the API key making the change
     /// * `id` - The ObjectId of the path to restore
     ///
     /// # Returns
     /// * `Ok(PathResponse)` - Restored path
     /// * `Err(anyhow::Error)` - Error if database operation fails or path not found
-    pub async fn undelete_path(db: &Database, id: &ObjectId) -> anyhow::Result<PathResponse> {
+    pub async fn undelete_path(
+        db: &Database,
+        actor: &str,
+        id: &ObjectId,
+    ) -> anyhow::Result<PathResponse> {
         debug!("Restoring path with id: {}", id);
 
         let collection = db.collection::<Path>("paths");
         let filter = doc! { "_id": id };
 
         // Check if path exists
-        let existing = collection.find_one(filter.clone()).await?;
-        if existing.is_none() {
-            return Err(anyhow::anyhow!("Path with id {} not found", id));
-        }
+        let existing = collection
+            .find_one(filter.clone())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Path with id {} not found", id))?;
 
         let update = doc! {
             "$set": {
@@ -437,10 +530,497 @@ impl PathService {
         collection.update_one(filter.clone(), update).await?;
 
         let restored_path = collection.find_one(filter).await?.unwrap();
+        Self::audit(
+            db,
+            actor,
+            AuditAction::Undelete,
+            id,
+            Some(&existing),
+            Some(&restored_path),
+        )
+        .await;
         debug!("Path restored successfully: {}", id);
         Ok(Self::map_to_response(restored_path))
     }
 
+    /// Simulate a path for a given anchor token amount
+    ///
+    /// Every pool of every leg is fetched on-chain at the latest block. Each path of the source
+    /// chain is combined with each path of the other chains: the source path is walked forward
+    /// from the anchor token and the target path is walked back to its anchor token.
+    ///
+    /// # Arguments
+    /// * `db` - Database reference
+    /// * `id` - The ObjectId of the path to simulate
+    /// * `request` - SimulatePathRequest containing the amount and the source chain
+    ///
+    /// # Returns
+    /// * `Ok(PathSimulationResponse)` - Per-route hop amounts, price impact and USD delta
+    /// * `Err(anyhow::Error)` - Error if the path is not found or cannot be loaded
+    pub async fn simulate_path(
+        db: &Database,
+        id: &ObjectId,
+        request: SimulatePathRequest,
+    ) -> anyhow::Result<PathSimulationResponse> {
+        debug!("Simulating path with id: {}", id);
+
+        if !request.amount.is_finite() || request.amount <= 0.0 {
+            return Err(anyhow::anyhow!(
+                "Invalid amount: {} must be greater than 0",
+                request.amount
+            ));
+        }
+
+        let simulation = Self::load_simulation(db, id, request.source_chain_id).await?;
+        let source = &simulation.source;
+        let amount_in = simulation
+            .profit_tokens
+            .to_raw_amount_f64(source.chain_id, source.anchor_token, request.amount)
+            .await?;
+        let amount_in_usd = simulation
+            .profit_tokens
+            .get_value(source.chain_id, source.anchor_token, amount_in)
+            .await;
+
+        let mut routes = Vec::new();
+        for target in &simulation.targets {
+            for (source_path_index, source_path) in source.paths.iter().enumerate() {
+                for (target_path_index, target_path) in target.paths.iter().enumerate() {
+                    let mut route = RouteSimulationResponse {
+                        target_chain_id: target.chain_id,
+                        source_path_index,
+                        target_path_index,
+                        source_hops: Vec::new(),
+                        target_hops: Vec::new(),
+                        amount_out: None,
+                        amount_out_usd: None,
+                        profit_usd: None,
+                        error: None,
+                    };
+
+                    let quote = async {
+                        let bridge_decimals =
+                            Self::bridge_decimals(source, source_path, target, target_path).await?;
+                        simulate_route(
+                            &source.pools,
+                            source_path,
+                            &target.pools,
+                            target_path,
+                            bridge_decimals,
+                            amount_in,
+                        )
+                        .await
+                    }
+                    .await;
+
+                    match quote {
+                        Ok(quote) => {
+                            let amount_out_usd = simulation
+                                .profit_tokens
+                                .get_value(target.chain_id, target.anchor_token, quote.amount_out)
+                                .await;
+                            route.source_hops =
+                                quote.source_hops.iter().map(Self::map_hop).collect();
+                            route.target_hops =
+                                quote.target_hops.iter().map(Self::map_hop).collect();
+                            route.amount_out = Some(quote.amount_out.to_string());
+                            route.amount_out_usd = amount_out_usd;
+                            route.profit_usd = amount_in_usd
+                                .zip(amount_out_usd)
+                                .map(|(value_in, value_out)| value_out - value_in);
+                        }
+                        Err(e) => {
+                            warn!(
+                                "Failed to simulate route {}->{} of path {}: {}",
+                                source_path_index, target_path_index, id, e
+                            );
+                            route.error = Some(e.to_string());
+                        }
+                    }
+
+                    routes.push(route);
+                }
+            }
+        }
+
+        debug!("Simulated {} routes for path {}", routes.len(), id);
+        Ok(PathSimulationResponse {
+            path_id: id.to_hex(),
+            source_chain_id: source.chain_id,
+            anchor_token: address_to_string(&source.anchor_token),
+            amount_in: amount_in.to_string(),
+            amount_in_usd,
+            routes,
+        })
+    }
+
+    /// Find the profit-maximizing input size of every route of a path
+    ///
+    /// The search is bounded by the `max_amount_usd` of the config, converted to the source
+    /// anchor token at its current price. Profit is the USD delta between the anchor token
+    /// received on the target chain and the anchor token sent on the source chain.
+    ///
+    /// # Arguments
+    /// * `db` - Database reference
+    /// * `id` - The ObjectId of the path to optimize
+    /// * `request` - OptimizePathRequest containing the source chain
+    ///
+    /// # Returns
+    /// * `Ok(PathOptimizationResponse)` - Optimal size and expected profit of each route
+    /// * `Err(anyhow::Error)` - Error if the path is not found, cannot be loaded or priced
+    pub async fn optimize_path(
+        db: &Database,
+        id: &ObjectId,
+        request: OptimizePathRequest,
+    ) -> anyhow::Result<PathOptimizationResponse> {
+        debug!("Optimizing path with id: {}", id);
+
+        let max_amount_usd = ConfigService::get_config(db)
+            .await?
+            .map(|config| config.max_amount_usd)
+            .filter(|max_amount_usd| *max_amount_usd > 0.0)
+            .ok_or_else(|| {
+                anyhow::anyhow!("Invalid config: max_amount_usd must be configured and above 0")
+            })?;
+
+        let simulation = Self::load_simulation(db, id, request.source_chain_id).await?;
+        let source = &simulation.source;
+        let source_price = simulation
+            .profit_tokens
+            .get_price(source.chain_id, source.anchor_token)
+            .await
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Price of anchor token {} on network {} is unavailable",
+                    source.anchor_token,
+                    source.chain_id
+                )
+            })?;
+        let max_amount_in = simulation
+            .profit_tokens
+            .to_raw_amount_f64(
+                source.chain_id,
+                source.anchor_token,
+                max_amount_usd / source_price,
+            )
+            .await?;
+        let source_decimals = source.decimals(&source.anchor_token).await?;
+
+        let mut routes = Vec::new();
+        for target in &simulation.targets {
+            let target_price = simulation
+                .profit_tokens
+                .get_price(target.chain_id, target.anchor_token)
+                .await;
+            let target_decimals = target.decimals(&target.anchor_token).await?;
+
+            for (source_path_index, source_path) in source.paths.iter().enumerate() {
+                for (target_path_index, target_path) in target.paths.iter().enumerate() {
+                    let mut route = RouteOptimizationResponse {
+                        target_chain_id: target.chain_id,
+                        source_path_index,
+                        target_path_index,
+                        amount_in: None,
+                        amount_in_usd: None,
+                        amount_out: None,
+                        amount_out_usd: None,
+                        profit_usd: None,
+                        profitable: false,
+                        source_hops: Vec::new(),
+                        target_hops: Vec::new(),
+                        error: None,
+                    };
+
+                    let optimal = async {
+                        let target_price = target_price.ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "Price of anchor token {} on network {} is unavailable",
+                                target.anchor_token,
+                                target.chain_id
+                            )
+                        })?;
+                        let bridge_decimals =
+                            Self::bridge_decimals(source, source_path, target, target_path).await?;
+                        let profit_usd = |quote: &RouteQuote| {
+                            to_usd(quote.amount_out, target_decimals, target_price)
+                                - to_usd(quote.amount_in, source_decimals, source_price)
+                        };
+
+                        find_optimal_amount(
+                            &source.pools,
+                            source_path,
+                            &target.pools,
+                            target_path,
+                            bridge_decimals,
+                            max_amount_in,
+                            profit_usd,
+                        )
+                        .await
+                        .map(|optimal| (optimal, target_price))
+                        .ok_or_else(|| anyhow::anyhow!("Route cannot be quoted for any amount"))
+                    }
+                    .await;
+
+                    match optimal {
+                        Ok((optimal, target_price)) => {
+                            let quote = &optimal.quote;
+                            route.amount_in = Some(optimal.amount_in.to_string());
+                            route.amount_in_usd =
+                                Some(to_usd(optimal.amount_in, source_decimals, source_price));
+                            route.amount_out = Some(quote.amount_out.to_string());
+                            route.amount_out_usd =
+                                Some(to_usd(quote.amount_out, target_decimals, target_price));
+                            route.profit_usd = Some(optimal.profit);
+                            route.profitable = optimal.profit > 0.0;
+                            route.source_hops =
+                                quote.source_hops.iter().map(Self::map_hop).collect();
+                            route.target_hops =
+                                quote.target_hops.iter().map(Self::map_hop).collect();
+                        }
+                        Err(e) => {
+                            warn!(
+                                "Failed to optimize route {}->{} of path {}: {}",
+                                source_path_index, target_path_index, id, e
+                            );
+                            route.error = Some(e.to_string());
+                        }
+                    }
+
+                    routes.push(route);
+                }
+            }
+        }
+
+        let best_route = routes
+            .iter()
+            .enumerate()
+            .filter_map(|(index, route)| route.profit_usd.map(|profit| (index, profit)))
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(index, _)| index);
+
+        debug!("Optimized {} routes for path {}", routes.len(), id);
+        Ok(PathOptimizationResponse {
+            path_id: id.to_hex(),
+            source_chain_id: source.chain_id,
+            anchor_token: address_to_string(&source.anchor_token),
+            max_amount_usd,
+            max_amount_in: max_amount_in.to_string(),
+            best_route,
+            routes,
+        })
+    }
+
+    /// Load a path and the on-chain state of all its legs for a simulation
+    ///
+    /// The source leg is `source_chain_id` (the first leg by default), every other leg is a target.
+    async fn load_simulation(
+        db: &Database,
+        id: &ObjectId,
+        source_chain_id: Option<u64>,
+    ) -> anyhow::Result<Simulation> {
+        let collection = db.collection::<Path>("paths");
+        let filter = doc! {
+            "_id": id,
+            "$or": [
+                { "deleted_at": null },
+                { "deleted_at": { "$exists": false } }
+            ]
+        };
+        let path = collection
+            .find_one(filter)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Path with id {} not found", id))?;
+
+        let source_chain_id = source_chain_id
+            .or_else(|| path.paths.first().map(|leg| leg.chain_id))
+            .ok_or_else(|| anyhow::anyhow!("Invalid path: path has no chains"))?;
+        let source_leg = path
+            .paths
+            .iter()
+            .find(|leg| leg.chain_id == source_chain_id)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid source chain: chain {} is not part of the path",
+                    source_chain_id
+                )
+            })?;
+        let target_legs: Vec<&SingleChainPathsWithAnchorToken> = path
+            .paths
+            .iter()
+            .filter(|leg| leg.chain_id != source_chain_id)
+            .collect();
+        if target_legs.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Invalid path: at least one chain besides the source chain is required"
+            ));
+        }
+
+        let profit_tokens = MultichainProfitTokenRegistry::new(0.0);
+        let source = Self::load_simulation_chain(db, source_leg, &profit_tokens).await?;
+        let mut targets = Vec::with_capacity(target_legs.len());
+        for target_leg in target_legs {
+            targets.push(Self::load_simulation_chain(db, target_leg, &profit_tokens).await?);
+        }
+
+        Ok(Simulation {
+            source,
+            targets,
+            profit_tokens,
+        })
+    }
+
+    /// Load the pools, tokens and anchor token price of one leg of a path
+    ///
+    /// Pools are read at a single block so every hop of the leg sees the same state.
+    /// The anchor token is priced like `GET /prices/network/{network_id}/token/{address}`: from the last
+    /// snapshot of the price refresher, else from the `default_price` of its profit token
+    /// configuration. A token that is neither is priced once on the network's `price_network`.
+    /// Without a price it is not registered as a profit token, so USD values stay empty rather
+    /// than falling back to an arbitrary price.
+    async fn load_simulation_chain(
+        db: &Database,
+        leg: &SingleChainPathsWithAnchorToken,
+        profit_tokens: &MultichainProfitTokenRegistry,
+    ) -> anyhow::Result<SimulationChain> {
+        let network = NetworkService::get_network_by_chain_id(db, leg.chain_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Network with chain_id {} not found", leg.chain_id))?;
+        if network.deleted {
+            return Err(anyhow::anyhow!(
+                "Network with chain_id {} not found",
+                leg.chain_id
+            ));
+        }
+
+        let provider = Arc::new(try_create_provider(&network.rpcs)?);
+        let multicall_address = PoolService::multicall_address(&network);
+        let block_number = provider.get_block_number().await?;
+
+        let pools = PoolRegistry::new((*provider).clone(), leg.chain_id);
+        let pool_addresses: HashSet<Address> = leg
+            .paths
+            .iter()
+            .flatten()
+            .map(|direction| direction.pool)
+            .collect();
+        for pool_address in pool_addresses {
+            let pool = PoolService::fetch_pool_at_block(
+                provider.clone(),
+                &network,
+                pool_address,
+                block_number,
+            )
+            .await
+            .map_err(|e| {
+                anyhow::anyhow!(
+                    "Failed to identify and fetch pool: {} for pool address: {}",
+                    e,
+                    pool_address
+                )
+            })?;
+            pools.add_pool(pool.clone_box()).await;
+        }
+
+        // Anchor token for the USD value, last tokens of each path for the bridge
+        let tokens = Arc::new(RwLock::new(TokenRegistry::new(leg.chain_id)));
+        let mut token_addresses = vec![leg.anchor_token];
+        token_addresses.extend(
+            leg.paths
+                .iter()
+                .filter_map(|pool_path| pool_path.last())
+                .map(|direction| direction.token_out),
+        );
+        for token in token_addresses {
+            get_or_fetch_token(&tokens, provider.clone(), token, multicall_address).await?;
+        }
+
+        let wrap_native = Address::from_str(&network.wrap_native).unwrap_or_default();
+        let profit_token_registry = ProfitTokenRegistry::new(
+            wrap_native,
+            tokens.clone(),
+            Arc::new(RwLock::new(PriceUpdater::default())),
+            network.min_profit_usd,
+        );
+        let config = ProfitTokenService::find_config(db, leg.chain_id, &leg.anchor_token).await?;
+        let snapshot = PriceService::find_snapshot(db, leg.chain_id, &leg.anchor_token).await?;
+        let mut price = PriceService::resolve_price(leg.chain_id, config.as_ref(), snapshot)
+            .map(|price| price.price_usd);
+        if let (None, Some(price_network)) = (price, &network.price_network) {
+            // Neither a profit token nor priced by the price refresher yet
+            let price_updater =
+                PriceUpdater::new(price_network.clone(), vec![leg.anchor_token]).await;
+            price = match price_updater.update_prices().await {
+                Ok(prices) => prices.get(&leg.anchor_token).map(|price| price.price),
+                Err(e) => {
+                    warn!(
+                        "Failed to fetch price of anchor token {} on network {}: {}",
+                        leg.anchor_token, leg.chain_id, e
+                    );
+                    None
+                }
+            };
+        }
+        let config = config.map(|config| config.to_profit_token()).transpose()?;
+        if let Some(price) = price {
+            let profit_token = match config {
+                Some(config) => ProfitToken {
+                    price: Some(price),
+                    ..config
+                },
+                None => ProfitToken {
+                    address: leg.anchor_token,
+                    min_profit: U256::ZERO,
+                    price_source: None,
+                    price: Some(price),
+                    default_price: price,
+                },
+            };
+            profit_token_registry
+                .add_token(leg.anchor_token, profit_token)
+                .await;
+        }
+        profit_tokens
+            .add_profit_token_registry(leg.chain_id, profit_token_registry)
+            .await;
+
+        Ok(SimulationChain {
+            chain_id: leg.chain_id,
+            anchor_token: leg.anchor_token,
+            paths: leg.paths.clone(),
+            pools,
+            tokens,
+        })
+    }
+
+    /// Decimals of the bridged token at the end of a source path and a target path
+    async fn bridge_decimals(
+        source: &SimulationChain,
+        source_path: &[PoolDirection],
+        target: &SimulationChain,
+        target_path: &[PoolDirection],
+    ) -> anyhow::Result<(u8, u8)> {
+        let (Some(source_end), Some(target_end)) = (source_path.last(), target_path.last()) else {
+            return Err(anyhow::anyhow!("Pool path cannot be empty"));
+        };
+
+        Ok((
+            source.decimals(&source_end.token_out).await?,
+            target.decimals(&target_end.token_out).await?,
+        ))
+    }
+
+    /// Map a hop quote to HopSimulationResponse DTO
+    fn map_hop(hop: &HopQuote) -> HopSimulationResponse {
+        HopSimulationResponse {
+            pool: address_to_string(&hop.pool),
+            token_in: address_to_string(&hop.token_in),
+            token_out: address_to_string(&hop.token_out),
+            amount_in: hop.amount_in.to_string(),
+            amount_out: hop.amount_out.to_string(),
+            price_impact: hop.price_impact,
+        }
+    }
+
     /// Map Path model to PathResponse DTO
     ///
     /// # Arguments
@@ -448,7 +1028,7 @@ impl PathService {
     ///
     /// # Returns
     /// PathResponse DTO
-    fn map_to_response(path: Path) -> PathResponse {
+    pub(crate) fn map_to_response(path: Path) -> PathResponse {
         let id = path
             .id
             .map(|oid| oid.to_hex())
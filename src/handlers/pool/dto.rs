@@ -10,6 +10,26 @@ pub struct PoolResponse {
     pub updated_at: u64,
 }
 
+/// Response model for the live on-chain state of a pool
+/// V2 pools fill `reserve0`/`reserve1`, V3 pools fill `sqrt_price_x96`/`tick`/`tick_spacing`/`liquidity`
+#[derive(Debug, Serialize)]
+pub struct PoolStateResponse {
+    pub network_id: u64,
+    pub address: String,
+    pub pool_type: String, // UniswapV2, Stable, UniswapV3, PancakeV3, AlgebraV3, RamsesV2, ...
+    pub token0: String,
+    pub token1: String,
+    pub fee: f64,     // fee as a fraction (e.g. 0.003 for 0.3%)
+    pub fee_raw: u64, // fee as stored on-chain
+    pub reserve0: Option<String>,
+    pub reserve1: Option<String>,
+    pub sqrt_price_x96: Option<String>,
+    pub tick: Option<i32>,
+    pub tick_spacing: Option<i32>,
+    pub liquidity: Option<String>,
+    pub block_number: u64,
+}
+
 /// Request model for creating a new pool
 #[derive(Debug, Deserialize)]
 pub struct CreatePoolRequest {
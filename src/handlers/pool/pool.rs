@@ -5,9 +5,10 @@ use mongodb::bson::oid::ObjectId;
 use mongodb::Database;
 
 use crate::{
+    database::{repositories::ListQuery, MongoDbService},
     errors::ApiError,
     handlers::{
-        config::auth::ApiKey,
+        config::auth::{ApiKey, HardDeleteApiKey},
         pool::{
             dto::{CreatePoolRequest, UpdatePoolRequest},
             service::PoolService,
@@ -15,54 +16,81 @@ use crate::{
     },
 };
 
-/// GET /pools - Returns all pools
+/// GET /pools - Returns a page of pools
+///
+/// # Arguments
+/// * `db_service` - MongoDB service
+/// * `query` - Query parameters with pagination (limit, page or cursor), sort and filters
+///   (include_deleted, updated_since)
 ///
 /// # Returns
-/// JSON array of PoolResponse objects containing pool information
-pub async fn get_pools_handler(db: web::Data<Database>) -> Result<HttpResponse, ApiError> {
+/// JSON object of Page<PoolResponse> with the total count
+pub async fn get_pools_handler(
+    db_service: web::Data<MongoDbService>,
+    query: web::Query<ListQuery>,
+) -> Result<HttpResponse, ApiError> {
     info!("Handling GET /pools request");
 
-    match PoolService::get_all_pools(&db).await {
+    match PoolService::get_pools(&db_service, None, &query).await {
         Ok(pools) => {
-            info!("Successfully retrieved {} pools", pools.len());
+            info!(
+                "Successfully retrieved {} of {} pools",
+                pools.items.len(),
+                pools.total
+            );
             Ok(HttpResponse::Ok().json(pools))
         }
         Err(e) => {
             error!("Failed to retrieve pools: {}", e);
-            Err(ApiError::DatabaseError(format!(
-                "Failed to retrieve pools: {}",
-                e
-            )))
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to retrieve pools: {}",
+                    e
+                )))
+            }
         }
     }
 }
 
-/// GET /pools/network/{network_id} - Returns pools by network ID
+/// GET /pools/network/{network_id} - Returns a page of the pools of a network
 ///
 /// # Arguments
-/// * `db` - Database connection
+/// * `db_service` - MongoDB service
 /// * `path` - Path parameters containing network_id
+/// * `query` - Query parameters with pagination (limit, page or cursor), sort and filters
+///   (include_deleted, updated_since)
 ///
 /// # Returns
-/// JSON array of PoolResponse objects
+/// JSON object of Page<PoolResponse> with the total count
 pub async fn get_pools_by_network_id_handler(
-    db: web::Data<Database>,
+    db_service: web::Data<MongoDbService>,
     path: web::Path<u64>,
+    query: web::Query<ListQuery>,
 ) -> Result<HttpResponse, ApiError> {
     let network_id = path.into_inner();
     info!("Handling GET /pools/network/{} request", network_id);
 
-    match PoolService::get_pools_by_network_id(&db, network_id).await {
+    match PoolService::get_pools(&db_service, Some(network_id), &query).await {
         Ok(pools) => {
-            info!("Successfully retrieved {} pools", pools.len());
+            info!(
+                "Successfully retrieved {} of {} pools",
+                pools.items.len(),
+                pools.total
+            );
             Ok(HttpResponse::Ok().json(pools))
         }
         Err(e) => {
             error!("Failed to retrieve pools: {}", e);
-            Err(ApiError::DatabaseError(format!(
-                "Failed to retrieve pools: {}",
-                e
-            )))
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to retrieve pools: {}",
+                    e
+                )))
+            }
         }
     }
 }
@@ -118,6 +146,62 @@ pub async fn get_pool_by_address_handler(
     }
 }
 
+/// GET /pools/network/{network_id}/address/{address}/state - Returns the live on-chain state of a pool
+///
+/// # Arguments
+/// * `db` - Database connection
+/// * `path` - Path parameters containing network_id and address
+///
+/// # Returns
+/// JSON object of PoolStateResponse containing pool type, tokens, fee and reserves or V3 state
+pub async fn get_pool_state_handler(
+    db: web::Data<Database>,
+    path: web::Path<(u64, String)>,
+) -> Result<HttpResponse, ApiError> {
+    let (network_id, address_str) = path.into_inner();
+    info!(
+        "Handling GET /pools/network/{}/address/{}/state request",
+        network_id, address_str
+    );
+
+    let address = match address_str.parse::<Address>() {
+        Ok(addr) => addr,
+        Err(e) => {
+            error!("Invalid address format: {}", e);
+            return Err(ApiError::BadRequest(format!(
+                "Invalid address format: {}",
+                e
+            )));
+        }
+    };
+
+    match PoolService::get_pool_state(&db, network_id, &address).await {
+        Ok(state) => {
+            info!(
+                "Successfully retrieved state of pool {} at block {}",
+                address_str, state.block_number
+            );
+            Ok(HttpResponse::Ok().json(state))
+        }
+        Err(e) => {
+            error!("Failed to retrieve pool state: {}", e);
+            // On-chain errors may mention "not found" too, they are told apart first
+            if e.to_string().contains("Failed to identify and fetch pool") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else if e.to_string().contains("RPC request failed") {
+                Err(ApiError::UpstreamError(e.to_string()))
+            } else if e.to_string().contains("not found") {
+                Err(ApiError::NotFound(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to retrieve pool state: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
+
 /// GET /pools/network/{network_id}/count - Returns count of pools by network ID
 ///
 /// # Arguments
@@ -148,24 +232,24 @@ pub async fn count_pools_by_network_id_handler(
     }
 }
 
-/// POST /pools - Creates a new pool
+/// POST /pools - Creates a new pool and fetches the metadata of its tokens in the background
 /// Requires API key authentication via X-API-Key header
 ///
 /// # Arguments
-/// * `_api_key` - API key from X-API-Key header (validated by extractor)
-/// * `db` - Database connection
+/// * `api_key` - API key from X-API-Key header (validated by extractor), recorded in the audit log
+/// * `db_service` - MongoDB service
 /// * `body` - CreatePoolRequest containing pool data
 ///
 /// # Returns
 /// JSON object of PoolResponse containing created pool information
 pub async fn create_pool_handler(
-    _api_key: ApiKey,
-    db: web::Data<Database>,
+    api_key: ApiKey,
+    db_service: web::Data<MongoDbService>,
     body: web::Json<CreatePoolRequest>,
 ) -> Result<HttpResponse, ApiError> {
     info!("Handling POST /pools request");
 
-    match PoolService::create_pool(&db, body.into_inner()).await {
+    match PoolService::create_pool(&db_service, &api_key.name, body.into_inner()).await {
         Ok(pool) => {
             info!("Successfully created pool with id: {}", pool.id);
             Ok(HttpResponse::Created().json(pool))
@@ -184,7 +268,7 @@ pub async fn create_pool_handler(
 /// Requires API key authentication via X-API-Key header
 ///
 /// # Arguments
-/// * `_api_key` - API key from X-API-Key header (validated by extractor)
+/// * `api_key` - API key from X-API-Key header (validated by extractor), recorded in the audit log
 /// * `db` - Database connection
 /// * `path` - Path parameters containing id
 /// * `body` - UpdatePoolRequest containing fields to update
@@ -192,7 +276,7 @@ pub async fn create_pool_handler(
 /// # Returns
 /// JSON object of PoolResponse containing updated pool information
 pub async fn update_pool_handler(
-    _api_key: ApiKey,
+    api_key: ApiKey,
     db: web::Data<Database>,
     path: web::Path<String>,
     body: web::Json<UpdatePoolRequest>,
@@ -208,7 +292,7 @@ pub async fn update_pool_handler(
         }
     };
 
-    match PoolService::update_pool(&db, &id, body.into_inner()).await {
+    match PoolService::update_pool(&db, &api_key.name, &id, body.into_inner()).await {
         Ok(pool) => {
             info!("Successfully updated pool with id: {}", id_str);
             Ok(HttpResponse::Ok().json(pool))
@@ -233,7 +317,7 @@ pub async fn update_pool_handler(
 /// DELETE /pools/{id} - Soft deletes a pool (sets deleted_at)
 /// Requires API key authentication via X-API-Key header
 pub async fn delete_pool_handler(
-    _api_key: ApiKey,
+    api_key: ApiKey,
     db: web::Data<Database>,
     path: web::Path<String>,
 ) -> Result<HttpResponse, ApiError> {
@@ -248,7 +332,7 @@ pub async fn delete_pool_handler(
         }
     };
 
-    match PoolService::delete_pool(&db, &id).await {
+    match PoolService::delete_pool(&db, &api_key.name, &id).await {
         Ok(()) => {
             info!("Successfully soft deleted pool with id: {}", id_str);
             Ok(HttpResponse::NoContent().finish())
@@ -272,9 +356,9 @@ pub async fn delete_pool_handler(
 
 /// DELETE /pools/{id}/hard - Hard deletes a pool (permanently removes from database)
 /// Only works on pools that are already soft-deleted
-/// Requires API key authentication via X-API-Key header
+/// Requires an API key with the hard_delete scope via X-API-Key header
 pub async fn hard_delete_pool_handler(
-    _api_key: ApiKey,
+    api_key: HardDeleteApiKey,
     db: web::Data<Database>,
     path: web::Path<String>,
 ) -> Result<HttpResponse, ApiError> {
@@ -289,7 +373,7 @@ pub async fn hard_delete_pool_handler(
         }
     };
 
-    match PoolService::hard_delete_pool(&db, &id).await {
+    match PoolService::hard_delete_pool(&db, &api_key.0.name, &id).await {
         Ok(()) => {
             info!("Successfully hard deleted pool with id: {}", id_str);
             Ok(HttpResponse::NoContent().finish())
@@ -1,7 +1,6 @@
 use alloy::eips::{BlockId, BlockNumberOrTag};
 use alloy::primitives::Address;
-use alloy::providers::{Provider, ProviderBuilder, MULTICALL3_ADDRESS};
-use futures::TryStreamExt;
+use alloy::providers::{DynProvider, Provider, ProviderBuilder, MULTICALL3_ADDRESS};
 use log::{debug, error, info, warn};
 use mongodb::bson::{doc, oid::ObjectId};
 use mongodb::Database;
@@ -10,13 +9,19 @@ use std::str::FromStr;
 use std::sync::Arc;
 use url::Url;
 
-use crate::bot::providers::create_provider;
-use crate::handlers::network::service::NetworkService;
+use crate::bot::providers::{is_rpc_failure, try_create_provider};
+use crate::handlers::network::{dto::NetworkResponse, service::NetworkService};
 use crate::{
+    bot::models::pool::base::PoolInterface,
+    bot::models::pool::{v2::UniswapV2Pool, v3::UniswapV3Pool},
     bot::providers::pool_fetcher::identify_and_fetch_pool,
     database::models::utils::address_to_string,
-    database::models::{Network, Pool},
-    handlers::pool::dto::{CreatePoolRequest, PoolResponse, UpdatePoolRequest},
+    database::models::{AuditAction, AuditEntity, Network, Pool},
+    database::repositories::{ListQuery, Page},
+    database::MongoDbService,
+    handlers::audit::service::AuditService,
+    handlers::pool::dto::{CreatePoolRequest, PoolResponse, PoolStateResponse, UpdatePoolRequest},
+    handlers::token::service::TokenService,
 };
 
 /// Service layer for pool-related business logic
@@ -28,118 +33,150 @@ impl PoolService {
         Address::from_str(address)
             .map_err(|e| anyhow::anyhow!("Invalid address format '{}': {}", address, e))
     }
-    /// Verify a pool on-chain by identifying its type and fetching its data.
+    /// Identify a pool on-chain and fetch its full state at the latest block.
     ///
-    /// This ensures the pool is a valid Uniswap V2/V3 pool before we persist it.
-    async fn verify_pool_on_chain(
+    /// Uses the network's `rpcs` and `multicall_address` (Multicall3 by default).
+    ///
+    /// # Returns
+    /// * `Ok((pool, block_number))` - Pool state and the block it was read at
+    /// * `Err(anyhow::Error)` - Error if the network is not found or deleted, the RPC request
+    ///   fails ("RPC request failed") or the pool cannot be identified and fetched
+    async fn fetch_pool_on_chain(
         db: &Database,
         network_id: u64,
         address: &str,
-    ) -> anyhow::Result<()> {
-        let network = NetworkService::get_network_by_chain_id(db, network_id).await?;
-        match network {
-            Some(network) => {
-                let provider = create_provider(network.rpcs);
-                let multicall_address: Address = network
-                    .multicall_address
-                    .as_ref()
-                    .and_then(|s| Address::from_str(s).ok())
-                    .unwrap_or(MULTICALL3_ADDRESS);
-
-                if let Err(e) = identify_and_fetch_pool(
-                    Arc::new(provider),
-                    address.parse::<Address>().unwrap(),
-                    BlockId::Number(BlockNumberOrTag::Latest),
-                    multicall_address,
-                    &network.v2_factory_to_fee.unwrap_or_default(),
-                    &network
-                        .aero_factory_addresses
-                        .unwrap_or_default()
-                        .iter()
-                        .map(|address| address.parse::<Address>().unwrap())
-                        .collect::<Vec<Address>>(),
-                )
-                .await
-                {
-                    return Err(anyhow::anyhow!(
+    ) -> anyhow::Result<(Box<dyn PoolInterface>, u64)> {
+        let network = NetworkService::get_network_by_chain_id(db, network_id)
+            .await?
+            .filter(|network| !network.deleted)
+            .ok_or_else(|| anyhow::anyhow!("Network with chain_id {} not found", network_id))?;
+        Self::fetch_pool_on_network(&network, address).await
+    }
+
+    /// Identify a pool on-chain and fetch its full state at the latest block, on a network
+    /// that may not be stored yet
+    async fn fetch_pool_on_network(
+        network: &NetworkResponse,
+        address: &str,
+    ) -> anyhow::Result<(Box<dyn PoolInterface>, u64)> {
+        let network_id = network.chain_id;
+        let pool_address = Self::validate_address(address)?;
+
+        let provider =
+            Arc::new(try_create_provider(&network.rpcs).map_err(|e| {
+                anyhow::anyhow!("RPC request failed on network {}: {}", network_id, e)
+            })?);
+
+        // Pin the block so the returned state is consistent
+        let block_number = provider
+            .get_block_number()
+            .await
+            .map_err(|e| anyhow::anyhow!("RPC request failed on network {}: {}", network_id, e))?;
+        let pool = Self::fetch_pool_at_block(provider, network, pool_address, block_number)
+            .await
+            .map_err(|e| {
+                if is_rpc_failure(&e) {
+                    anyhow::anyhow!("RPC request failed on network {}: {}", network_id, e)
+                } else {
+                    anyhow::anyhow!(
                         "Failed to identify and fetch pool: {} for pool address: {}",
                         e,
                         address
-                    ));
+                    )
                 }
-            }
-            None => {
-                return Err(anyhow::anyhow!(
-                    "Network with chain_id {} not found",
-                    network_id
-                ));
-            }
-        }
+            })?;
 
-        Ok(())
+        Ok((pool, block_number))
     }
-    /// Get all pools
-    ///
-    /// # Arguments
-    /// * `db` - Database reference
-    ///
-    /// # Returns
-    /// * `Ok(Vec<PoolResponse>)` - List of pools
-    /// * `Err(anyhow::Error)` - Error if database operation fails
-    pub async fn get_all_pools(db: &Database) -> anyhow::Result<Vec<PoolResponse>> {
-        debug!("Fetching all pools");
 
-        let collection = db.collection::<Pool>("pools");
-        // Filter out soft-deleted records
-        let filter = doc! {
-            "$or": [
-                { "deleted_at": null },
-                { "deleted_at": { "$exists": false } }
-            ]
-        };
-        let mut cursor = collection.find(filter).await?;
-        let mut pools = Vec::new();
+    /// Multicall contract of a network, Multicall3 unless the network overrides it
+    pub fn multicall_address(network: &NetworkResponse) -> Address {
+        network
+            .multicall_address
+            .as_ref()
+            .and_then(|s| Address::from_str(s).ok())
+            .unwrap_or(MULTICALL3_ADDRESS)
+    }
 
-        while let Some(pool) = cursor.try_next().await? {
-            pools.push(Self::map_to_response(pool));
-        }
+    /// Identify a pool on-chain and fetch its full state at a given block.
+    ///
+    /// Uses the network's `multicall_address` (Multicall3 by default) and factory settings.
+    pub async fn fetch_pool_at_block(
+        provider: Arc<DynProvider>,
+        network: &NetworkResponse,
+        pool_address: Address,
+        block_number: u64,
+    ) -> anyhow::Result<Box<dyn PoolInterface>> {
+        identify_and_fetch_pool(
+            provider,
+            pool_address,
+            BlockId::Number(BlockNumberOrTag::Number(block_number)),
+            Self::multicall_address(network),
+            &network.v2_factory_to_fee.clone().unwrap_or_default(),
+            &network
+                .aero_factory_addresses
+                .iter()
+                .flatten()
+                .filter_map(|address| address.parse::<Address>().ok())
+                .collect::<Vec<Address>>(),
+        )
+        .await
+    }
 
-        debug!("Retrieved {} pools from database", pools.len());
-        Ok(pools)
+    /// Verify a pool on-chain by identifying its type and fetching its data.
+    ///
+    /// This ensures the pool is a valid Uniswap V2/V3 pool before we persist it.
+    /// Returns the two tokens of the pool.
+    async fn verify_pool_on_chain(
+        db: &Database,
+        network_id: u64,
+        address: &str,
+    ) -> anyhow::Result<Vec<Address>> {
+        let (pool, _) = Self::fetch_pool_on_chain(db, network_id, address).await?;
+        Ok(vec![pool.token0(), pool.token1()])
     }
 
-    /// Get pools by network ID
+    /// Verify a pool on-chain on a network that may not be stored yet, e.g. one of an import
+    /// Returns the two tokens of the pool.
+    pub(crate) async fn verify_pool_on_network(
+        network: &NetworkResponse,
+        address: &str,
+    ) -> anyhow::Result<Vec<Address>> {
+        let (pool, _) = Self::fetch_pool_on_network(network, address).await?;
+        Ok(vec![pool.token0(), pool.token1()])
+    }
+
+    /// Get a page of pools
     ///
     /// # Arguments
-    /// * `db` - Database reference
-    /// * `network_id` - The network ID to filter by
+    /// * `db_service` - MongoDB service
+    /// * `network_id` - Optional network ID to filter by
+    /// * `query` - Pagination, sorting and filters
     ///
     /// # Returns
-    /// * `Ok(Vec<PoolResponse>)` - List of pools
-    /// * `Err(anyhow::Error)` - Error if database operation fails
-    pub async fn get_pools_by_network_id(
-        db: &Database,
-        network_id: u64,
-    ) -> anyhow::Result<Vec<PoolResponse>> {
-        debug!("Fetching pools with network_id: {}", network_id);
-
-        let collection = db.collection::<Pool>("pools");
-        // Filter out soft-deleted records
-        let filter = doc! {
-            "network_id": network_id as i64,
-            "$or": [
-                { "deleted_at": null },
-                { "deleted_at": { "$exists": false } }
-            ]
-        };
-        let mut cursor = collection.find(filter).await?;
-        let mut pools = Vec::new();
+    /// * `Ok(Page<PoolResponse>)` - Page of pools with the total count
+    /// * `Err(anyhow::Error)` - Error if the query is invalid or the database operation fails
+    pub async fn get_pools(
+        db_service: &MongoDbService,
+        network_id: Option<u64>,
+        query: &ListQuery,
+    ) -> anyhow::Result<Page<PoolResponse>> {
+        debug!(
+            "Fetching pools with network_id: {:?}, query: {:?}",
+            network_id, query
+        );
 
-        while let Some(pool) = cursor.try_next().await? {
-            pools.push(Self::map_to_response(pool));
-        }
+        let pools = db_service
+            .get_pool_repo()
+            .find_page(network_id, query)
+            .await?
+            .map(Self::map_to_response);
 
-        debug!("Retrieved {} pools from database", pools.len());
+        debug!(
+            "Retrieved {} of {} pools from database",
+            pools.items.len(),
+            pools.total
+        );
         Ok(pools)
     }
 
@@ -210,6 +247,27 @@ impl PoolService {
         Ok(count)
     }
 
+    /// Record a change of a pool in the audit log
+    async fn audit(
+        db: &Database,
+        actor: &str,
+        action: AuditAction,
+        id: &ObjectId,
+        before: Option<&Pool>,
+        after: Option<&Pool>,
+    ) {
+        AuditService::record(
+            db,
+            actor,
+            action,
+            AuditEntity::Pool,
+            &id.to_hex(),
+            before,
+            after,
+        )
+        .await;
+    }
+
     /// Create a pool if it doesn't exist
     ///
     /// # Arguments
@@ -251,37 +309,42 @@ impl PoolService {
 
     /// Create a new pool
     ///
+    /// The metadata of the pool's tokens that are not stored yet is fetched in the background.
+    ///
     /// # Arguments
-    /// * `db` - Database reference
+    /// * `db_service` - MongoDB service
+    /// * `actor` - Name of the API key making the change
     /// * `request` - CreatePoolRequest containing pool data
     ///
     /// # Returns
     /// * `Ok(PoolResponse)` - Created pool
     /// * `Err(anyhow::Error)` - Error if database operation fails
     pub async fn create_pool(
-        db: &Database,
+        db_service: &MongoDbService,
+        actor: &str,
         request: CreatePoolRequest,
     ) -> anyhow::Result<PoolResponse> {
+        let db = &db_service.get_client().database();
         debug!(
             "Creating new pool with network_id: {}, address: {}",
             request.network_id, request.address
         );
 
-        // Validate address format
-        Self::validate_address(&request.address)?;
+        // Validate address format, stored in the same form as the indexer's pools
+        let address = address_to_string(&Self::validate_address(&request.address)?);
 
         // Always verify the pool on-chain before creating/restoring
-        Self::verify_pool_on_chain(db, request.network_id, &request.address).await?;
+        let tokens = Self::verify_pool_on_chain(db, request.network_id, &address).await?;
 
         let collection = db.collection::<Pool>("pools");
         // Check if pool exists (including soft-deleted)
         let filter = doc! {
             "network_id": request.network_id as i64,
-            "address": &request.address
+            "address": &address
         };
         let existing = collection.find_one(filter.clone()).await?;
 
-        if let Some(_existing_pool) = existing {
+        if let Some(existing_pool) = existing {
             // Pool exists, restore it and update
             debug!("Pool exists, restoring and updating");
             let update = doc! {
@@ -292,23 +355,45 @@ impl PoolService {
             };
             collection.update_one(filter.clone(), update).await?;
             let restored_pool = collection.find_one(filter).await?.unwrap();
+            if let Some(id) = &restored_pool.id {
+                Self::audit(
+                    db,
+                    actor,
+                    AuditAction::Create,
+                    id,
+                    Some(&existing_pool),
+                    Some(&restored_pool),
+                )
+                .await;
+            }
+            TokenService::spawn_enrich_tokens(db_service.clone(), request.network_id, tokens);
             return Ok(Self::map_to_response(restored_pool));
         }
 
         // Create new pool
-        let pool = Pool::new(request.network_id, request.address);
+        let pool = Pool::new(request.network_id, address);
         let result = collection.insert_one(&pool).await?;
         let id = result.inserted_id.as_object_id().unwrap();
 
         let filter = doc! { "_id": id };
         let created_pool = collection.find_one(filter).await?.unwrap();
+        Self::audit(
+            db,
+            actor,
+            AuditAction::Create,
+            &id,
+            None,
+            Some(&created_pool),
+        )
+        .await;
 
         debug!("Pool created successfully with id: {}", id);
+        TokenService::spawn_enrich_tokens(db_service.clone(), request.network_id, tokens);
         Ok(Self::map_to_response(created_pool))
     }
 
     /// Soft delete a pool by ID (set deleted_at instead of removing)
-    pub async fn delete_pool(db: &Database, id: &ObjectId) -> anyhow::Result<()> {
+    pub async fn delete_pool(db: &Database, actor: &str, id: &ObjectId) -> anyhow::Result<()> {
         debug!("Soft deleting pool with id: {}", id);
 
         let collection = db.collection::<Pool>("pools");
@@ -320,13 +405,10 @@ impl PoolService {
             ]
         };
 
-        let existing = collection.find_one(filter.clone()).await?;
-        if existing.is_none() {
-            return Err(anyhow::anyhow!(
-                "Pool with id {} not found or already deleted",
-                id
-            ));
-        }
+        let existing = collection
+            .find_one(filter.clone())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Pool with id {} not found or already deleted", id))?;
 
         let update = doc! {
             "$set": {
@@ -337,6 +419,17 @@ impl PoolService {
 
         collection.update_one(filter, update).await?;
 
+        let deleted = collection.find_one(doc! { "_id": id }).await?;
+        Self::audit(
+            db,
+            actor,
+            AuditAction::Delete,
+            id,
+            Some(&existing),
+            deleted.as_ref(),
+        )
+        .await;
+
         debug!("Pool soft deleted successfully: {}", id);
         Ok(())
     }
@@ -346,12 +439,13 @@ impl PoolService {
     ///
     /// # Arguments
     /// * `db` - Database reference
+    /// * `actor` - Name of the API key making the change
     /// * `id` - The ObjectId of the pool to hard delete
     ///
     /// # Returns
     /// * `Ok(())` - Successfully deleted
     /// * `Err(anyhow::Error)` - Error if pool not found or not soft-deleted
-    pub async fn hard_delete_pool(db: &Database, id: &ObjectId) -> anyhow::Result<()> {
+    pub async fn hard_delete_pool(db: &Database, actor: &str, id: &ObjectId) -> anyhow::Result<()> {
         debug!("Hard deleting pool with id: {}", id);
 
         let collection = db.collection::<Pool>("pools");
@@ -362,16 +456,22 @@ impl PoolService {
         };
 
         // Check if pool exists and is soft-deleted
-        let existing = collection.find_one(filter.clone()).await?;
-        if existing.is_none() {
-            return Err(anyhow::anyhow!(
-                "Pool with id {} not found or not soft-deleted",
-                id
-            ));
-        }
+        let existing = collection
+            .find_one(filter.clone())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Pool with id {} not found or not soft-deleted", id))?;
 
         // Hard delete: actually remove from database
         collection.delete_one(filter).await?;
+        Self::audit(
+            db,
+            actor,
+            AuditAction::HardDelete,
+            id,
+            Some(&existing),
+            None,
+        )
+        .await;
 
         debug!("Pool hard deleted successfully: {}", id);
         Ok(())
@@ -381,6 +481,7 @@ impl PoolService {
     ///
     /// # Arguments
     /// * `db` - Database reference
+    /// * `actor` - Name of the API key making the change
     /// * `id` - The ObjectId of the pool to update
     /// * `request` - UpdatePoolRequest containing fields to update
     ///
@@ -389,6 +490,7 @@ impl PoolService {
     /// * `Err(anyhow::Error)` - Error if database operation fails or pool not found
     pub async fn update_pool(
         db: &Database,
+        actor: &str,
         id: &ObjectId,
         request: UpdatePoolRequest,
     ) -> anyhow::Result<PoolResponse> {
@@ -398,10 +500,10 @@ impl PoolService {
         let filter = doc! { "_id": id };
 
         // Check if pool exists
-        let existing = collection.find_one(filter.clone()).await?;
-        if existing.is_none() {
-            return Err(anyhow::anyhow!("Pool with id {} not found", id));
-        }
+        let existing = collection
+            .find_one(filter.clone())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Pool with id {} not found", id))?;
 
         // Build update document
         let mut update_doc = mongodb::bson::Document::new();
@@ -412,8 +514,8 @@ impl PoolService {
         }
         if let Some(address) = request.address {
             // Validate address format
-            Self::validate_address(&address)?;
-            update_doc.insert("address", address);
+            let address = Self::validate_address(&address)?;
+            update_doc.insert("address", address_to_string(&address));
         }
 
         let update = doc! { "$set": update_doc };
@@ -421,11 +523,96 @@ impl PoolService {
 
         // Get updated pool
         let pool = collection.find_one(filter).await?.unwrap();
+        Self::audit(
+            db,
+            actor,
+            AuditAction::Update,
+            id,
+            Some(&existing),
+            Some(&pool),
+        )
+        .await;
 
         debug!("Pool updated successfully: {}", id);
         Ok(Self::map_to_response(pool))
     }
 
+    /// Get the live on-chain state of a pool
+    ///
+    /// # Arguments
+    /// * `db` - Database reference
+    /// * `network_id` - The network ID
+    /// * `address` - The pool address
+    ///
+    /// # Returns
+    /// * `Ok(PoolStateResponse)` - Pool state read from the chain
+    /// * `Err(anyhow::Error)` - Error if the network is not found or the pool cannot be fetched
+    pub async fn get_pool_state(
+        db: &Database,
+        network_id: u64,
+        address: &Address,
+    ) -> anyhow::Result<PoolStateResponse> {
+        let addr_str = address_to_string(address);
+        debug!(
+            "Fetching on-chain state of pool {} on network {}",
+            addr_str, network_id
+        );
+
+        let (pool, block_number) = Self::fetch_pool_on_chain(db, network_id, &addr_str).await?;
+        Self::map_to_state_response(network_id, pool.as_ref(), block_number)
+    }
+
+    /// Map an on-chain pool to PoolStateResponse DTO
+    ///
+    /// # Arguments
+    /// * `network_id` - The network ID
+    /// * `pool` - Pool fetched by the bot's pool fetcher
+    /// * `block_number` - Block the state was read at
+    ///
+    /// # Returns
+    /// PoolStateResponse DTO
+    fn map_to_state_response(
+        network_id: u64,
+        pool: &dyn PoolInterface,
+        block_number: u64,
+    ) -> anyhow::Result<PoolStateResponse> {
+        let mut response = PoolStateResponse {
+            network_id,
+            address: address_to_string(&pool.address()),
+            pool_type: String::new(),
+            token0: address_to_string(&pool.token0()),
+            token1: address_to_string(&pool.token1()),
+            fee: pool.fee(),
+            fee_raw: pool.fee_raw(),
+            reserve0: None,
+            reserve1: None,
+            sqrt_price_x96: None,
+            tick: None,
+            tick_spacing: None,
+            liquidity: None,
+            block_number,
+        };
+
+        if let Some(v2_pool) = pool.as_any().downcast_ref::<UniswapV2Pool>() {
+            response.pool_type = format!("{:?}", v2_pool.pool_type);
+            response.reserve0 = Some(v2_pool.reserve0.to_string());
+            response.reserve1 = Some(v2_pool.reserve1.to_string());
+        } else if let Some(v3_pool) = pool.as_any().downcast_ref::<UniswapV3Pool>() {
+            response.pool_type = format!("{:?}", v3_pool.pool_type);
+            response.sqrt_price_x96 = Some(v3_pool.sqrt_price_x96.to_string());
+            response.tick = Some(v3_pool.tick);
+            response.tick_spacing = Some(v3_pool.tick_spacing);
+            response.liquidity = Some(v3_pool.liquidity.to_string());
+        } else {
+            return Err(anyhow::anyhow!(
+                "Unsupported pool type for pool address: {}",
+                response.address
+            ));
+        }
+
+        Ok(response)
+    }
+
     /// Map Pool model to PoolResponse DTO
     ///
     /// # Arguments
@@ -433,7 +620,7 @@ impl PoolService {
     ///
     /// # Returns
     /// PoolResponse DTO
-    fn map_to_response(pool: Pool) -> PoolResponse {
+    pub(crate) fn map_to_response(pool: Pool) -> PoolResponse {
         let id = pool
             .id
             .map(|oid| oid.to_hex())
@@ -0,0 +1,13 @@
+use serde::Serialize;
+
+use crate::bot::models::profit_token::price_updater::PriceSourceType;
+
+/// Response model for price API endpoints
+#[derive(Debug, Serialize)]
+pub struct TokenPriceResponse {
+    pub network_id: u64,
+    pub address: String,
+    pub price_usd: f64,
+    pub source: Option<PriceSourceType>, // None when the profit token's default price is used
+    pub updated_at: u64, // when the price was fetched, or the default price last changed
+}
@@ -0,0 +1,6 @@
+pub mod dto;
+pub mod price;
+pub mod service;
+
+pub use dto::*;
+pub use price::*;
@@ -0,0 +1,89 @@
+use crate::{database::MongoDbService, errors::ApiError, handlers::price::service::PriceService};
+use actix_web::{web, HttpResponse};
+use alloy::primitives::Address;
+use log::{error, info};
+
+/// GET /prices/network/{network_id} - Returns the USD prices of the profit tokens of a network
+///
+/// # Arguments
+/// * `db_service` - MongoDB service
+/// * `path` - Path parameters containing network_id
+///
+/// # Returns
+/// JSON array of TokenPriceResponse
+pub async fn get_prices_handler(
+    db_service: web::Data<MongoDbService>,
+    path: web::Path<u64>,
+) -> Result<HttpResponse, ApiError> {
+    let network_id = path.into_inner();
+    info!("Handling GET /prices/network/{} request", network_id);
+
+    match PriceService::get_prices(&db_service, network_id).await {
+        Ok(prices) => {
+            info!("Successfully retrieved {} token prices", prices.len());
+            Ok(HttpResponse::Ok().json(prices))
+        }
+        Err(e) => {
+            error!("Failed to retrieve token prices: {}", e);
+            if e.to_string().contains("not found") {
+                Err(ApiError::NotFound(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to retrieve token prices: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
+
+/// GET /prices/network/{network_id}/token/{address} - Returns the USD price of a token
+///
+/// # Arguments
+/// * `db_service` - MongoDB service
+/// * `path` - Path parameters containing network_id and address
+///
+/// # Returns
+/// JSON object of TokenPriceResponse
+pub async fn get_token_price_handler(
+    db_service: web::Data<MongoDbService>,
+    path: web::Path<(u64, String)>,
+) -> Result<HttpResponse, ApiError> {
+    let (network_id, address_str) = path.into_inner();
+    info!(
+        "Handling GET /prices/network/{}/token/{} request",
+        network_id, address_str
+    );
+
+    let address = match address_str.parse::<Address>() {
+        Ok(addr) => addr,
+        Err(e) => {
+            error!("Invalid address format: {}", e);
+            return Err(ApiError::BadRequest(format!(
+                "Invalid address format: {}",
+                e
+            )));
+        }
+    };
+
+    match PriceService::get_price(&db_service, network_id, &address).await {
+        Ok(Some(price)) => {
+            info!("Successfully retrieved token price");
+            Ok(HttpResponse::Ok().json(price))
+        }
+        Ok(None) => {
+            info!("Token price not found");
+            Err(ApiError::NotFound(format!(
+                "Price of token with network_id {} and address {} not found",
+                network_id, address_str
+            )))
+        }
+        Err(e) => {
+            error!("Failed to retrieve token price: {}", e);
+            Err(ApiError::DatabaseError(format!(
+                "Failed to retrieve token price: {}",
+                e
+            )))
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use alloy::primitives::Address;
+use log::debug;
+use mongodb::bson::doc;
+use mongodb::Database;
+use std::collections::HashMap;
+
+use crate::{
+    database::models::{address_to_string, PriceSnapshot, ProfitTokenConfig},
+    database::MongoDbService,
+    handlers::price::dto::TokenPriceResponse,
+};
+
+/// Service layer for token price business logic
+pub struct PriceService;
+
+impl PriceService {
+    /// Get the prices of the profit tokens of a network
+    ///
+    /// # Arguments
+    /// * `db_service` - MongoDB service
+    /// * `network_id` - The network ID
+    ///
+    /// # Returns
+    /// * `Ok(Vec<TokenPriceResponse>)` - Prices of the profit tokens, by address
+    /// * `Err(anyhow::Error)` - Error if the network is not found or the database operation fails
+    pub async fn get_prices(
+        db_service: &MongoDbService,
+        network_id: u64,
+    ) -> anyhow::Result<Vec<TokenPriceResponse>> {
+        debug!("Getting token prices of network {}", network_id);
+        db_service
+            .find_network(network_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Network with chain_id {} not found", network_id))?;
+
+        let configs = db_service
+            .get_profit_token_repo()
+            .find_by_network_id(network_id)
+            .await?;
+        let mut snapshots: HashMap<String, PriceSnapshot> = db_service
+            .get_price_repo()
+            .find_by_network_id(network_id)
+            .await?
+            .into_iter()
+            .map(|snapshot| (snapshot.address.clone(), snapshot))
+            .collect();
+
+        Ok(configs
+            .iter()
+            .filter_map(|config| {
+                let snapshot = snapshots.remove(&config.address);
+                Self::resolve_price(network_id, Some(config), snapshot)
+            })
+            .collect())
+    }
+
+    /// Get the price of a token
+    ///
+    /// # Returns
+    /// * `Ok(Some(TokenPriceResponse))` - Price of the token
+    /// * `Ok(None)` - The token is not a profit token and was never priced
+    /// * `Err(anyhow::Error)` - Error if the database operation fails
+    pub async fn get_price(
+        db_service: &MongoDbService,
+        network_id: u64,
+        address: &Address,
+    ) -> anyhow::Result<Option<TokenPriceResponse>> {
+        let config = db_service
+            .get_profit_token_repo()
+            .find_by_address(network_id, address)
+            .await?;
+        let snapshot = db_service
+            .get_price_repo()
+            .find_by_address(network_id, address)
+            .await?;
+
+        Ok(Self::resolve_price(network_id, config.as_ref(), snapshot))
+    }
+
+    /// Last price snapshot of a token
+    pub(crate) async fn find_snapshot(
+        db: &Database,
+        network_id: u64,
+        address: &Address,
+    ) -> anyhow::Result<Option<PriceSnapshot>> {
+        let collection = db.collection::<PriceSnapshot>("token_prices");
+        let filter = doc! {
+            "network_id": network_id as i64,
+            "address": address_to_string(address)
+        };
+        Ok(collection.find_one(filter).await?)
+    }
+
+    /// Price of a token from its last snapshot, or from the default price of its profit token
+    /// configuration when it has no price source or was never priced
+    pub(crate) fn resolve_price(
+        network_id: u64,
+        config: Option<&ProfitTokenConfig>,
+        snapshot: Option<PriceSnapshot>,
+    ) -> Option<TokenPriceResponse> {
+        match (config, snapshot) {
+            (Some(config), Some(snapshot)) if config.price_source.is_some() => {
+                Some(Self::map_snapshot(snapshot))
+            }
+            (Some(config), _) => Some(TokenPriceResponse {
+                network_id,
+                address: config.address.clone(),
+                price_usd: config.default_price,
+                source: None,
+                updated_at: config.updated_at,
+            }),
+            (None, snapshot) => snapshot.map(Self::map_snapshot),
+        }
+    }
+
+    /// Map PriceSnapshot model to TokenPriceResponse DTO
+    fn map_snapshot(snapshot: PriceSnapshot) -> TokenPriceResponse {
+        TokenPriceResponse {
+            network_id: snapshot.network_id,
+            address: snapshot.address,
+            price_usd: snapshot.price_usd,
+            source: Some(snapshot.source),
+            updated_at: snapshot.fetched_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::models::profit_token::price_updater::PriceSourceType;
+
+    const DAI: &str = "0x6b175474e89094c44da98b954eedeac495271d0f";
+
+    fn config(price_source: Option<PriceSourceType>) -> ProfitTokenConfig {
+        ProfitTokenConfig::new(
+            1,
+            DAI.to_string(),
+            "1000000000000000000".to_string(),
+            price_source,
+            1.0,
+        )
+    }
+
+    fn snapshot() -> PriceSnapshot {
+        PriceSnapshot::new(1, DAI.to_string(), 0.998, PriceSourceType::GeckoTerminal)
+    }
+
+    #[test]
+    fn test_resolve_price_snapshot() {
+        let config = config(Some(PriceSourceType::GeckoTerminal));
+        let price = PriceService::resolve_price(1, Some(&config), Some(snapshot())).unwrap();
+        assert_eq!(price.price_usd, 0.998);
+        assert_eq!(price.source, Some(PriceSourceType::GeckoTerminal));
+
+        // Priced tokens that are not profit tokens keep their snapshot
+        let price = PriceService::resolve_price(1, None, Some(snapshot())).unwrap();
+        assert_eq!(price.price_usd, 0.998);
+    }
+
+    #[test]
+    fn test_resolve_price_default() {
+        // Never priced yet
+        let config = config(Some(PriceSourceType::GeckoTerminal));
+        let price = PriceService::resolve_price(1, Some(&config), None).unwrap();
+        assert_eq!(price.price_usd, 1.0);
+        assert_eq!(price.source, None);
+
+        // No price source, a leftover snapshot is ignored
+        let config = ProfitTokenConfig {
+            price_source: None,
+            ..config
+        };
+        let price = PriceService::resolve_price(1, Some(&config), Some(snapshot())).unwrap();
+        assert_eq!(price.price_usd, 1.0);
+        assert_eq!(price.source, None);
+    }
+
+    #[test]
+    fn test_resolve_price_no_source() {
+        assert!(PriceService::resolve_price(1, None, None).is_none());
+    }
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::bot::models::profit_token::price_updater::PriceSourceType;
+
+/// Response model for profit token API endpoints
+#[derive(Debug, Serialize)]
+pub struct ProfitTokenResponse {
+    pub id: String, // MongoDB ObjectId as string
+    pub network_id: u64,
+    pub address: String,
+    pub min_profit: String, // in the token's smallest unit
+    pub price_source: Option<PriceSourceType>,
+    pub default_price: f64,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+/// Request model for adding a profit token to a network
+#[derive(Debug, Deserialize)]
+pub struct CreateProfitTokenRequest {
+    pub network_id: u64,
+    pub address: String,
+    pub min_profit: String,                    // in the token's smallest unit
+    pub price_source: Option<PriceSourceType>, // left out to always use `default_price`
+    pub default_price: f64,
+}
+
+/// Request model for updating a profit token, fields left out are unchanged
+#[derive(Debug, Deserialize)]
+pub struct UpdateProfitTokenRequest {
+    pub min_profit: Option<String>,
+    /// `null` removes the price source so `default_price` is always used
+    #[serde(default, deserialize_with = "deserialize_present")]
+    pub price_source: Option<Option<PriceSourceType>>,
+    pub default_price: Option<f64>,
+}
+
+/// Tell a field set to `null` (`Some(None)`) from a field left out (`None`)
+fn deserialize_present<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
@@ -0,0 +1,6 @@
+pub mod dto;
+pub mod profit_token;
+pub mod service;
+
+pub use dto::*;
+pub use profit_token::*;
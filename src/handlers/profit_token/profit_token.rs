@@ -0,0 +1,242 @@
+use crate::{
+    database::MongoDbService,
+    errors::ApiError,
+    handlers::{
+        config::auth::ApiKey,
+        profit_token::{
+            dto::{CreateProfitTokenRequest, UpdateProfitTokenRequest},
+            service::ProfitTokenService,
+        },
+    },
+};
+use actix_web::{web, HttpResponse};
+use alloy::primitives::Address;
+use log::{error, info};
+use mongodb::Database;
+
+/// Parse the address path parameter of a profit token
+fn parse_address(address_str: &str) -> Result<Address, ApiError> {
+    address_str.parse::<Address>().map_err(|e| {
+        error!("Invalid address format: {}", e);
+        ApiError::BadRequest(format!("Invalid address format: {}", e))
+    })
+}
+
+/// GET /profit-tokens/network/{network_id} - Returns the profit tokens of a network
+///
+/// # Arguments
+/// * `db_service` - MongoDB service
+/// * `path` - Path parameters containing network_id
+///
+/// # Returns
+/// JSON array of ProfitTokenResponse
+pub async fn get_profit_tokens_handler(
+    db_service: web::Data<MongoDbService>,
+    path: web::Path<u64>,
+) -> Result<HttpResponse, ApiError> {
+    let network_id = path.into_inner();
+    info!("Handling GET /profit-tokens/network/{} request", network_id);
+
+    match ProfitTokenService::get_profit_tokens(&db_service, network_id).await {
+        Ok(profit_tokens) => {
+            info!(
+                "Successfully retrieved {} profit tokens",
+                profit_tokens.len()
+            );
+            Ok(HttpResponse::Ok().json(profit_tokens))
+        }
+        Err(e) => {
+            error!("Failed to retrieve profit tokens: {}", e);
+            Err(ApiError::DatabaseError(format!(
+                "Failed to retrieve profit tokens: {}",
+                e
+            )))
+        }
+    }
+}
+
+/// GET /profit-tokens/network/{network_id}/address/{address} - Returns a specific profit token
+///
+/// # Arguments
+/// * `db` - Database connection
+/// * `path` - Path parameters containing network_id and address
+///
+/// # Returns
+/// JSON object of ProfitTokenResponse
+pub async fn get_profit_token_handler(
+    db: web::Data<Database>,
+    path: web::Path<(u64, String)>,
+) -> Result<HttpResponse, ApiError> {
+    let (network_id, address_str) = path.into_inner();
+    info!(
+        "Handling GET /profit-tokens/network/{}/address/{} request",
+        network_id, address_str
+    );
+    let address = parse_address(&address_str)?;
+
+    match ProfitTokenService::get_profit_token(&db, network_id, &address).await {
+        Ok(Some(profit_token)) => {
+            info!("Successfully retrieved profit token");
+            Ok(HttpResponse::Ok().json(profit_token))
+        }
+        Ok(None) => {
+            info!("Profit token not found");
+            Err(ApiError::NotFound(format!(
+                "Profit token with network_id {} and address {} not found",
+                network_id, address_str
+            )))
+        }
+        Err(e) => {
+            error!("Failed to retrieve profit token: {}", e);
+            Err(ApiError::DatabaseError(format!(
+                "Failed to retrieve profit token: {}",
+                e
+            )))
+        }
+    }
+}
+
+/// POST /profit-tokens - Adds a profit token to a network
+/// Requires API key authentication via X-API-Key header
+///
+/// # Arguments
+/// * `api_key` - API key from X-API-Key header (validated by extractor), recorded in the audit log
+/// * `db` - Database connection
+/// * `body` - CreateProfitTokenRequest containing the profit token configuration
+///
+/// # Returns
+/// JSON object of ProfitTokenResponse containing the created profit token
+pub async fn create_profit_token_handler(
+    api_key: ApiKey,
+    db: web::Data<Database>,
+    body: web::Json<CreateProfitTokenRequest>,
+) -> Result<HttpResponse, ApiError> {
+    info!("Handling POST /profit-tokens request");
+
+    match ProfitTokenService::create_profit_token(&db, &api_key.name, body.into_inner()).await {
+        Ok(profit_token) => {
+            info!(
+                "Successfully created profit token {} on network {}",
+                profit_token.address, profit_token.network_id
+            );
+            Ok(HttpResponse::Created().json(profit_token))
+        }
+        Err(e) => {
+            error!("Failed to create profit token: {}", e);
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else if e.to_string().contains("not found") {
+                Err(ApiError::NotFound(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to create profit token: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
+
+/// PUT /profit-tokens/network/{network_id}/address/{address} - Updates a profit token
+/// Requires API key authentication via X-API-Key header
+///
+/// # Arguments
+/// * `api_key` - API key from X-API-Key header (validated by extractor), recorded in the audit log
+/// * `db` - Database connection
+/// * `path` - Path parameters containing network_id and address
+/// * `body` - UpdateProfitTokenRequest containing fields to update
+///
+/// # Returns
+/// JSON object of ProfitTokenResponse containing the updated profit token
+pub async fn update_profit_token_handler(
+    api_key: ApiKey,
+    db: web::Data<Database>,
+    path: web::Path<(u64, String)>,
+    body: web::Json<UpdateProfitTokenRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let (network_id, address_str) = path.into_inner();
+    info!(
+        "Handling PUT /profit-tokens/network/{}/address/{} request",
+        network_id, address_str
+    );
+    let address = parse_address(&address_str)?;
+
+    match ProfitTokenService::update_profit_token(
+        &db,
+        &api_key.name,
+        network_id,
+        &address,
+        body.into_inner(),
+    )
+    .await
+    {
+        Ok(profit_token) => {
+            info!(
+                "Successfully updated profit token with network_id {} and address {}",
+                network_id, address_str
+            );
+            Ok(HttpResponse::Ok().json(profit_token))
+        }
+        Err(e) => {
+            error!(
+                "Failed to update profit token with network_id {} and address {}: {}",
+                network_id, address_str, e
+            );
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else if e.to_string().contains("not found") {
+                Err(ApiError::NotFound(format!(
+                    "Profit token with network_id {} and address {} not found",
+                    network_id, address_str
+                )))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to update profit token: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
+
+/// DELETE /profit-tokens/network/{network_id}/address/{address} - Removes a profit token
+/// Requires API key authentication via X-API-Key header
+pub async fn delete_profit_token_handler(
+    api_key: ApiKey,
+    db: web::Data<Database>,
+    path: web::Path<(u64, String)>,
+) -> Result<HttpResponse, ApiError> {
+    let (network_id, address_str) = path.into_inner();
+    info!(
+        "Handling DELETE /profit-tokens/network/{}/address/{} request",
+        network_id, address_str
+    );
+    let address = parse_address(&address_str)?;
+
+    match ProfitTokenService::delete_profit_token(&db, &api_key.name, network_id, &address).await {
+        Ok(()) => {
+            info!(
+                "Successfully deleted profit token with network_id {} and address {}",
+                network_id, address_str
+            );
+            Ok(HttpResponse::NoContent().finish())
+        }
+        Err(e) => {
+            error!(
+                "Failed to delete profit token with network_id {} and address {}: {}",
+                network_id, address_str, e
+            );
+            if e.to_string().contains("not found") {
+                Err(ApiError::NotFound(format!(
+                    "Profit token with network_id {} and address {} not found",
+                    network_id, address_str
+                )))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to delete profit token: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
@@ -0,0 +1,349 @@
+use alloy::primitives::{Address, U256};
+use log::debug;
+use mongodb::bson::{self, doc};
+use mongodb::Database;
+use std::str::FromStr;
+
+use crate::{
+    database::models::utils::address_to_string,
+    database::models::{AuditAction, AuditEntity, ProfitTokenConfig},
+    database::mongodb::is_duplicate_key_error,
+    database::MongoDbService,
+    handlers::{
+        audit::service::AuditService,
+        network::service::NetworkService,
+        profit_token::dto::{
+            CreateProfitTokenRequest, ProfitTokenResponse, UpdateProfitTokenRequest,
+        },
+    },
+};
+
+/// Service layer for profit token business logic
+pub struct ProfitTokenService;
+
+impl ProfitTokenService {
+    /// Get the profit tokens of a network
+    ///
+    /// # Arguments
+    /// * `db_service` - MongoDB service
+    /// * `network_id` - The network ID
+    ///
+    /// # Returns
+    /// * `Ok(Vec<ProfitTokenResponse>)` - Profit tokens of the network, by address
+    /// * `Err(anyhow::Error)` - Error if the database operation fails
+    pub async fn get_profit_tokens(
+        db_service: &MongoDbService,
+        network_id: u64,
+    ) -> anyhow::Result<Vec<ProfitTokenResponse>> {
+        let configs = db_service
+            .get_profit_token_repo()
+            .find_by_network_id(network_id)
+            .await?;
+        Ok(configs.into_iter().map(Self::map_to_response).collect())
+    }
+
+    /// Get a profit token by network ID and address
+    ///
+    /// # Returns
+    /// * `Ok(Some(ProfitTokenResponse))` - Profit token if found
+    /// * `Ok(None)` - The token is not a profit token of the network
+    /// * `Err(anyhow::Error)` - Error if the database operation fails
+    pub async fn get_profit_token(
+        db: &Database,
+        network_id: u64,
+        address: &Address,
+    ) -> anyhow::Result<Option<ProfitTokenResponse>> {
+        Ok(Self::find_config(db, network_id, address)
+            .await?
+            .map(Self::map_to_response))
+    }
+
+    /// Find the stored configuration of a profit token
+    pub(crate) async fn find_config(
+        db: &Database,
+        network_id: u64,
+        address: &Address,
+    ) -> anyhow::Result<Option<ProfitTokenConfig>> {
+        let collection = db.collection::<ProfitTokenConfig>("profit_tokens");
+        Ok(collection
+            .find_one(Self::filter(network_id, &address_to_string(address)))
+            .await?)
+    }
+
+    /// Filter on a profit token by network ID and lowercase address
+    fn filter(network_id: u64, address: &str) -> bson::Document {
+        doc! {
+            "network_id": network_id as i64,
+            "address": address
+        }
+    }
+
+    /// Validate that an address string is a valid Ethereum address
+    fn validate_address(address: &str) -> anyhow::Result<Address> {
+        Address::from_str(address)
+            .map_err(|e| anyhow::anyhow!("Invalid address format '{}': {}", address, e))
+    }
+
+    /// Validate a minimum profit in the token's smallest unit, returned in decimal form
+    fn validate_min_profit(min_profit: &str) -> anyhow::Result<String> {
+        U256::from_str(min_profit.trim())
+            .map(|min_profit| min_profit.to_string())
+            .map_err(|e| anyhow::anyhow!("Invalid min_profit '{}': {}", min_profit, e))
+    }
+
+    /// Validate a fallback USD price
+    fn validate_default_price(default_price: f64) -> anyhow::Result<()> {
+        if !default_price.is_finite() || default_price <= 0.0 {
+            return Err(anyhow::anyhow!(
+                "Invalid default_price {}: must be a positive number",
+                default_price
+            ));
+        }
+        Ok(())
+    }
+
+    /// Add a profit token to a network
+    ///
+    /// # Arguments
+    /// * `db` - Database reference
+    /// * `actor` - Name of the API key making the change
+    /// * `request` - CreateProfitTokenRequest containing the profit token configuration
+    ///
+    /// # Returns
+    /// * `Ok(ProfitTokenResponse)` - Created profit token
+    /// * `Err(anyhow::Error)` - Error if the request is invalid, the token is already a profit
+    ///   token of the network, the network is not found or the database operation fails
+    pub async fn create_profit_token(
+        db: &Database,
+        actor: &str,
+        request: CreateProfitTokenRequest,
+    ) -> anyhow::Result<ProfitTokenResponse> {
+        debug!(
+            "Creating profit token with network_id: {}, address: {}",
+            request.network_id, request.address
+        );
+
+        let address = Self::validate_address(&request.address)?;
+        let min_profit = Self::validate_min_profit(&request.min_profit)?;
+        Self::validate_default_price(request.default_price)?;
+        match NetworkService::get_network_by_chain_id(db, request.network_id).await? {
+            Some(network) if !network.deleted => {}
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "Network with chain_id {} not found",
+                    request.network_id
+                ))
+            }
+        }
+
+        let addr_str = address_to_string(&address);
+        let config = ProfitTokenConfig::new(
+            request.network_id,
+            addr_str.clone(),
+            min_profit,
+            request.price_source,
+            request.default_price,
+        );
+
+        let collection = db.collection::<ProfitTokenConfig>("profit_tokens");
+        if let Err(e) = collection.insert_one(&config).await {
+            if is_duplicate_key_error(&e) {
+                return Err(anyhow::anyhow!(
+                    "Invalid profit token: {} is already a profit token of network {}",
+                    addr_str,
+                    request.network_id
+                ));
+            }
+            return Err(e.into());
+        }
+
+        let created = collection
+            .find_one(Self::filter(request.network_id, &addr_str))
+            .await?
+            .unwrap();
+        Self::audit(
+            db,
+            actor,
+            AuditAction::Create,
+            request.network_id,
+            &addr_str,
+            None,
+            Some(&created),
+        )
+        .await;
+
+        debug!(
+            "Profit token created successfully: network_id={}, address={}",
+            request.network_id, addr_str
+        );
+        Ok(Self::map_to_response(created))
+    }
+
+    /// Update the configuration of a profit token
+    ///
+    /// # Arguments
+    /// * `db` - Database reference
+    /// * `actor` - Name of the API key making the change
+    /// * `network_id` - The network ID
+    /// * `address` - The token address
+    /// * `request` - UpdateProfitTokenRequest containing fields to update
+    ///
+    /// # Returns
+    /// * `Ok(ProfitTokenResponse)` - Updated profit token
+    /// * `Err(anyhow::Error)` - Error if the request is invalid, the profit token is not found
+    ///   or the database operation fails
+    pub async fn update_profit_token(
+        db: &Database,
+        actor: &str,
+        network_id: u64,
+        address: &Address,
+        request: UpdateProfitTokenRequest,
+    ) -> anyhow::Result<ProfitTokenResponse> {
+        let addr_str = address_to_string(address);
+        debug!(
+            "Updating profit token with network_id: {}, address: {}",
+            network_id, addr_str
+        );
+
+        let mut update_doc = doc! { "updated_at": chrono::Utc::now().timestamp() };
+        if let Some(min_profit) = &request.min_profit {
+            update_doc.insert("min_profit", Self::validate_min_profit(min_profit)?);
+        }
+        if let Some(price_source) = &request.price_source {
+            update_doc.insert("price_source", bson::to_bson(price_source)?);
+        }
+        if let Some(default_price) = request.default_price {
+            Self::validate_default_price(default_price)?;
+            update_doc.insert("default_price", default_price);
+        }
+
+        let collection = db.collection::<ProfitTokenConfig>("profit_tokens");
+        let filter = Self::filter(network_id, &addr_str);
+        let existing = collection.find_one(filter.clone()).await?.ok_or_else(|| {
+            anyhow::anyhow!(
+                "Profit token with network_id {} and address {} not found",
+                network_id,
+                addr_str
+            )
+        })?;
+
+        collection
+            .update_one(filter.clone(), doc! { "$set": update_doc })
+            .await?;
+
+        let config = collection.find_one(filter).await?.unwrap();
+        Self::audit(
+            db,
+            actor,
+            AuditAction::Update,
+            network_id,
+            &addr_str,
+            Some(&existing),
+            Some(&config),
+        )
+        .await;
+
+        debug!(
+            "Profit token updated successfully: network_id={}, address={}",
+            network_id, addr_str
+        );
+        Ok(Self::map_to_response(config))
+    }
+
+    /// Remove a token from the profit tokens of a network
+    ///
+    /// The configuration is removed rather than soft deleted, the audit log keeps its last state.
+    ///
+    /// # Arguments
+    /// * `db` - Database reference
+    /// * `actor` - Name of the API key making the change
+    /// * `network_id` - The network ID
+    /// * `address` - The token address
+    ///
+    /// # Returns
+    /// * `Ok(())` - Successfully removed
+    /// * `Err(anyhow::Error)` - Error if the profit token is not found or the database
+    ///   operation fails
+    pub async fn delete_profit_token(
+        db: &Database,
+        actor: &str,
+        network_id: u64,
+        address: &Address,
+    ) -> anyhow::Result<()> {
+        let addr_str = address_to_string(address);
+        debug!(
+            "Deleting profit token with network_id: {}, address: {}",
+            network_id, addr_str
+        );
+
+        let collection = db.collection::<ProfitTokenConfig>("profit_tokens");
+        let existing = collection
+            .find_one_and_delete(Self::filter(network_id, &addr_str))
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Profit token with network_id {} and address {} not found",
+                    network_id,
+                    addr_str
+                )
+            })?;
+
+        Self::audit(
+            db,
+            actor,
+            AuditAction::Delete,
+            network_id,
+            &addr_str,
+            Some(&existing),
+            None,
+        )
+        .await;
+
+        debug!(
+            "Profit token deleted successfully: network_id={}, address={}",
+            network_id, addr_str
+        );
+        Ok(())
+    }
+
+    /// Record a change of a profit token in the audit log, identified as `network_id:address`
+    async fn audit(
+        db: &Database,
+        actor: &str,
+        action: AuditAction,
+        network_id: u64,
+        address: &str,
+        before: Option<&ProfitTokenConfig>,
+        after: Option<&ProfitTokenConfig>,
+    ) {
+        AuditService::record(
+            db,
+            actor,
+            action,
+            AuditEntity::ProfitToken,
+            &format!("{}:{}", network_id, address),
+            before,
+            after,
+        )
+        .await;
+    }
+
+    /// Map ProfitTokenConfig model to ProfitTokenResponse DTO
+    fn map_to_response(config: ProfitTokenConfig) -> ProfitTokenResponse {
+        let id = config
+            .id
+            .map(|oid| oid.to_hex())
+            .unwrap_or_else(|| "unknown".to_string());
+
+        ProfitTokenResponse {
+            id,
+            network_id: config.network_id,
+            address: config.address,
+            min_profit: config.min_profit,
+            price_source: config.price_source,
+            default_price: config.default_price,
+            created_at: config.created_at,
+            updated_at: config.updated_at,
+        }
+    }
+}
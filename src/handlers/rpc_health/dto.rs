@@ -0,0 +1,28 @@
+use serde::Serialize;
+
+use crate::database::models::RpcKind;
+
+/// Health of an RPC endpoint at its last check
+#[derive(Debug, Serialize)]
+pub struct RpcEndpointHealthResponse {
+    pub url: String,
+    pub kind: RpcKind,
+    pub healthy: bool,
+    pub head_block: Option<u64>,
+    pub lag: Option<u64>, // blocks behind the highest head of the network's endpoints
+    pub latency_ms: Option<u64>,
+    pub error: Option<String>,
+    pub error_rate: f64, // share of failed checks among the last `checks`
+    pub checks: usize,
+    pub last_ok_at: Option<u64>,
+    pub checked_at: Option<u64>, // None until the endpoint is checked for the first time
+}
+
+/// Response model for GET /networks/{chain_id}/rpc-health
+#[derive(Debug, Serialize)]
+pub struct NetworkRpcHealthResponse {
+    pub network_id: u64,
+    pub head_block: Option<u64>, // highest head of the healthy endpoints
+    pub healthy: usize,          // number of healthy endpoints
+    pub endpoints: Vec<RpcEndpointHealthResponse>,
+}
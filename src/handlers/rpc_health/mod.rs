@@ -0,0 +1,6 @@
+pub mod dto;
+pub mod rpc_health;
+pub mod service;
+
+pub use dto::*;
+pub use rpc_health::*;
@@ -0,0 +1,44 @@
+use crate::{
+    database::MongoDbService, errors::ApiError, handlers::rpc_health::service::RpcHealthService,
+};
+use actix_web::{web, HttpResponse};
+use log::{error, info};
+
+/// GET /networks/{chain_id}/rpc-health - Returns the health of the RPC and WebSocket endpoints
+/// of a network at their last check
+///
+/// # Arguments
+/// * `db_service` - MongoDB service
+/// * `path` - Path parameter containing chain_id
+///
+/// # Returns
+/// JSON object of NetworkRpcHealthResponse
+pub async fn get_rpc_health_handler(
+    db_service: web::Data<MongoDbService>,
+    path: web::Path<u64>,
+) -> Result<HttpResponse, ApiError> {
+    let chain_id = path.into_inner();
+    info!("Handling GET /networks/{}/rpc-health request", chain_id);
+
+    match RpcHealthService::get_rpc_health(&db_service, chain_id).await {
+        Ok(health) => {
+            info!(
+                "Successfully retrieved RPC health: {} of {} endpoints healthy",
+                health.healthy,
+                health.endpoints.len()
+            );
+            Ok(HttpResponse::Ok().json(health))
+        }
+        Err(e) => {
+            error!("Failed to retrieve RPC health: {}", e);
+            if e.to_string().contains("not found") {
+                Err(ApiError::NotFound(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to retrieve RPC health: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
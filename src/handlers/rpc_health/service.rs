@@ -0,0 +1,99 @@
+use log::debug;
+use std::collections::HashMap;
+
+use crate::{
+    database::models::{RpcHealth, RpcKind},
+    database::MongoDbService,
+    handlers::rpc_health::dto::{NetworkRpcHealthResponse, RpcEndpointHealthResponse},
+};
+
+/// Service layer for RPC endpoint health business logic
+pub struct RpcHealthService;
+
+impl RpcHealthService {
+    /// Get the health of the RPC and WebSocket endpoints of a network
+    ///
+    /// Endpoints are listed in the order of the network's configuration, RPCs first. An endpoint
+    /// added since the last check is listed as unhealthy, without a `checked_at`.
+    ///
+    /// # Arguments
+    /// * `db_service` - MongoDB service
+    /// * `chain_id` - The chain_id of the network
+    ///
+    /// # Returns
+    /// * `Ok(NetworkRpcHealthResponse)` - Health of the endpoints of the network
+    /// * `Err(anyhow::Error)` - Error if the network is not found or the database operation fails
+    pub async fn get_rpc_health(
+        db_service: &MongoDbService,
+        chain_id: u64,
+    ) -> anyhow::Result<NetworkRpcHealthResponse> {
+        debug!("Getting RPC health of network {}", chain_id);
+        let network = db_service
+            .find_network(chain_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Network with chain_id {} not found", chain_id))?;
+
+        let mut healths: HashMap<String, RpcHealth> = db_service
+            .get_rpc_health_repo()
+            .find_by_network_id(chain_id)
+            .await?
+            .into_iter()
+            .map(|health| (health.url.clone(), health))
+            .collect();
+
+        let endpoints: Vec<RpcEndpointHealthResponse> = network
+            .rpc_endpoints()
+            .into_iter()
+            .map(|(url, kind)| {
+                let health = healths.remove(&url);
+                Self::map_to_response(url, kind, health)
+            })
+            .collect();
+
+        Ok(NetworkRpcHealthResponse {
+            network_id: chain_id,
+            head_block: endpoints
+                .iter()
+                .filter(|endpoint| endpoint.healthy)
+                .filter_map(|endpoint| endpoint.head_block)
+                .max(),
+            healthy: endpoints.iter().filter(|endpoint| endpoint.healthy).count(),
+            endpoints,
+        })
+    }
+
+    fn map_to_response(
+        url: String,
+        kind: RpcKind,
+        health: Option<RpcHealth>,
+    ) -> RpcEndpointHealthResponse {
+        match health {
+            Some(health) => RpcEndpointHealthResponse {
+                url,
+                kind,
+                healthy: health.healthy,
+                head_block: health.head_block,
+                lag: health.lag,
+                latency_ms: health.latency_ms,
+                error: health.error,
+                error_rate: health.error_rate,
+                checks: health.recent.len(),
+                last_ok_at: health.last_ok_at,
+                checked_at: Some(health.checked_at),
+            },
+            None => RpcEndpointHealthResponse {
+                url,
+                kind,
+                healthy: false,
+                head_block: None,
+                lag: None,
+                latency_ms: None,
+                error: None,
+                error_rate: 0.0,
+                checks: 0,
+                last_ok_at: None,
+                checked_at: None,
+            },
+        }
+    }
+}
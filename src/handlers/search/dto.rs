@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+use crate::handlers::{
+    network::dto::NetworkResponse, pool::dto::PoolResponse, token::dto::TokenResponse,
+};
+
+/// Query parameters for searching tokens, pools and networks
+#[derive(Debug, Deserialize)]
+pub struct SearchQuery {
+    pub q: String,
+    pub limit: Option<u64>, // per entity, defaults to 10, at most 50
+}
+
+/// Response model for the search endpoint, hits grouped by entity
+/// Prefix matches come first in each group, then full-text matches by relevance
+#[derive(Debug, Serialize)]
+pub struct SearchResponse {
+    pub query: String,
+    pub tokens: Vec<TokenResponse>,
+    pub pools: Vec<PoolResponse>,
+    pub networks: Vec<NetworkResponse>,
+}
@@ -0,0 +1,44 @@
+use actix_web::{web, HttpResponse};
+use log::{error, info};
+
+use crate::{
+    database::MongoDbService,
+    errors::ApiError,
+    handlers::search::{dto::SearchQuery, service::SearchService},
+};
+
+/// GET /search - Searches tokens, pools and networks by prefix and full text
+///
+/// # Arguments
+/// * `db_service` - MongoDB service
+/// * `query` - Query parameters with the search text (`q`) and the number of hits per entity
+///
+/// # Returns
+/// JSON object of SearchResponse with the hits grouped by entity
+pub async fn search_handler(
+    db_service: web::Data<MongoDbService>,
+    query: web::Query<SearchQuery>,
+) -> Result<HttpResponse, ApiError> {
+    info!("Handling GET /search request for '{}'", query.q);
+
+    match SearchService::search(&db_service, query.into_inner()).await {
+        Ok(results) => {
+            info!(
+                "Successfully searched '{}': {} tokens, {} pools, {} networks",
+                results.query,
+                results.tokens.len(),
+                results.pools.len(),
+                results.networks.len()
+            );
+            Ok(HttpResponse::Ok().json(results))
+        }
+        Err(e) => {
+            error!("Failed to search: {}", e);
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!("Failed to search: {}", e)))
+            }
+        }
+    }
+}
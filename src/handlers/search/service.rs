@@ -0,0 +1,103 @@
+use log::debug;
+use std::collections::HashMap;
+
+use crate::{
+    database::MongoDbService,
+    handlers::{
+        network::service::NetworkService,
+        pool::service::PoolService,
+        search::dto::{SearchQuery, SearchResponse},
+        token::service::TokenService,
+    },
+};
+
+/// Default number of hits per entity
+const DEFAULT_LIMIT: u64 = 10;
+
+/// Maximum number of hits per entity
+const MAX_LIMIT: u64 = 50;
+
+/// Maximum length of a search query
+const MAX_QUERY_LENGTH: usize = 100;
+
+/// Service layer for searching tokens, pools and networks
+pub struct SearchService;
+
+impl SearchService {
+    /// Search tokens (symbol, name, address), pools (address) and networks (name, chain_id)
+    /// Soft-deleted documents are left out
+    ///
+    /// # Arguments
+    /// * `db_service` - MongoDB service
+    /// * `query` - SearchQuery containing the search text and the number of hits per entity
+    ///
+    /// # Returns
+    /// * `Ok(SearchResponse)` - Hits grouped by entity
+    /// * `Err(anyhow::Error)` - Error if the query is invalid or the database operation fails
+    pub async fn search(
+        db_service: &MongoDbService,
+        query: SearchQuery,
+    ) -> anyhow::Result<SearchResponse> {
+        let q = query.q.trim();
+        if q.is_empty() {
+            return Err(anyhow::anyhow!("Invalid query: q must not be empty"));
+        }
+        if q.len() > MAX_QUERY_LENGTH {
+            return Err(anyhow::anyhow!(
+                "Invalid query: q must be at most {} characters",
+                MAX_QUERY_LENGTH
+            ));
+        }
+        let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+        debug!("Searching '{}' (limit {} per entity)", q, limit);
+
+        let (tokens, pools, networks) = futures::try_join!(
+            db_service.get_token_repo().search(q, limit),
+            db_service.get_pool_repo().search(q, limit),
+            db_service.get_network_repo().search(q, limit),
+        )?;
+
+        let network_ids: Vec<u64> = networks.iter().map(|network| network.chain_id).collect();
+        let mut checkpoints_by_network: HashMap<u64, HashMap<String, u64>> = HashMap::new();
+        if !network_ids.is_empty() {
+            for checkpoint in db_service
+                .get_checkpoint_repo()
+                .find_by_network_ids(&network_ids)
+                .await?
+            {
+                checkpoints_by_network
+                    .entry(checkpoint.network_id)
+                    .or_default()
+                    .insert(checkpoint.indexer, checkpoint.last_block);
+            }
+        }
+
+        debug!(
+            "Found {} tokens, {} pools and {} networks for '{}'",
+            tokens.len(),
+            pools.len(),
+            networks.len(),
+            q
+        );
+        Ok(SearchResponse {
+            query: q.to_string(),
+            tokens: tokens
+                .into_iter()
+                .map(TokenService::map_to_response)
+                .collect(),
+            pools: pools
+                .into_iter()
+                .map(PoolService::map_to_response)
+                .collect(),
+            networks: networks
+                .into_iter()
+                .map(|network| {
+                    let checkpoints = checkpoints_by_network
+                        .remove(&network.chain_id)
+                        .unwrap_or_default();
+                    NetworkService::map_to_response(network, checkpoints)
+                })
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use crate::database::models::AuditEntity;
+use crate::handlers::{
+    network::dto::NetworkResponse, path::dto::PathResponse, pool::dto::PoolResponse,
+    token::dto::TokenResponse,
+};
+
+/// Query parameters for GET /sync
+#[derive(Debug, Deserialize)]
+pub struct SyncQuery {
+    pub since: Option<u64>, // watermark of the previous sync, a full sync when left out or 0
+}
+
+/// Network, pool, path or token deleted since the previous sync
+#[derive(Debug, Serialize)]
+pub struct Tombstone {
+    pub entity: AuditEntity,
+    pub id: String, // chain_id of a network, network_id:address of a token, ObjectId otherwise
+    pub network_id: Option<u64>, // None for paths, which can span networks
+    pub address: Option<String>, // set for pools and tokens
+    pub deleted_at: u64,
+    pub hard: bool, // removed from the database rather than soft-deleted
+}
+
+/// Response model for GET /sync
+#[derive(Debug, Serialize)]
+pub struct SyncResponse {
+    pub since: u64,
+    pub watermark: u64, // pass as `since` on the next sync
+    pub networks: Vec<NetworkResponse>,
+    pub tokens: Vec<TokenResponse>,
+    pub pools: Vec<PoolResponse>,
+    pub paths: Vec<PathResponse>,
+    pub deleted: Vec<Tombstone>, // always empty on a full sync
+}
@@ -0,0 +1,6 @@
+pub mod dto;
+pub mod service;
+pub mod sync;
+
+pub use dto::*;
+pub use sync::*;
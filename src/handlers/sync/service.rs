@@ -0,0 +1,263 @@
+use chrono::Utc;
+use futures::TryStreamExt;
+use log::debug;
+use mongodb::bson::doc;
+use serde_json::Value;
+
+use crate::{
+    database::models::{AuditAction, AuditEntity, AuditEntry},
+    database::MongoDbService,
+    handlers::{
+        network::service::NetworkService,
+        path::service::PathService,
+        pool::service::PoolService,
+        sync::dto::{SyncResponse, Tombstone},
+        token::service::TokenService,
+    },
+};
+
+/// Service layer for the incremental sync of the bot instances
+pub struct SyncService;
+
+impl SyncService {
+    /// Get the networks, tokens, pools and paths changed since a previous sync
+    ///
+    /// The watermark is taken one second before the sync starts, so a change made while
+    /// the sync runs is returned again by the next one rather than missed.
+    ///
+    /// # Arguments
+    /// * `db_service` - MongoDB service
+    /// * `since` - Watermark of the previous sync, 0 for a full sync
+    ///
+    /// # Returns
+    /// * `Ok(SyncResponse)` - Records created or updated since `since`, and tombstones of the deleted ones
+    /// * `Err(anyhow::Error)` - Error if `since` is in the future or the database operation fails
+    pub async fn sync(db_service: &MongoDbService, since: u64) -> anyhow::Result<SyncResponse> {
+        let now = Utc::now().timestamp() as u64;
+        if since > now {
+            return Err(anyhow::anyhow!(
+                "Invalid since: {} is in the future, pass the watermark of the previous sync",
+                since
+            ));
+        }
+        let watermark = now - 1;
+        let full = since == 0;
+        debug!("Syncing changes since {} (watermark {})", since, watermark);
+
+        let mut deleted = Vec::new();
+
+        let mut networks = Vec::new();
+        for network in db_service
+            .get_network_repo()
+            .find_updated_since(since)
+            .await?
+        {
+            if let Some(deleted_at) = network.deleted_at {
+                if !full {
+                    deleted.push(Tombstone {
+                        entity: AuditEntity::Network,
+                        id: network.chain_id.to_string(),
+                        network_id: Some(network.chain_id),
+                        address: None,
+                        deleted_at,
+                        hard: false,
+                    });
+                }
+                continue;
+            }
+            let checkpoints = db_service
+                .get_checkpoint_repo()
+                .find_by_network_id(network.chain_id)
+                .await?
+                .into_iter()
+                .map(|checkpoint| (checkpoint.indexer, checkpoint.last_block))
+                .collect();
+            networks.push(NetworkService::map_to_response(network, checkpoints));
+        }
+
+        let mut tokens = Vec::new();
+        for token in db_service
+            .get_token_repo()
+            .find_updated_since(since)
+            .await?
+        {
+            match token.deleted_at {
+                Some(deleted_at) if !full => deleted.push(Tombstone {
+                    entity: AuditEntity::Token,
+                    id: format!("{}:{}", token.network_id, token.address),
+                    network_id: Some(token.network_id),
+                    address: Some(token.address),
+                    deleted_at,
+                    hard: false,
+                }),
+                Some(_) => {}
+                None => tokens.push(TokenService::map_to_response(token)),
+            }
+        }
+
+        let mut pools = Vec::new();
+        for pool in db_service.get_pool_repo().find_updated_since(since).await? {
+            match pool.deleted_at {
+                Some(deleted_at) if !full => deleted.push(Tombstone {
+                    entity: AuditEntity::Pool,
+                    id: pool.id.map(|oid| oid.to_hex()).unwrap_or_default(),
+                    network_id: Some(pool.network_id),
+                    address: Some(pool.address),
+                    deleted_at,
+                    hard: false,
+                }),
+                Some(_) => {}
+                None => pools.push(PoolService::map_to_response(pool)),
+            }
+        }
+
+        let mut paths = Vec::new();
+        for path in db_service.get_path_repo().find_updated_since(since).await? {
+            match path.deleted_at {
+                Some(deleted_at) if !full => deleted.push(Tombstone {
+                    entity: AuditEntity::Path,
+                    id: path.id.map(|oid| oid.to_hex()).unwrap_or_default(),
+                    network_id: None,
+                    address: None,
+                    deleted_at,
+                    hard: false,
+                }),
+                Some(_) => {}
+                None => paths.push(PathService::map_to_response(path)),
+            }
+        }
+
+        // Hard-deleted records are gone from their collections, only the audit log knows them
+        if !full {
+            let filter = doc! {
+                "action": AuditAction::HardDelete.as_str(),
+                "entity": {
+                    "$in": [
+                        AuditEntity::Network.as_str(),
+                        AuditEntity::Token.as_str(),
+                        AuditEntity::Pool.as_str(),
+                        AuditEntity::Path.as_str(),
+                    ]
+                },
+                "created_at": { "$gt": since as i64 }
+            };
+            let mut cursor = db_service
+                .get_client()
+                .collection::<AuditEntry>("audit_log")
+                .find(filter)
+                .sort(doc! { "created_at": 1, "_id": 1 })
+                .await?;
+            while let Some(entry) = cursor.try_next().await? {
+                deleted.push(Self::hard_delete_tombstone(entry));
+            }
+        }
+
+        debug!(
+            "Synced {} networks, {} tokens, {} pools, {} paths and {} deletions since {}",
+            networks.len(),
+            tokens.len(),
+            pools.len(),
+            paths.len(),
+            deleted.len(),
+            since
+        );
+        Ok(SyncResponse {
+            since,
+            watermark,
+            networks,
+            tokens,
+            pools,
+            paths,
+            deleted,
+        })
+    }
+
+    /// Build the tombstone of a hard delete from its audit log entry
+    /// The network and address are the values of the record before it was removed
+    fn hard_delete_tombstone(entry: AuditEntry) -> Tombstone {
+        let before = |field: &str| {
+            entry
+                .changes
+                .iter()
+                .find(|change| change.field == field)
+                .map(|change| &change.before)
+        };
+
+        let network_id = match entry.entity {
+            AuditEntity::Network => before("chain_id"),
+            _ => before("network_id"),
+        }
+        .and_then(Value::as_u64);
+        let address = before("address")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
+        Tombstone {
+            entity: entry.entity,
+            id: entry.entity_id,
+            network_id,
+            address,
+            deleted_at: entry.created_at,
+            hard: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::FieldChange;
+    use serde_json::json;
+
+    fn hard_delete(entity: AuditEntity, entity_id: &str, changes: Vec<FieldChange>) -> AuditEntry {
+        AuditEntry::new(
+            "admin".to_string(),
+            AuditAction::HardDelete,
+            entity,
+            entity_id.to_string(),
+            changes,
+        )
+    }
+
+    fn removed(field: &str, value: Value) -> FieldChange {
+        FieldChange {
+            field: field.to_string(),
+            before: value,
+            after: Value::Null,
+        }
+    }
+
+    #[test]
+    fn test_hard_delete_tombstone() {
+        let entry = hard_delete(
+            AuditEntity::Pool,
+            "65f000000000000000000001",
+            vec![
+                removed("address", json!("0xabc")),
+                removed("network_id", json!(8453)),
+            ],
+        );
+        let tombstone = SyncService::hard_delete_tombstone(entry);
+        assert_eq!(tombstone.entity, AuditEntity::Pool);
+        assert_eq!(tombstone.id, "65f000000000000000000001");
+        assert_eq!(tombstone.network_id, Some(8453));
+        assert_eq!(tombstone.address.as_deref(), Some("0xabc"));
+        assert!(tombstone.hard);
+
+        let entry = hard_delete(
+            AuditEntity::Network,
+            "10",
+            vec![removed("chain_id", json!(10))],
+        );
+        let tombstone = SyncService::hard_delete_tombstone(entry);
+        assert_eq!(tombstone.network_id, Some(10));
+        assert_eq!(tombstone.address, None);
+
+        let tombstone = SyncService::hard_delete_tombstone(hard_delete(
+            AuditEntity::Path,
+            "65f000000000000000000002",
+            vec![],
+        ));
+        assert_eq!(tombstone.network_id, None);
+    }
+}
@@ -0,0 +1,47 @@
+use actix_web::{web, HttpResponse};
+use log::{error, info};
+
+use crate::{
+    database::MongoDbService,
+    errors::ApiError,
+    handlers::sync::{dto::SyncQuery, service::SyncService},
+};
+
+/// GET /sync - Returns the networks, tokens, pools and paths changed since a previous sync
+///
+/// # Arguments
+/// * `db_service` - MongoDB service
+/// * `query` - Query parameters with the watermark of the previous sync (`since`)
+///
+/// # Returns
+/// JSON object of SyncResponse with the changed records, the tombstones and the new watermark
+pub async fn sync_handler(
+    db_service: web::Data<MongoDbService>,
+    query: web::Query<SyncQuery>,
+) -> Result<HttpResponse, ApiError> {
+    let since = query.since.unwrap_or(0);
+    info!("Handling GET /sync request since {}", since);
+
+    match SyncService::sync(&db_service, since).await {
+        Ok(changes) => {
+            info!(
+                "Successfully synced since {}: {} networks, {} tokens, {} pools, {} paths, {} deletions",
+                since,
+                changes.networks.len(),
+                changes.tokens.len(),
+                changes.pools.len(),
+                changes.paths.len(),
+                changes.deleted.len()
+            );
+            Ok(HttpResponse::Ok().json(changes))
+        }
+        Err(e) => {
+            error!("Failed to sync: {}", e);
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!("Failed to sync: {}", e)))
+            }
+        }
+    }
+}
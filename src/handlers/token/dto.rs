@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// Response model for token API endpoints
 #[derive(Debug, Serialize)]
@@ -9,6 +9,51 @@ pub struct TokenResponse {
     pub name: Option<String>,
     pub symbol: Option<String>,
     pub decimals: Option<u8>,
+    pub blacklisted: bool,
+    pub profit_token: bool,
     pub created_at: u64,
     pub updated_at: u64,
 }
+
+fn default_verify() -> bool {
+    true
+}
+
+/// Request model for creating a token
+#[derive(Debug, Deserialize)]
+pub struct CreateTokenRequest {
+    pub network_id: u64,
+    pub address: String,
+    pub name: Option<String>,   // fetched on-chain when left out
+    pub symbol: Option<String>, // fetched on-chain when left out
+    pub decimals: Option<u8>,
+    #[serde(default = "default_verify")]
+    pub verify: bool, // check that the address is a contract answering IERC20 decimals()
+    pub blacklisted: Option<bool>, // false for a new token, unchanged on a restored one
+    pub profit_token: Option<bool>, // false for a new token, unchanged on a restored one
+}
+
+/// Request model for updating an existing token, fields left out are unchanged
+#[derive(Debug, Deserialize)]
+pub struct UpdateTokenRequest {
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub decimals: Option<u8>,
+    pub blacklisted: Option<bool>,
+    pub profit_token: Option<bool>,
+}
+
+/// Query parameters for POST /tokens/network/{network_id}/refresh
+#[derive(Debug, Deserialize)]
+pub struct RefreshTokensQuery {
+    pub limit: Option<u64>, // defaults to 200, at most 1000
+}
+
+/// Response model for POST /tokens/network/{network_id}/refresh
+#[derive(Debug, Serialize)]
+pub struct RefreshTokensResponse {
+    pub network_id: u64,
+    pub refreshed: usize,    // tokens whose metadata was fetched and stored
+    pub failed: Vec<String>, // addresses whose metadata could not be fetched
+    pub remaining: u64,      // tokens still missing metadata, failed ones included
+}
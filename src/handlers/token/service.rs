@@ -1,80 +1,82 @@
 use alloy::primitives::Address;
-use futures::TryStreamExt;
-use log::debug;
-use mongodb::bson::doc;
+use alloy::providers::{Provider, MULTICALL3_ADDRESS};
+use futures::StreamExt;
+use log::{debug, error, info, warn};
+use mongodb::bson::{doc, Document};
 use mongodb::Database;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::Arc;
 
 use crate::{
-    database::models::utils::address_to_string, database::models::Token,
-    handlers::token::dto::TokenResponse,
+    bot::contracts::IERC20,
+    bot::providers::{fetch_token_data, is_rpc_failure, try_create_provider},
+    database::models::utils::address_to_string,
+    database::models::{AuditAction, AuditEntity, Token},
+    database::repositories::{ListQuery, Page},
+    database::MongoDbService,
+    handlers::{
+        audit::service::AuditService,
+        network::{dto::NetworkResponse, service::NetworkService},
+        token::dto::{
+            CreateTokenRequest, RefreshTokensQuery, RefreshTokensResponse, TokenResponse,
+            UpdateTokenRequest,
+        },
+    },
 };
 
-/// Service layer for token-related business logic
-pub struct TokenService;
+/// Number of token metadata requests in flight at once
+const FETCH_CONCURRENCY: usize = 8;
 
-impl TokenService {
-    /// Get all tokens
-    ///
-    /// # Arguments
-    /// * `db` - Database reference
-    ///
-    /// # Returns
-    /// * `Ok(Vec<TokenResponse>)` - List of tokens
-    /// * `Err(anyhow::Error)` - Error if database operation fails
-    pub async fn get_all_tokens(db: &Database) -> anyhow::Result<Vec<TokenResponse>> {
-        debug!("Fetching all tokens");
+/// Default number of tokens refreshed per request
+const DEFAULT_REFRESH_LIMIT: u64 = 200;
 
-        let collection = db.collection::<Token>("tokens");
-        // Filter out soft-deleted records
-        let filter = doc! {
-            "$or": [
-                { "deleted_at": null },
-                { "deleted_at": { "$exists": false } }
-            ]
-        };
-        let mut cursor = collection.find(filter).await?;
-        let mut tokens = Vec::new();
+/// Maximum number of tokens refreshed per request
+const MAX_REFRESH_LIMIT: u64 = 1000;
 
-        while let Some(token) = cursor.try_next().await? {
-            tokens.push(Self::map_to_response(token));
-        }
+/// Outcome of fetching token metadata on-chain
+struct EnrichedTokens {
+    fetched: usize,
+    inserted: usize,
+    updated: usize,
+    failed: Vec<Address>,
+}
 
-        debug!("Retrieved {} tokens from database", tokens.len());
-        Ok(tokens)
-    }
+/// Service layer for token-related business logic
+pub struct TokenService;
 
-    /// Get tokens by network ID
+impl TokenService {
+    /// Get a page of tokens
     ///
     /// # Arguments
-    /// * `db` - Database reference
-    /// * `network_id` - The network ID to filter by
+    /// * `db_service` - MongoDB service
+    /// * `network_id` - Optional network ID to filter by
+    /// * `query` - Pagination, sorting and filters
     ///
     /// # Returns
-    /// * `Ok(Vec<TokenResponse>)` - List of tokens
-    /// * `Err(anyhow::Error)` - Error if database operation fails
-    pub async fn get_tokens_by_network_id(
-        db: &Database,
-        network_id: u64,
-    ) -> anyhow::Result<Vec<TokenResponse>> {
-        debug!("Fetching tokens with network_id: {}", network_id);
-
-        let collection = db.collection::<Token>("tokens");
-        // Filter out soft-deleted records
-        let filter = doc! {
-            "network_id": network_id as i64,
-            "$or": [
-                { "deleted_at": null },
-                { "deleted_at": { "$exists": false } }
-            ]
-        };
-        let mut cursor = collection.find(filter).await?;
-        let mut tokens = Vec::new();
+    /// * `Ok(Page<TokenResponse>)` - Page of tokens with the total count
+    /// * `Err(anyhow::Error)` - Error if the query is invalid or the database operation fails
+    pub async fn get_tokens(
+        db_service: &MongoDbService,
+        network_id: Option<u64>,
+        query: &ListQuery,
+    ) -> anyhow::Result<Page<TokenResponse>> {
+        debug!(
+            "Fetching tokens with network_id: {:?}, query: {:?}",
+            network_id, query
+        );
 
-        while let Some(token) = cursor.try_next().await? {
-            tokens.push(Self::map_to_response(token));
-        }
+        let tokens = db_service
+            .get_token_repo()
+            .find_page(network_id, query)
+            .await?
+            .map(Self::map_to_response);
 
-        debug!("Retrieved {} tokens from database", tokens.len());
+        debug!(
+            "Retrieved {} of {} tokens from database",
+            tokens.items.len(),
+            tokens.total
+        );
         Ok(tokens)
     }
 
@@ -145,9 +147,503 @@ impl TokenService {
         Ok(count)
     }
 
+    /// Fetch the ERC20 metadata (name, symbol, decimals) of tokens on-chain and upsert them
+    ///
+    /// Tokens already stored with their full metadata are skipped, and only the missing fields
+    /// of stored tokens are filled so that values set by operators are kept. Tokens whose
+    /// metadata cannot be fetched (e.g. not an ERC20 contract) are reported as failed.
+    async fn enrich_tokens(
+        db_service: &MongoDbService,
+        network_id: u64,
+        addresses: &[Address],
+    ) -> anyhow::Result<EnrichedTokens> {
+        let stored: HashMap<Address, Token> = db_service
+            .get_token_repo()
+            .find_by_addresses(network_id, addresses)
+            .await?
+            .into_iter()
+            .filter_map(|token| Some((Address::from_str(&token.address).ok()?, token)))
+            .collect();
+        let mut seen = HashSet::new();
+        let missing: Vec<Address> = addresses
+            .iter()
+            .copied()
+            .filter(|address| {
+                let complete = stored.get(address).is_some_and(|token| {
+                    token.name.is_some() && token.symbol.is_some() && token.decimals.is_some()
+                });
+                !complete && seen.insert(*address)
+            })
+            .collect();
+
+        if missing.is_empty() {
+            return Ok(EnrichedTokens {
+                fetched: 0,
+                inserted: 0,
+                updated: 0,
+                failed: Vec::new(),
+            });
+        }
+
+        let network = db_service
+            .find_network(network_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Network with chain_id {} not found", network_id))?;
+        let multicall_address = network
+            .multicall_address
+            .as_ref()
+            .and_then(|s| Address::from_str(s).ok())
+            .unwrap_or(MULTICALL3_ADDRESS);
+        let provider = Arc::new(try_create_provider(&network.rpcs)?);
+
+        debug!(
+            "Fetching metadata of {} tokens on network {}",
+            missing.len(),
+            network_id
+        );
+        let results: Vec<(Address, anyhow::Result<crate::bot::models::token::Token>)> =
+            futures::stream::iter(missing)
+                .map(|address| {
+                    let provider = provider.clone();
+                    async move {
+                        let result =
+                            fetch_token_data(provider, address, network_id, multicall_address)
+                                .await;
+                        (address, result)
+                    }
+                })
+                .buffer_unordered(FETCH_CONCURRENCY)
+                .collect()
+                .await;
+
+        let mut tokens = Vec::new();
+        let mut failed = Vec::new();
+        for (address, result) in results {
+            match result {
+                Ok(data) => {
+                    let stored = stored.get(&address);
+                    let missing = |field: fn(&Token) -> bool| !stored.is_some_and(field);
+                    tokens.push(Token::new(
+                        network_id,
+                        address_to_string(&address),
+                        Some(data.name).filter(|_| missing(|t| t.name.is_some())),
+                        Some(data.symbol).filter(|_| missing(|t| t.symbol.is_some())),
+                        Some(data.decimals).filter(|_| missing(|t| t.decimals.is_some())),
+                    ));
+                }
+                Err(e) => {
+                    warn!(
+                        "Failed to fetch metadata of token {} on network {}: {}",
+                        address, network_id, e
+                    );
+                    failed.push(address);
+                }
+            }
+        }
+
+        let fetched = tokens.len();
+        let (inserted, updated) = db_service
+            .get_token_repo()
+            .bulk_insert_or_update(tokens)
+            .await?;
+
+        Ok(EnrichedTokens {
+            fetched,
+            inserted,
+            updated,
+            failed,
+        })
+    }
+
+    /// Fetch the metadata of tokens missing from the database in the background
+    /// Used when pools or paths reference tokens, failures are only logged
+    pub fn spawn_enrich_tokens(
+        db_service: MongoDbService,
+        network_id: u64,
+        addresses: Vec<Address>,
+    ) {
+        tokio::spawn(async move {
+            match Self::enrich_tokens(&db_service, network_id, &addresses).await {
+                Ok(result) if result.fetched > 0 || !result.failed.is_empty() => info!(
+                    "Enriched tokens on network {}: {} inserted, {} updated, {} failed",
+                    network_id,
+                    result.inserted,
+                    result.updated,
+                    result.failed.len()
+                ),
+                Ok(_) => {}
+                Err(e) => error!("Failed to enrich tokens on network {}: {}", network_id, e),
+            }
+        });
+    }
+
+    /// Re-fetch the metadata of the tokens of a network missing their name, symbol or decimals
+    ///
+    /// # Arguments
+    /// * `db_service` - MongoDB service
+    /// * `network_id` - The network ID
+    /// * `query` - RefreshTokensQuery with the maximum number of tokens to refresh
+    ///
+    /// # Returns
+    /// * `Ok(RefreshTokensResponse)` - Refreshed and failed tokens, and the tokens left to refresh
+    /// * `Err(anyhow::Error)` - Error if the network is not found or the database operation fails
+    pub async fn refresh_tokens(
+        db_service: &MongoDbService,
+        network_id: u64,
+        query: RefreshTokensQuery,
+    ) -> anyhow::Result<RefreshTokensResponse> {
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_REFRESH_LIMIT)
+            .clamp(1, MAX_REFRESH_LIMIT);
+        debug!(
+            "Refreshing metadata of up to {} tokens on network {}",
+            limit, network_id
+        );
+
+        if db_service.find_network(network_id).await?.is_none() {
+            return Err(anyhow::anyhow!(
+                "Network with chain_id {} not found",
+                network_id
+            ));
+        }
+
+        let addresses: Vec<Address> = db_service
+            .get_token_repo()
+            .find_missing_metadata(network_id, limit)
+            .await?
+            .iter()
+            .filter_map(|token| Address::from_str(&token.address).ok())
+            .collect();
+        let result = Self::enrich_tokens(db_service, network_id, &addresses).await?;
+        let remaining = db_service
+            .get_token_repo()
+            .count_missing_metadata(network_id)
+            .await?;
+
+        Ok(RefreshTokensResponse {
+            network_id,
+            refreshed: result.inserted + result.updated,
+            failed: result.failed.iter().map(address_to_string).collect(),
+            remaining,
+        })
+    }
+
+    /// Validate that an address string is a valid Ethereum address
+    pub(crate) fn validate_address(address: &str) -> anyhow::Result<Address> {
+        Address::from_str(address)
+            .map_err(|e| anyhow::anyhow!("Invalid address format '{}': {}", address, e))
+    }
+
+    /// Validate an operator provided name or symbol
+    pub(crate) fn validate_label(field: &str, value: &Option<String>) -> anyhow::Result<()> {
+        match value {
+            Some(value) if value.trim().is_empty() => {
+                Err(anyhow::anyhow!("Invalid {}: must not be empty", field))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Verify on-chain that a token is a contract answering IERC20 `decimals()`
+    /// Returns the decimals of the token
+    async fn verify_token_on_chain(
+        network: &NetworkResponse,
+        address: Address,
+    ) -> anyhow::Result<u8> {
+        let provider = try_create_provider(&network.rpcs)?;
+
+        let code = provider.get_code_at(address).await.map_err(|e| {
+            anyhow::anyhow!("RPC request failed on network {}: {}", network.chain_id, e)
+        })?;
+        if code.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Invalid token: no contract at {} on network {}",
+                address,
+                network.chain_id
+            ));
+        }
+
+        IERC20::new(address, &provider)
+            .decimals()
+            .call()
+            .await
+            .map_err(|e| {
+                // A revert or an empty answer means the token is not an ERC20
+                let e = anyhow::Error::from(e);
+                if is_rpc_failure(&e) {
+                    anyhow::anyhow!("RPC request failed on network {}: {}", network.chain_id, e)
+                } else {
+                    anyhow::anyhow!(
+                        "Invalid token: {} does not answer decimals() on network {}: {}",
+                        address,
+                        network.chain_id,
+                        e
+                    )
+                }
+            })
+    }
+
+    /// Decimals to store for a verified token
+    /// Fails when the requested decimals differ from the on-chain value
+    fn check_decimals(requested: Option<u8>, onchain: u8) -> anyhow::Result<u8> {
+        match requested {
+            Some(requested) if requested != onchain => Err(anyhow::anyhow!(
+                "Invalid decimals: the token has {} decimals on-chain",
+                onchain
+            )),
+            _ => Ok(onchain),
+        }
+    }
+
+    /// `$set` document restoring a soft-deleted token with the fields given in `request`
+    /// Flags left out keep their stored value
+    fn restore_update(request: &CreateTokenRequest, decimals: Option<u8>, now: i64) -> Document {
+        let mut update_doc = doc! {
+            "updated_at": now,
+            "deleted_at": null,
+        };
+        if let Some(name) = &request.name {
+            update_doc.insert("name", name);
+        }
+        if let Some(symbol) = &request.symbol {
+            update_doc.insert("symbol", symbol);
+        }
+        if let Some(decimals) = decimals {
+            update_doc.insert("decimals", decimals as i32);
+        }
+        if let Some(blacklisted) = request.blacklisted {
+            update_doc.insert("blacklisted", blacklisted);
+        }
+        if let Some(profit_token) = request.profit_token {
+            update_doc.insert("profit_token", profit_token);
+        }
+        update_doc
+    }
+
+    /// Create a new token
+    ///
+    /// The token is verified on-chain unless `verify` is false, its decimals must then match.
+    /// A name or symbol left out is fetched on-chain in the background. If a token with the same
+    /// network_id and address exists (even if soft-deleted), it is restored and updated.
+    ///
+    /// # Arguments
+    /// * `db_service` - MongoDB service
+    /// * `actor` - Name of the API key making the change
+    /// * `request` - CreateTokenRequest containing token data
+    ///
+    /// # Returns
+    /// * `Ok(TokenResponse)` - Created token
+    /// * `Err(anyhow::Error)` - Error if the request is invalid, the network is not found, the
+    ///   RPC request fails ("RPC request failed") or the database operation fails
+    pub async fn create_token(
+        db_service: &MongoDbService,
+        actor: &str,
+        request: CreateTokenRequest,
+    ) -> anyhow::Result<TokenResponse> {
+        debug!(
+            "Creating new token with network_id: {}, address: {}",
+            request.network_id, request.address
+        );
+        let db = &db_service.get_client().database();
+
+        let address = Self::validate_address(&request.address)?;
+        Self::validate_label("name", &request.name)?;
+        Self::validate_label("symbol", &request.symbol)?;
+        let network = NetworkService::get_network_by_chain_id(db, request.network_id)
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!("Network with chain_id {} not found", request.network_id)
+            })?;
+
+        let mut decimals = request.decimals;
+        if request.verify {
+            let onchain_decimals = Self::verify_token_on_chain(&network, address).await?;
+            decimals = Some(Self::check_decimals(decimals, onchain_decimals)?);
+        }
+
+        let collection = db.collection::<Token>("tokens");
+        let addr_str = address_to_string(&address);
+        // Check if token exists (including soft-deleted)
+        let filter = doc! {
+            "network_id": request.network_id as i64,
+            "address": &addr_str
+        };
+        let existing = collection.find_one(filter.clone()).await?;
+
+        let token = if let Some(existing_token) = existing {
+            // Token exists, restore it and apply the requested fields
+            debug!("Token exists, restoring and updating");
+            let update_doc =
+                Self::restore_update(&request, decimals, chrono::Utc::now().timestamp());
+            collection
+                .update_one(filter.clone(), doc! { "$set": update_doc })
+                .await?;
+
+            let restored_token = collection.find_one(filter).await?.unwrap();
+            Self::audit(
+                db,
+                actor,
+                AuditAction::Create,
+                request.network_id,
+                &addr_str,
+                Some(&existing_token),
+                Some(&restored_token),
+            )
+            .await;
+            restored_token
+        } else {
+            let mut token = Token::new(
+                request.network_id,
+                addr_str.clone(),
+                request.name,
+                request.symbol,
+                decimals,
+            );
+            token.blacklisted = request.blacklisted.unwrap_or(false);
+            token.profit_token = request.profit_token.unwrap_or(false);
+            collection.insert_one(&token).await?;
+
+            let created_token = collection.find_one(filter).await?.unwrap();
+            Self::audit(
+                db,
+                actor,
+                AuditAction::Create,
+                request.network_id,
+                &addr_str,
+                None,
+                Some(&created_token),
+            )
+            .await;
+            created_token
+        };
+
+        debug!(
+            "Token created successfully: network_id={}, address={}",
+            request.network_id, addr_str
+        );
+        if token.name.is_none() || token.symbol.is_none() || token.decimals.is_none() {
+            Self::spawn_enrich_tokens(db_service.clone(), request.network_id, vec![address]);
+        }
+        Ok(Self::map_to_response(token))
+    }
+
+    /// Update an existing token, e.g. to override its name or symbol or change its flags
+    ///
+    /// # Arguments
+    /// * `db` - Database reference
+    /// * `actor` - Name of the API key making the change
+    /// * `network_id` - The network ID
+    /// * `address` - The token address
+    /// * `request` - UpdateTokenRequest containing fields to update
+    ///
+    /// # Returns
+    /// * `Ok(TokenResponse)` - Updated token
+    /// * `Err(anyhow::Error)` - Error if the request is invalid, the token is not found or the
+    ///   database operation fails
+    pub async fn update_token(
+        db: &Database,
+        actor: &str,
+        network_id: u64,
+        address: &Address,
+        request: UpdateTokenRequest,
+    ) -> anyhow::Result<TokenResponse> {
+        let addr_str = address_to_string(address);
+        debug!(
+            "Updating token with network_id: {}, address: {}",
+            network_id, addr_str
+        );
+        Self::validate_label("name", &request.name)?;
+        Self::validate_label("symbol", &request.symbol)?;
+
+        let collection = db.collection::<Token>("tokens");
+        // Only update non-deleted tokens
+        let filter = doc! {
+            "network_id": network_id as i64,
+            "address": &addr_str,
+            "$or": [
+                { "deleted_at": null },
+                { "deleted_at": { "$exists": false } }
+            ]
+        };
+
+        let existing = collection.find_one(filter.clone()).await?.ok_or_else(|| {
+            anyhow::anyhow!(
+                "Token with network_id {} and address {} not found",
+                network_id,
+                addr_str
+            )
+        })?;
+
+        // Build update document
+        let mut update_doc = mongodb::bson::Document::new();
+        update_doc.insert("updated_at", chrono::Utc::now().timestamp());
+
+        if let Some(name) = request.name {
+            update_doc.insert("name", name);
+        }
+        if let Some(symbol) = request.symbol {
+            update_doc.insert("symbol", symbol);
+        }
+        if let Some(decimals) = request.decimals {
+            update_doc.insert("decimals", decimals as i32);
+        }
+        if let Some(blacklisted) = request.blacklisted {
+            update_doc.insert("blacklisted", blacklisted);
+        }
+        if let Some(profit_token) = request.profit_token {
+            update_doc.insert("profit_token", profit_token);
+        }
+
+        let update = doc! { "$set": update_doc };
+        collection.update_one(filter.clone(), update).await?;
+
+        // Get updated token
+        let token = collection.find_one(filter).await?.unwrap();
+        Self::audit(
+            db,
+            actor,
+            AuditAction::Update,
+            network_id,
+            &addr_str,
+            Some(&existing),
+            Some(&token),
+        )
+        .await;
+
+        debug!(
+            "Token updated successfully: network_id={}, address={}",
+            network_id, addr_str
+        );
+        Ok(Self::map_to_response(token))
+    }
+
+    /// Record a change of a token in the audit log, tokens are identified as `network_id:address`
+    async fn audit(
+        db: &Database,
+        actor: &str,
+        action: AuditAction,
+        network_id: u64,
+        address: &str,
+        before: Option<&Token>,
+        after: Option<&Token>,
+    ) {
+        AuditService::record(
+            db,
+            actor,
+            action,
+            AuditEntity::Token,
+            &format!("{}:{}", network_id, address),
+            before,
+            after,
+        )
+        .await;
+    }
+
     /// Soft delete a token by network_id and address (set deleted_at instead of removing)
     pub async fn delete_token(
         db: &Database,
+        actor: &str,
         network_id: u64,
         address: &Address,
     ) -> anyhow::Result<()> {
@@ -168,14 +664,13 @@ impl TokenService {
             ]
         };
 
-        let existing = collection.find_one(filter.clone()).await?;
-        if existing.is_none() {
-            return Err(anyhow::anyhow!(
+        let existing = collection.find_one(filter.clone()).await?.ok_or_else(|| {
+            anyhow::anyhow!(
                 "Token with network_id {} and address {} not found or already deleted",
                 network_id,
                 addr_str
-            ));
-        }
+            )
+        })?;
 
         let update = doc! {
             "$set": {
@@ -186,6 +681,20 @@ impl TokenService {
 
         collection.update_one(filter, update).await?;
 
+        let deleted = collection
+            .find_one(doc! { "network_id": network_id as i64, "address": &addr_str })
+            .await?;
+        Self::audit(
+            db,
+            actor,
+            AuditAction::Delete,
+            network_id,
+            &addr_str,
+            Some(&existing),
+            deleted.as_ref(),
+        )
+        .await;
+
         debug!(
             "Token soft deleted successfully: network_id={}, address={}",
             network_id, addr_str
@@ -198,6 +707,7 @@ impl TokenService {
     ///
     /// # Arguments
     /// * `db` - Database reference
+    /// * `actor` - Name of the API key making the change
     /// * `network_id` - The network ID
     /// * `address` - The token address
     ///
@@ -206,6 +716,7 @@ impl TokenService {
     /// * `Err(anyhow::Error)` - Error if token not found or not soft-deleted
     pub async fn hard_delete_token(
         db: &Database,
+        actor: &str,
         network_id: u64,
         address: &Address,
     ) -> anyhow::Result<()> {
@@ -224,17 +735,26 @@ impl TokenService {
         };
 
         // Check if token exists and is soft-deleted
-        let existing = collection.find_one(filter.clone()).await?;
-        if existing.is_none() {
-            return Err(anyhow::anyhow!(
+        let existing = collection.find_one(filter.clone()).await?.ok_or_else(|| {
+            anyhow::anyhow!(
                 "Token with network_id {} and address {} not found or not soft-deleted",
                 network_id,
                 addr_str
-            ));
-        }
+            )
+        })?;
 
         // Hard delete: actually remove from database
         collection.delete_one(filter).await?;
+        Self::audit(
+            db,
+            actor,
+            AuditAction::HardDelete,
+            network_id,
+            &addr_str,
+            Some(&existing),
+            None,
+        )
+        .await;
 
         debug!(
             "Token hard deleted successfully: network_id={}, address={}",
@@ -250,7 +770,7 @@ impl TokenService {
     ///
     /// # Returns
     /// TokenResponse DTO
-    fn map_to_response(token: Token) -> TokenResponse {
+    pub(crate) fn map_to_response(token: Token) -> TokenResponse {
         let id = token
             .id
             .map(|oid| oid.to_hex())
@@ -263,8 +783,54 @@ impl TokenService {
             name: token.name,
             symbol: token.symbol,
             decimals: token.decimals,
+            blacklisted: token.blacklisted,
+            profit_token: token.profit_token,
             created_at: token.created_at,
             updated_at: token.updated_at,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(blacklisted: Option<bool>, profit_token: Option<bool>) -> CreateTokenRequest {
+        CreateTokenRequest {
+            network_id: 1,
+            address: "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48".to_string(),
+            name: None,
+            symbol: Some("USDC".to_string()),
+            decimals: None,
+            verify: true,
+            blacklisted,
+            profit_token,
+        }
+    }
+
+    #[test]
+    fn test_check_decimals() {
+        assert_eq!(TokenService::check_decimals(None, 6).unwrap(), 6);
+        assert_eq!(TokenService::check_decimals(Some(6), 6).unwrap(), 6);
+        let error = TokenService::check_decimals(Some(18), 6).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid decimals: the token has 6 decimals on-chain"
+        );
+    }
+
+    #[test]
+    fn test_restore_update_flags() {
+        // Flags left out keep the stored values of the restored token
+        let update = TokenService::restore_update(&request(None, None), Some(6), 100);
+        assert_eq!(
+            update,
+            doc! { "updated_at": 100_i64, "deleted_at": null, "symbol": "USDC", "decimals": 6 }
+        );
+
+        let update = TokenService::restore_update(&request(Some(false), Some(true)), None, 100);
+        assert!(!update.get_bool("blacklisted").unwrap());
+        assert!(update.get_bool("profit_token").unwrap());
+        assert!(!update.contains_key("decimals"));
+    }
+}
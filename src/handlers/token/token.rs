@@ -1,6 +1,13 @@
 use crate::{
+    database::{repositories::ListQuery, MongoDbService},
     errors::ApiError,
-    handlers::{config::auth::ApiKey, token::service::TokenService},
+    handlers::{
+        config::auth::{ApiKey, HardDeleteApiKey},
+        token::{
+            dto::{CreateTokenRequest, RefreshTokensQuery, UpdateTokenRequest},
+            service::TokenService,
+        },
+    },
 };
 use actix_web::{web, HttpResponse};
 use alloy::primitives::Address;
@@ -8,54 +15,81 @@ use log::{error, info};
 use mongodb::Database;
 use std::str::FromStr;
 
-/// GET /tokens - Returns all tokens
+/// GET /tokens - Returns a page of tokens
+///
+/// # Arguments
+/// * `db_service` - MongoDB service
+/// * `query` - Query parameters with pagination (limit, page or cursor), sort and filters
+///   (include_deleted, updated_since)
 ///
 /// # Returns
-/// JSON array of TokenResponse objects containing token information
-pub async fn get_tokens_handler(db: web::Data<Database>) -> Result<HttpResponse, ApiError> {
+/// JSON object of Page<TokenResponse> with the total count
+pub async fn get_tokens_handler(
+    db_service: web::Data<MongoDbService>,
+    query: web::Query<ListQuery>,
+) -> Result<HttpResponse, ApiError> {
     info!("Handling GET /tokens request");
 
-    match TokenService::get_all_tokens(&db).await {
+    match TokenService::get_tokens(&db_service, None, &query).await {
         Ok(tokens) => {
-            info!("Successfully retrieved {} tokens", tokens.len());
+            info!(
+                "Successfully retrieved {} of {} tokens",
+                tokens.items.len(),
+                tokens.total
+            );
             Ok(HttpResponse::Ok().json(tokens))
         }
         Err(e) => {
             error!("Failed to retrieve tokens: {}", e);
-            Err(ApiError::DatabaseError(format!(
-                "Failed to retrieve tokens: {}",
-                e
-            )))
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to retrieve tokens: {}",
+                    e
+                )))
+            }
         }
     }
 }
 
-/// GET /tokens/network/{network_id} - Returns tokens by network ID
+/// GET /tokens/network/{network_id} - Returns a page of the tokens of a network
 ///
 /// # Arguments
-/// * `db` - Database connection
+/// * `db_service` - MongoDB service
 /// * `path` - Path parameters containing network_id
+/// * `query` - Query parameters with pagination (limit, page or cursor), sort and filters
+///   (include_deleted, updated_since)
 ///
 /// # Returns
-/// JSON array of TokenResponse objects
+/// JSON object of Page<TokenResponse> with the total count
 pub async fn get_tokens_by_network_id_handler(
-    db: web::Data<Database>,
+    db_service: web::Data<MongoDbService>,
     path: web::Path<u64>,
+    query: web::Query<ListQuery>,
 ) -> Result<HttpResponse, ApiError> {
     let network_id = path.into_inner();
     info!("Handling GET /tokens/network/{} request", network_id);
 
-    match TokenService::get_tokens_by_network_id(&db, network_id).await {
+    match TokenService::get_tokens(&db_service, Some(network_id), &query).await {
         Ok(tokens) => {
-            info!("Successfully retrieved {} tokens", tokens.len());
+            info!(
+                "Successfully retrieved {} of {} tokens",
+                tokens.items.len(),
+                tokens.total
+            );
             Ok(HttpResponse::Ok().json(tokens))
         }
         Err(e) => {
             error!("Failed to retrieve tokens: {}", e);
-            Err(ApiError::DatabaseError(format!(
-                "Failed to retrieve tokens: {}",
-                e
-            )))
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to retrieve tokens: {}",
+                    e
+                )))
+            }
         }
     }
 }
@@ -141,10 +175,170 @@ pub async fn count_tokens_by_network_id_handler(
     }
 }
 
+/// POST /tokens - Creates a new token, verified on-chain unless `verify` is false
+/// Requires API key authentication via X-API-Key header
+///
+/// # Arguments
+/// * `api_key` - API key from X-API-Key header (validated by extractor), recorded in the audit log
+/// * `db_service` - MongoDB service
+/// * `body` - CreateTokenRequest containing token data
+///
+/// # Returns
+/// JSON object of TokenResponse containing created token information
+pub async fn create_token_handler(
+    api_key: ApiKey,
+    db_service: web::Data<MongoDbService>,
+    body: web::Json<CreateTokenRequest>,
+) -> Result<HttpResponse, ApiError> {
+    info!("Handling POST /tokens request");
+
+    match TokenService::create_token(&db_service, &api_key.name, body.into_inner()).await {
+        Ok(token) => {
+            info!(
+                "Successfully created token {} on network {}",
+                token.address, token.network_id
+            );
+            Ok(HttpResponse::Created().json(token))
+        }
+        Err(e) => {
+            error!("Failed to create token: {}", e);
+            if e.to_string().contains("RPC request failed") {
+                Err(ApiError::UpstreamError(e.to_string()))
+            } else if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else if e.to_string().contains("not found") {
+                Err(ApiError::NotFound(e.to_string()))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to create token: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
+
+/// PUT /tokens/network/{network_id}/address/{address} - Updates an existing token
+/// Requires API key authentication via X-API-Key header
+///
+/// # Arguments
+/// * `api_key` - API key from X-API-Key header (validated by extractor), recorded in the audit log
+/// * `db` - Database connection
+/// * `path` - Path parameters containing network_id and address
+/// * `body` - UpdateTokenRequest containing fields to update
+///
+/// # Returns
+/// JSON object of TokenResponse containing updated token information
+pub async fn update_token_handler(
+    api_key: ApiKey,
+    db: web::Data<Database>,
+    path: web::Path<(u64, String)>,
+    body: web::Json<UpdateTokenRequest>,
+) -> Result<HttpResponse, ApiError> {
+    let (network_id, address_str) = path.into_inner();
+    info!(
+        "Handling PUT /tokens/network/{}/address/{} request",
+        network_id, address_str
+    );
+
+    let address = match address_str.parse::<Address>() {
+        Ok(addr) => addr,
+        Err(e) => {
+            error!("Invalid address format: {}", e);
+            return Err(ApiError::BadRequest(format!(
+                "Invalid address format: {}",
+                e
+            )));
+        }
+    };
+
+    match TokenService::update_token(&db, &api_key.name, network_id, &address, body.into_inner())
+        .await
+    {
+        Ok(token) => {
+            info!(
+                "Successfully updated token with network_id {} and address {}",
+                network_id, address_str
+            );
+            Ok(HttpResponse::Ok().json(token))
+        }
+        Err(e) => {
+            error!(
+                "Failed to update token with network_id {} and address {}: {}",
+                network_id, address_str, e
+            );
+            if e.to_string().contains("Invalid") {
+                Err(ApiError::BadRequest(e.to_string()))
+            } else if e.to_string().contains("not found") {
+                Err(ApiError::NotFound(format!(
+                    "Token with network_id {} and address {} not found",
+                    network_id, address_str
+                )))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to update token: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
+
+/// POST /tokens/network/{network_id}/refresh - Re-fetches missing token metadata on-chain
+/// Requires API key authentication via X-API-Key header
+///
+/// # Arguments
+/// * `_api_key` - API key from X-API-Key header (validated by extractor)
+/// * `db_service` - MongoDB service
+/// * `path` - Path parameters containing network_id
+/// * `query` - Query parameters with the maximum number of tokens to refresh
+///
+/// # Returns
+/// JSON object of RefreshTokensResponse with the refreshed, failed and remaining tokens
+pub async fn refresh_tokens_handler(
+    _api_key: ApiKey,
+    db_service: web::Data<MongoDbService>,
+    path: web::Path<u64>,
+    query: web::Query<RefreshTokensQuery>,
+) -> Result<HttpResponse, ApiError> {
+    let network_id = path.into_inner();
+    info!(
+        "Handling POST /tokens/network/{}/refresh request",
+        network_id
+    );
+
+    match TokenService::refresh_tokens(&db_service, network_id, query.into_inner()).await {
+        Ok(result) => {
+            info!(
+                "Refreshed {} tokens on network {}, {} failed, {} remaining",
+                result.refreshed,
+                network_id,
+                result.failed.len(),
+                result.remaining
+            );
+            Ok(HttpResponse::Ok().json(result))
+        }
+        Err(e) => {
+            error!("Failed to refresh tokens on network {}: {}", network_id, e);
+            if e.to_string().contains("not found") {
+                Err(ApiError::NotFound(format!(
+                    "Network with chain_id {} not found",
+                    network_id
+                )))
+            } else {
+                Err(ApiError::DatabaseError(format!(
+                    "Failed to refresh tokens: {}",
+                    e
+                )))
+            }
+        }
+    }
+}
+
 /// DELETE /tokens/network/{network_id}/address/{address} - Soft deletes a token
 /// Requires API key authentication via X-API-Key header
 pub async fn delete_token_by_address_handler(
-    _api_key: ApiKey,
+    api_key: ApiKey,
     db: web::Data<Database>,
     path: web::Path<(u64, String)>,
 ) -> Result<HttpResponse, ApiError> {
@@ -165,7 +359,7 @@ pub async fn delete_token_by_address_handler(
         }
     };
 
-    match TokenService::delete_token(&db, network_id, &address).await {
+    match TokenService::delete_token(&db, &api_key.name, network_id, &address).await {
         Ok(()) => {
             info!(
                 "Successfully soft deleted token with network_id {} and address {}",
@@ -195,9 +389,9 @@ pub async fn delete_token_by_address_handler(
 
 /// DELETE /tokens/network/{network_id}/address/{address}/hard - Hard deletes a token (permanently removes from database)
 /// Only works on tokens that are already soft-deleted
-/// Requires API key authentication via X-API-Key header
+/// Requires an API key with the hard_delete scope via X-API-Key header
 pub async fn hard_delete_token_handler(
-    _api_key: ApiKey,
+    api_key: HardDeleteApiKey,
     db: web::Data<Database>,
     path: web::Path<(u64, String)>,
 ) -> Result<HttpResponse, ApiError> {
@@ -218,7 +412,7 @@ pub async fn hard_delete_token_handler(
         }
     };
 
-    match TokenService::hard_delete_token(&db, network_id, &address).await {
+    match TokenService::hard_delete_token(&db, &api_key.0.name, network_id, &address).await {
         Ok(()) => {
             info!(
                 "Successfully hard deleted token with network_id: {}, address: {}",
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+use crate::services::{Event, EventTopic};
+
+/// Query parameters for GET /ws, the initial subscription
+#[derive(Debug, Deserialize)]
+pub struct WsQuery {
+    pub topics: Option<String>, // comma-separated topics, e.g. "pool,indexer"
+    pub network_ids: Option<String>, // comma-separated chain IDs, all networks when left out
+}
+
+/// Message sent by a WebSocket client
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// Add topics, and replace the network filter when `network_ids` is set
+    /// (an empty list removes the filter)
+    Subscribe {
+        topics: Vec<EventTopic>,
+        network_ids: Option<Vec<u64>>,
+    },
+    /// Remove topics
+    Unsubscribe { topics: Vec<EventTopic> },
+}
+
+/// Message sent to a WebSocket client
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    /// Current subscription, after every subscribe or unsubscribe
+    Subscribed {
+        topics: Vec<EventTopic>,
+        network_ids: Option<Vec<u64>>,
+    },
+    Event(Event),
+    /// The client fell behind and `missed` events were dropped, it should reload its data
+    Lagged {
+        missed: u64,
+    },
+    Error {
+        message: String,
+    },
+}
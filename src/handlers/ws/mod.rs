@@ -0,0 +1,6 @@
+pub mod dto;
+pub mod session;
+pub mod ws;
+
+pub use dto::*;
+pub use ws::*;
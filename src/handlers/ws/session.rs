@@ -0,0 +1,171 @@
+use actix::{Actor, ActorContext, AsyncContext, StreamHandler};
+use actix_web_actors::ws;
+use futures::Stream;
+use log::{debug, warn};
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::{
+    handlers::ws::dto::{ClientMessage, ServerMessage},
+    services::{Event, EventBus, EventTopic},
+};
+
+/// How often the client is pinged
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Connections without any message or pong for this long are closed
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(45);
+
+/// What the event bus delivers to a session
+enum Delivery {
+    Event(Event),
+    Lagged(u64),
+}
+
+/// Stream of the events published on the bus from now on
+fn deliveries(receiver: broadcast::Receiver<Event>) -> impl Stream<Item = Delivery> {
+    futures::stream::unfold(receiver, |mut receiver| async move {
+        match receiver.recv().await {
+            Ok(event) => Some((Delivery::Event(event), receiver)),
+            Err(RecvError::Lagged(missed)) => Some((Delivery::Lagged(missed), receiver)),
+            Err(RecvError::Closed) => None,
+        }
+    })
+}
+
+/// WebSocket connection of a dashboard client, forwarding the events of its topics
+pub struct WsSession {
+    topics: HashSet<EventTopic>,
+    network_ids: Option<Vec<u64>>,
+    last_heartbeat: Instant,
+}
+
+impl WsSession {
+    pub fn new(topics: HashSet<EventTopic>, network_ids: Option<Vec<u64>>) -> Self {
+        Self {
+            topics,
+            network_ids,
+            last_heartbeat: Instant::now(),
+        }
+    }
+
+    fn send(&self, ctx: &mut ws::WebsocketContext<Self>, message: &ServerMessage) {
+        match serde_json::to_string(message) {
+            Ok(text) => ctx.text(text),
+            Err(e) => warn!("Failed to serialize WebSocket message: {}", e),
+        }
+    }
+
+    fn send_subscription(&self, ctx: &mut ws::WebsocketContext<Self>) {
+        let mut topics: Vec<EventTopic> = self.topics.iter().copied().collect();
+        topics.sort_by_key(|topic| topic.as_str());
+        self.send(
+            ctx,
+            &ServerMessage::Subscribed {
+                topics,
+                network_ids: self.network_ids.clone(),
+            },
+        );
+    }
+
+    /// Apply a subscribe or unsubscribe message
+    fn handle_text(&mut self, text: &str, ctx: &mut ws::WebsocketContext<Self>) {
+        match serde_json::from_str::<ClientMessage>(text) {
+            Ok(ClientMessage::Subscribe {
+                topics,
+                network_ids,
+            }) => {
+                self.topics.extend(topics);
+                if let Some(network_ids) = network_ids {
+                    self.network_ids = (!network_ids.is_empty()).then_some(network_ids);
+                }
+                self.send_subscription(ctx);
+            }
+            Ok(ClientMessage::Unsubscribe { topics }) => {
+                for topic in topics {
+                    self.topics.remove(&topic);
+                }
+                self.send_subscription(ctx);
+            }
+            Err(e) => self.send(
+                ctx,
+                &ServerMessage::Error {
+                    message: format!("Invalid message: {}", e),
+                },
+            ),
+        }
+    }
+
+    /// Ping the client and close the connection once it stops answering
+    fn heartbeat(&self, ctx: &mut ws::WebsocketContext<Self>) {
+        ctx.run_interval(HEARTBEAT_INTERVAL, |session, ctx| {
+            if Instant::now().duration_since(session.last_heartbeat) > CLIENT_TIMEOUT {
+                debug!("WebSocket client timed out, closing the connection");
+                ctx.stop();
+                return;
+            }
+            ctx.ping(b"");
+        });
+    }
+}
+
+impl Actor for WsSession {
+    type Context = ws::WebsocketContext<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        self.heartbeat(ctx);
+        ctx.add_stream(deliveries(EventBus::global().subscribe()));
+        self.send_subscription(ctx);
+    }
+}
+
+impl StreamHandler<Delivery> for WsSession {
+    fn handle(&mut self, delivery: Delivery, ctx: &mut Self::Context) {
+        match delivery {
+            Delivery::Event(event) => {
+                if self.topics.contains(&event.topic)
+                    && event.matches_networks(self.network_ids.as_deref())
+                {
+                    self.send(ctx, &ServerMessage::Event(event));
+                }
+            }
+            Delivery::Lagged(missed) => self.send(ctx, &ServerMessage::Lagged { missed }),
+        }
+    }
+
+    fn finished(&mut self, ctx: &mut Self::Context) {
+        ctx.stop();
+    }
+}
+
+impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for WsSession {
+    fn handle(&mut self, message: Result<ws::Message, ws::ProtocolError>, ctx: &mut Self::Context) {
+        let message = match message {
+            Ok(message) => message,
+            Err(e) => {
+                warn!("WebSocket protocol error: {}", e);
+                ctx.stop();
+                return;
+            }
+        };
+
+        self.last_heartbeat = Instant::now();
+        match message {
+            ws::Message::Ping(payload) => ctx.pong(&payload),
+            ws::Message::Pong(_) => {}
+            ws::Message::Text(text) => self.handle_text(&text, ctx),
+            ws::Message::Binary(_) => self.send(
+                ctx,
+                &ServerMessage::Error {
+                    message: "Invalid message: binary messages are not supported".to_string(),
+                },
+            ),
+            ws::Message::Close(reason) => {
+                ctx.close(reason);
+                ctx.stop();
+            }
+            ws::Message::Continuation(_) | ws::Message::Nop => {}
+        }
+    }
+}
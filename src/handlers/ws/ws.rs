@@ -0,0 +1,69 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use actix_web_actors::ws;
+use log::{error, info};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use crate::{
+    errors::ApiError,
+    handlers::ws::{dto::WsQuery, session::WsSession},
+    services::EventTopic,
+};
+
+/// Parse the initial subscription of GET /ws
+fn parse_subscription(query: &WsQuery) -> anyhow::Result<(HashSet<EventTopic>, Option<Vec<u64>>)> {
+    let topics = match &query.topics {
+        Some(topics) => topics
+            .split(',')
+            .map(str::trim)
+            .filter(|topic| !topic.is_empty())
+            .map(EventTopic::from_str)
+            .collect::<anyhow::Result<HashSet<_>>>()?,
+        None => HashSet::new(),
+    };
+
+    let network_ids = match &query.network_ids {
+        Some(network_ids) => Some(
+            network_ids
+                .split(',')
+                .map(str::trim)
+                .filter(|network_id| !network_id.is_empty())
+                .map(|network_id| {
+                    network_id
+                        .parse::<u64>()
+                        .map_err(|e| anyhow::anyhow!("Invalid network ID '{}': {}", network_id, e))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?,
+        ),
+        None => None,
+    };
+
+    Ok((topics, network_ids.filter(|ids| !ids.is_empty())))
+}
+
+/// GET /ws - Opens a WebSocket receiving the change events of the subscribed topics
+///
+/// # Arguments
+/// * `req` - The upgrade request
+/// * `stream` - The request payload, the WebSocket frames
+/// * `query` - Query parameters with the initial topics and network filter
+///
+/// # Returns
+/// The `101 Switching Protocols` response of the WebSocket handshake
+pub async fn ws_handler(
+    req: HttpRequest,
+    stream: web::Payload,
+    query: web::Query<WsQuery>,
+) -> Result<HttpResponse, ApiError> {
+    info!("Handling GET /ws request");
+
+    let (topics, network_ids) = parse_subscription(&query).map_err(|e| {
+        error!("Invalid WebSocket subscription: {}", e);
+        ApiError::BadRequest(e.to_string())
+    })?;
+
+    ws::start(WsSession::new(topics, network_ids), &req, stream).map_err(|e| {
+        error!("WebSocket handshake failed: {}", e);
+        ApiError::BadRequest(format!("WebSocket handshake failed: {}", e))
+    })
+}
@@ -16,12 +16,18 @@ use log::LevelFilter;
 use config::Config;
 use database::service::MongoDbService;
 use routes::configure_routes;
+use services::{IndexerManager, NotificationHandler, PriceRefresher, RpcHealthMonitor};
+use std::sync::Arc;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
     #[arg(long, default_value = "info")]
     log_level: String,
+
+    /// Apply the pending database migrations and create the indexes, then exit
+    #[arg(long)]
+    migrate_only: bool,
 }
 
 #[actix_web::main]
@@ -43,18 +49,44 @@ async fn main() -> std::io::Result<()> {
     info!("Starting Arbitrage Bot API...");
     info!("Configuration loaded: {:?}", config);
 
-    // Initialize database service
+    // Initialize database service, applies the pending migrations and creates the indexes
     let db_service = MongoDbService::new(&config.database)
         .await
         .expect("Failed to initialize database service");
 
+    if args.migrate_only {
+        info!("Database migrations complete, exiting (--migrate-only)");
+        return Ok(());
+    }
+
     let db = db_service.get_client().database();
 
-    // Start the background indexer in a separate task
-    // let mut indexer = SimpleIndexer::new();
-    // tokio::spawn(async move {
-    //     indexer.start().await;
-    // });
+    // Notifications (Telegram, webhooks), a no-op when no sink is configured
+    let notification_handler = Arc::new(NotificationHandler::from_config(
+        &config,
+        db_service.clone(),
+    ));
+    if notification_handler.is_configured() {
+        info!(
+            "Notifications enabled for: {}",
+            notification_handler.notifier_names().join(", ")
+        );
+    } else {
+        info!("No notification sink configured, notifications are disabled");
+    }
+
+    // Start a pool indexer for every active network
+    let indexer_manager = IndexerManager::new(db_service.clone(), notification_handler.clone());
+    indexer_manager
+        .start_all()
+        .await
+        .expect("Failed to start indexers");
+
+    // Refresh the profit token prices of every network in the background
+    PriceRefresher::new(db_service.clone(), config.prices.refresh_interval_secs).start();
+
+    // Check the RPC and WebSocket endpoints of every network in the background
+    RpcHealthMonitor::new(db_service.clone(), &config.rpc_health).start();
 
     // Build bind address from config
     let bind_addr = format!("{}:{}", config.server.host, config.server.port);
@@ -113,7 +145,10 @@ async fn main() -> std::io::Result<()> {
 
         App::new()
             .app_data(web::Data::new(db.clone()))
+            .app_data(web::Data::new(db_service.clone()))
             .app_data(web::Data::new(std::sync::Arc::new(config.clone())))
+            .app_data(web::Data::new(indexer_manager.clone()))
+            .app_data(web::Data::from(notification_handler.clone()))
             .wrap(cors)
             .wrap(Logger::default())
             .configure(configure_routes)
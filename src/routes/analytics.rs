@@ -0,0 +1,8 @@
+use actix_web::web;
+
+use crate::handlers::analytics::{get_profit_handler, get_top_paths_handler};
+
+pub fn configure_analytics_routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/analytics/profit", web::get().to(get_profit_handler))
+        .route("/analytics/top-paths", web::get().to(get_top_paths_handler));
+}
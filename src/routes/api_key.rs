@@ -0,0 +1,11 @@
+use actix_web::web;
+
+use crate::handlers::api_key::{
+    create_api_key_handler, get_api_keys_handler, revoke_api_key_handler,
+};
+
+pub fn configure_api_key_routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/api-keys", web::get().to(get_api_keys_handler))
+        .route("/api-keys", web::post().to(create_api_key_handler))
+        .route("/api-keys/{id}", web::delete().to(revoke_api_key_handler));
+}
@@ -0,0 +1,7 @@
+use actix_web::web;
+
+use crate::handlers::audit::get_audit_log_handler;
+
+pub fn configure_audit_routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/audit", web::get().to(get_audit_log_handler));
+}
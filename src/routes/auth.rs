@@ -0,0 +1,173 @@
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    http::Method,
+    middleware::Next,
+    web, Error, HttpMessage,
+};
+use log::error;
+use std::sync::Arc;
+
+use crate::config::{Access, AuthConfig, Config};
+use crate::database::models::ApiKeyScope;
+use crate::errors::ApiError;
+use crate::handlers::config::auth::ApiKey;
+
+/// Prefix of the routes the middleware is wrapped around
+pub const API_PREFIX: &str = "/api/v1";
+
+/// Whether `path` matches `pattern`
+/// `*` matches exactly one segment, a trailing `**` matches any number of segments
+fn path_matches(pattern: &str, path: &str) -> bool {
+    let pattern: Vec<&str> = pattern.trim_matches('/').split('/').collect();
+    let path: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+    for (i, segment) in pattern.iter().enumerate() {
+        if *segment == "**" && i == pattern.len() - 1 {
+            return true;
+        }
+        match path.get(i) {
+            Some(part) if *segment == "*" || segment == part => {}
+            _ => return false,
+        }
+    }
+
+    pattern.len() == path.len()
+}
+
+/// Access required for `method` on `path` (relative to `/api/v1`)
+fn required_access(auth: &AuthConfig, method: &Method, path: &str) -> Access {
+    if let Some(rule) = auth.rules.iter().find(|rule| {
+        path_matches(&rule.path, path)
+            && (rule.methods.is_empty()
+                || rule
+                    .methods
+                    .iter()
+                    .any(|m| m.eq_ignore_ascii_case(method.as_str())))
+    }) {
+        return rule.access;
+    }
+
+    if path_matches("/health", path) || *method == Method::OPTIONS {
+        Access::Public
+    } else if path_matches("/api-keys/**", path) {
+        Access::Admin
+    } else if path_matches("/export", path) {
+        // Bundles carry every network's RPC and WebSocket URLs
+        Access::Admin
+    } else if path_matches("/audit", path) && auth.read == Access::Public {
+        // Audit entries carry whole records, RPC URLs with their provider keys included
+        Access::Read
+    } else if *method == Method::DELETE && path.trim_end_matches('/').ends_with("/hard") {
+        auth.hard_delete
+    } else if *method == Method::GET || *method == Method::HEAD {
+        auth.read
+    } else {
+        auth.write
+    }
+}
+
+fn required_scope(access: Access) -> Option<ApiKeyScope> {
+    match access {
+        Access::Public => None,
+        Access::Read => Some(ApiKeyScope::Read),
+        Access::Write => Some(ApiKeyScope::Write),
+        Access::HardDelete => Some(ApiKeyScope::HardDelete),
+        Access::Admin => Some(ApiKeyScope::Admin),
+    }
+}
+
+/// Middleware enforcing the `[auth]` policy on every `/api/v1` route
+///
+/// The authenticated key is stored in the request extensions, where the `ApiKey`
+/// extractors of the handlers pick it up instead of looking it up again.
+pub async fn auth_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let config = req
+        .app_data::<web::Data<Arc<Config>>>()
+        .cloned()
+        .ok_or_else(|| {
+            error!("Config not found in app data");
+            ApiError::InternalError
+        })?;
+
+    let path = req.path().strip_prefix(API_PREFIX).unwrap_or(req.path());
+    let access = required_access(&config.auth, req.method(), path);
+    if let Some(scope) = required_scope(access) {
+        let api_key = ApiKey::authenticate(req.request(), scope).await?;
+        req.extensions_mut().insert(api_key);
+    }
+
+    next.call(req).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AuthRule;
+
+    #[test]
+    fn test_path_matches() {
+        assert!(path_matches("/pools", "/pools"));
+        assert!(path_matches("/pools/*/hard", "/pools/abc/hard"));
+        assert!(!path_matches("/pools/*/hard", "/pools/abc"));
+        assert!(!path_matches("/pools", "/pools/abc"));
+        assert!(path_matches("/api-keys/**", "/api-keys"));
+        assert!(path_matches("/api-keys/**", "/api-keys/abc/def"));
+        assert!(!path_matches("/api-keys/**", "/api-key"));
+    }
+
+    #[test]
+    fn test_required_access() {
+        let mut auth = AuthConfig::default();
+        assert_eq!(
+            required_access(&auth, &Method::GET, "/pools"),
+            Access::Public
+        );
+        assert_eq!(
+            required_access(&auth, &Method::POST, "/pools"),
+            Access::Write
+        );
+        assert_eq!(
+            required_access(&auth, &Method::DELETE, "/pools/abc/hard"),
+            Access::HardDelete
+        );
+        assert_eq!(
+            required_access(&auth, &Method::GET, "/api-keys"),
+            Access::Admin
+        );
+        assert_eq!(required_access(&auth, &Method::GET, "/audit"), Access::Read);
+        assert_eq!(
+            required_access(&auth, &Method::GET, "/export"),
+            Access::Admin
+        );
+
+        auth.read = Access::Read;
+        auth.rules.push(AuthRule {
+            path: "/config".to_string(),
+            methods: vec!["put".to_string()],
+            access: Access::Admin,
+        });
+        assert_eq!(
+            required_access(&auth, &Method::GET, "/health"),
+            Access::Public
+        );
+        assert_eq!(required_access(&auth, &Method::GET, "/pools"), Access::Read);
+        assert_eq!(
+            required_access(&auth, &Method::PUT, "/config"),
+            Access::Admin
+        );
+        assert_eq!(
+            required_access(&auth, &Method::GET, "/config"),
+            Access::Read
+        );
+
+        auth.read = Access::Admin;
+        assert_eq!(
+            required_access(&auth, &Method::GET, "/audit"),
+            Access::Admin
+        );
+    }
+}
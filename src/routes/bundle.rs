@@ -0,0 +1,14 @@
+use actix_web::web;
+
+use crate::handlers::bundle::{export_handler, import_handler};
+
+/// Largest bundle accepted by POST /import, bundles carry every pool of every network
+const MAX_BUNDLE_SIZE: usize = 32 * 1024 * 1024;
+
+pub fn configure_bundle_routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/export", web::get().to(export_handler)).service(
+        web::resource("/import")
+            .app_data(web::JsonConfig::default().limit(MAX_BUNDLE_SIZE))
+            .route(web::post().to(import_handler)),
+    );
+}
@@ -0,0 +1,29 @@
+use actix_web::web;
+
+use crate::handlers::config_snapshot::{
+    create_config_snapshot_handler, diff_config_snapshots_handler, get_config_snapshot_handler,
+    get_config_snapshots_handler, restore_config_snapshot_handler,
+};
+
+pub fn configure_config_snapshot_routes(cfg: &mut web::ServiceConfig) {
+    cfg.route(
+        "/config/snapshots",
+        web::post().to(create_config_snapshot_handler),
+    )
+    .route(
+        "/config/snapshots",
+        web::get().to(get_config_snapshots_handler),
+    )
+    .route(
+        "/config/snapshots/{version}",
+        web::get().to(get_config_snapshot_handler),
+    )
+    .route(
+        "/config/snapshots/{version}/diff/{other}",
+        web::get().to(diff_config_snapshots_handler),
+    )
+    .route(
+        "/config/snapshots/{version}/restore",
+        web::post().to(restore_config_snapshot_handler),
+    );
+}
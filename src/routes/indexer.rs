@@ -0,0 +1,26 @@
+use actix_web::web;
+
+use crate::handlers::indexer::{
+    backfill_indexer_handler, get_indexer_handler, get_indexers_handler, pause_indexer_handler,
+    resume_indexer_handler,
+};
+
+pub fn configure_indexer_routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/indexers", web::get().to(get_indexers_handler))
+        .route(
+            "/indexers/network/{network_id}",
+            web::get().to(get_indexer_handler),
+        )
+        .route(
+            "/indexers/network/{network_id}/pause",
+            web::post().to(pause_indexer_handler),
+        )
+        .route(
+            "/indexers/network/{network_id}/resume",
+            web::post().to(resume_indexer_handler),
+        )
+        .route(
+            "/indexers/network/{network_id}/backfill",
+            web::post().to(backfill_indexer_handler),
+        );
+}
@@ -1,8 +1,22 @@
+pub mod analytics;
+pub mod api_key;
+pub mod audit;
+pub mod auth;
+pub mod bundle;
 pub mod config;
+pub mod config_snapshot;
+pub mod indexer;
 pub mod network;
+pub mod opportunity;
 pub mod path;
 pub mod pool;
+pub mod price;
+pub mod profit_token;
 pub mod routes;
+pub mod rpc_health;
+pub mod search;
+pub mod sync;
 pub mod token;
+pub mod ws;
 
 pub use routes::*;
@@ -0,0 +1,19 @@
+use actix_web::web;
+
+use crate::handlers::opportunity::{
+    create_opportunity_handler, get_opportunities_handler, get_opportunity_by_id_handler,
+    update_opportunity_handler,
+};
+
+pub fn configure_opportunity_routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/opportunities", web::get().to(get_opportunities_handler))
+        .route("/opportunities", web::post().to(create_opportunity_handler))
+        .route(
+            "/opportunities/{id}",
+            web::get().to(get_opportunity_by_id_handler),
+        )
+        .route(
+            "/opportunities/{id}",
+            web::put().to(update_opportunity_handler),
+        );
+}
@@ -3,7 +3,8 @@ use actix_web::web;
 use crate::handlers::path::{
     create_path_handler, delete_path_handler, get_path_by_id_handler,
     get_paths_by_anchor_token_handler, get_paths_by_chain_id_handler, get_paths_handler,
-    hard_delete_path_handler, undelete_path_handler, update_path_handler,
+    hard_delete_path_handler, optimize_path_handler, simulate_path_handler, undelete_path_handler,
+    update_path_handler,
 };
 
 pub fn configure_path_routes(cfg: &mut web::ServiceConfig) {
@@ -16,6 +17,14 @@ pub fn configure_path_routes(cfg: &mut web::ServiceConfig) {
             "/paths/{id}/undelete",
             web::post().to(undelete_path_handler),
         )
+        .route(
+            "/paths/{id}/simulate",
+            web::post().to(simulate_path_handler),
+        )
+        .route(
+            "/paths/{id}/optimize",
+            web::post().to(optimize_path_handler),
+        )
         .route(
             "/paths/{id}/hard",
             web::delete().to(hard_delete_path_handler),
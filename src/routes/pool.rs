@@ -2,8 +2,8 @@ use actix_web::web;
 
 use crate::handlers::pool::{
     count_pools_by_network_id_handler, create_pool_handler, delete_pool_handler,
-    get_pool_by_address_handler, get_pools_by_network_id_handler, get_pools_handler,
-    hard_delete_pool_handler, update_pool_handler,
+    get_pool_by_address_handler, get_pool_state_handler, get_pools_by_network_id_handler,
+    get_pools_handler, hard_delete_pool_handler, update_pool_handler,
 };
 
 pub fn configure_pool_routes(cfg: &mut web::ServiceConfig) {
@@ -23,6 +23,10 @@ pub fn configure_pool_routes(cfg: &mut web::ServiceConfig) {
             "/pools/network/{network_id}/address/{address}",
             web::get().to(get_pool_by_address_handler),
         )
+        .route(
+            "/pools/network/{network_id}/address/{address}/state",
+            web::get().to(get_pool_state_handler),
+        )
         .route(
             "/pools/network/{network_id}/count",
             web::get().to(count_pools_by_network_id_handler),
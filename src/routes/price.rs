@@ -0,0 +1,14 @@
+use actix_web::web;
+
+use crate::handlers::price::{get_prices_handler, get_token_price_handler};
+
+pub fn configure_price_routes(cfg: &mut web::ServiceConfig) {
+    cfg.route(
+        "/prices/network/{network_id}",
+        web::get().to(get_prices_handler),
+    )
+    .route(
+        "/prices/network/{network_id}/token/{address}",
+        web::get().to(get_token_price_handler),
+    );
+}
@@ -0,0 +1,29 @@
+use actix_web::web;
+
+use crate::handlers::profit_token::{
+    create_profit_token_handler, delete_profit_token_handler, get_profit_token_handler,
+    get_profit_tokens_handler, update_profit_token_handler,
+};
+
+pub fn configure_profit_token_routes(cfg: &mut web::ServiceConfig) {
+    cfg.route(
+        "/profit-tokens",
+        web::post().to(create_profit_token_handler),
+    )
+    .route(
+        "/profit-tokens/network/{network_id}",
+        web::get().to(get_profit_tokens_handler),
+    )
+    .route(
+        "/profit-tokens/network/{network_id}/address/{address}",
+        web::get().to(get_profit_token_handler),
+    )
+    .route(
+        "/profit-tokens/network/{network_id}/address/{address}",
+        web::put().to(update_profit_token_handler),
+    )
+    .route(
+        "/profit-tokens/network/{network_id}/address/{address}",
+        web::delete().to(delete_profit_token_handler),
+    );
+}
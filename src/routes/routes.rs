@@ -1,8 +1,25 @@
-use actix_web::{web, HttpResponse, Result};
+use actix_web::{middleware::from_fn, web, HttpResponse, Result};
 
 use crate::routes::{
-    config::configure_config_routes, network::configure_network_routes,
-    path::configure_path_routes, pool::configure_pool_routes, token::configure_token_routes,
+    analytics::configure_analytics_routes,
+    api_key::configure_api_key_routes,
+    audit::configure_audit_routes,
+    auth::{auth_middleware, API_PREFIX},
+    bundle::configure_bundle_routes,
+    config::configure_config_routes,
+    config_snapshot::configure_config_snapshot_routes,
+    indexer::configure_indexer_routes,
+    network::configure_network_routes,
+    opportunity::configure_opportunity_routes,
+    path::configure_path_routes,
+    pool::configure_pool_routes,
+    price::configure_price_routes,
+    profit_token::configure_profit_token_routes,
+    rpc_health::configure_rpc_health_routes,
+    search::configure_search_routes,
+    sync::configure_sync_routes,
+    token::configure_token_routes,
+    ws::configure_ws_routes,
 };
 
 /// Health check endpoint
@@ -12,12 +29,26 @@ async fn health_check() -> Result<HttpResponse> {
 
 pub fn configure_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(
-        web::scope("/api/v1")
+        web::scope(API_PREFIX)
+            .wrap(from_fn(auth_middleware))
             .route("/health", web::get().to(health_check))
+            .configure(configure_analytics_routes)
+            .configure(configure_api_key_routes)
+            .configure(configure_audit_routes)
+            .configure(configure_bundle_routes)
             .configure(configure_config_routes)
+            .configure(configure_config_snapshot_routes)
+            .configure(configure_indexer_routes)
             .configure(configure_network_routes)
+            .configure(configure_opportunity_routes)
             .configure(configure_path_routes)
             .configure(configure_pool_routes)
-            .configure(configure_token_routes),
+            .configure(configure_price_routes)
+            .configure(configure_profit_token_routes)
+            .configure(configure_rpc_health_routes)
+            .configure(configure_search_routes)
+            .configure(configure_sync_routes)
+            .configure(configure_token_routes)
+            .configure(configure_ws_routes),
     );
 }
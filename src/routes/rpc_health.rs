@@ -0,0 +1,10 @@
+use actix_web::web;
+
+use crate::handlers::rpc_health::get_rpc_health_handler;
+
+pub fn configure_rpc_health_routes(cfg: &mut web::ServiceConfig) {
+    cfg.route(
+        "/networks/{chain_id}/rpc-health",
+        web::get().to(get_rpc_health_handler),
+    );
+}
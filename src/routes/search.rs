@@ -0,0 +1,7 @@
+use actix_web::web;
+
+use crate::handlers::search::search_handler;
+
+pub fn configure_search_routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/search", web::get().to(search_handler));
+}
@@ -0,0 +1,7 @@
+use actix_web::web;
+
+use crate::handlers::sync::sync_handler;
+
+pub fn configure_sync_routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/sync", web::get().to(sync_handler));
+}
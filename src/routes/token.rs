@@ -1,13 +1,14 @@
 use actix_web::web;
 
 use crate::handlers::token::{
-    count_tokens_by_network_id_handler, delete_token_by_address_handler,
+    count_tokens_by_network_id_handler, create_token_handler, delete_token_by_address_handler,
     get_token_by_address_handler, get_tokens_by_network_id_handler, get_tokens_handler,
-    hard_delete_token_handler,
+    hard_delete_token_handler, refresh_tokens_handler, update_token_handler,
 };
 
 pub fn configure_token_routes(cfg: &mut web::ServiceConfig) {
     cfg.route("/tokens", web::get().to(get_tokens_handler))
+        .route("/tokens", web::post().to(create_token_handler))
         .route(
             "/tokens/network/{network_id}",
             web::get().to(get_tokens_by_network_id_handler),
@@ -16,6 +17,10 @@ pub fn configure_token_routes(cfg: &mut web::ServiceConfig) {
             "/tokens/network/{network_id}/address/{address}",
             web::get().to(get_token_by_address_handler),
         )
+        .route(
+            "/tokens/network/{network_id}/address/{address}",
+            web::put().to(update_token_handler),
+        )
         .route(
             "/tokens/network/{network_id}/address/{address}",
             web::delete().to(delete_token_by_address_handler),
@@ -27,5 +32,9 @@ pub fn configure_token_routes(cfg: &mut web::ServiceConfig) {
         .route(
             "/tokens/network/{network_id}/count",
             web::get().to(count_tokens_by_network_id_handler),
+        )
+        .route(
+            "/tokens/network/{network_id}/refresh",
+            web::post().to(refresh_tokens_handler),
         );
 }
@@ -0,0 +1,278 @@
+use chrono::Utc;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+use tokio::sync::broadcast;
+
+use crate::database::models::{AuditAction, AuditEntity};
+
+/// Number of events kept for a subscriber that falls behind before it misses some
+const EVENT_BUFFER: usize = 1024;
+
+/// Process-wide event bus, shared by the services publishing events and the WebSocket sessions
+static EVENT_BUS: LazyLock<EventBus> = LazyLock::new(|| EventBus::new(EVENT_BUFFER));
+
+/// What an event is about, WebSocket clients subscribe to topics
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum EventTopic {
+    Network,
+    Pool,
+    Path,
+    Token,
+    ProfitToken,
+    Config,
+    Indexer,
+    Opportunity,
+}
+
+impl EventTopic {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Network => "network",
+            Self::Pool => "pool",
+            Self::Path => "path",
+            Self::Token => "token",
+            Self::ProfitToken => "profit_token",
+            Self::Config => "config",
+            Self::Indexer => "indexer",
+            Self::Opportunity => "opportunity",
+        }
+    }
+}
+
+impl fmt::Display for EventTopic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for EventTopic {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "network" => Ok(Self::Network),
+            "pool" => Ok(Self::Pool),
+            "path" => Ok(Self::Path),
+            "token" => Ok(Self::Token),
+            "profit_token" => Ok(Self::ProfitToken),
+            "config" => Ok(Self::Config),
+            "indexer" => Ok(Self::Indexer),
+            "opportunity" => Ok(Self::Opportunity),
+            _ => Err(anyhow::anyhow!("Invalid topic '{}'", s)),
+        }
+    }
+}
+
+impl From<AuditEntity> for EventTopic {
+    fn from(entity: AuditEntity) -> Self {
+        match entity {
+            AuditEntity::Network => Self::Network,
+            AuditEntity::Pool => Self::Pool,
+            AuditEntity::Path => Self::Path,
+            AuditEntity::Token => Self::Token,
+            AuditEntity::ProfitToken => Self::ProfitToken,
+            AuditEntity::Config | AuditEntity::ConfigSnapshot => Self::Config,
+        }
+    }
+}
+
+/// A change published to the WebSocket clients subscribed to its topic
+#[derive(Debug, Serialize, Clone)]
+pub struct Event {
+    pub topic: EventTopic,
+    pub action: String, // create, update, delete, undelete, hard_delete or progress
+    pub entity_id: String,
+    pub network_ids: Vec<u64>, // networks the event concerns, empty for global changes
+    pub data: Value,           // the document after the change, null once removed
+    pub timestamp: u64,
+}
+
+impl Event {
+    pub fn new(
+        topic: EventTopic,
+        action: &str,
+        entity_id: &str,
+        network_ids: Vec<u64>,
+        data: Value,
+    ) -> Self {
+        Self {
+            topic,
+            action: action.to_string(),
+            entity_id: entity_id.to_string(),
+            network_ids,
+            data: normalize_id(data),
+            timestamp: Utc::now().timestamp() as u64,
+        }
+    }
+
+    /// Event of a change recorded in the audit log, `before`/`after` are the serialized
+    /// documents, `null` before a creation and after a hard delete
+    pub fn from_change(
+        action: AuditAction,
+        entity: AuditEntity,
+        entity_id: &str,
+        before: &Value,
+        after: &Value,
+    ) -> Self {
+        let document = if after.is_null() { before } else { after };
+        Self::new(
+            entity.into(),
+            action.as_str(),
+            entity_id,
+            document_network_ids(document),
+            after.clone(),
+        )
+    }
+
+    /// Whether a subscriber restricted to `network_ids` (all networks if `None`) gets the event
+    pub fn matches_networks(&self, network_ids: Option<&[u64]>) -> bool {
+        match network_ids {
+            Some(network_ids) if !self.network_ids.is_empty() => self
+                .network_ids
+                .iter()
+                .any(|network_id| network_ids.contains(network_id)),
+            _ => true,
+        }
+    }
+}
+
+/// Networks of a serialized document: `network_id` of pools and tokens, `chain_id` of
+/// networks and the `chain_id` of every leg of paths
+fn document_network_ids(document: &Value) -> Vec<u64> {
+    if let Some(network_id) = document
+        .get("network_id")
+        .or_else(|| document.get("chain_id"))
+        .and_then(Value::as_u64)
+    {
+        return vec![network_id];
+    }
+
+    let mut network_ids: Vec<u64> = document
+        .get("paths")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|leg| leg.get("chain_id").and_then(Value::as_u64))
+        .collect();
+    network_ids.sort_unstable();
+    network_ids.dedup();
+    network_ids
+}
+
+/// Rename the MongoDB `_id` of a serialized document to the `id` hex string of the API responses
+fn normalize_id(mut data: Value) -> Value {
+    if let Some(object) = data.as_object_mut() {
+        if let Some(id) = object.remove("_id") {
+            let id = id.get("$oid").cloned().unwrap_or(id);
+            object.insert("id".to_string(), id);
+        }
+    }
+    data
+}
+
+/// Broadcast channel of the events, every subscriber receives every event
+pub struct EventBus {
+    sender: broadcast::Sender<Event>,
+}
+
+impl EventBus {
+    fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    /// The event bus of the process
+    pub fn global() -> &'static EventBus {
+        &EVENT_BUS
+    }
+
+    /// Publish an event, dropped when nobody is subscribed
+    pub fn publish(&self, event: Event) {
+        if self.sender.send(event).is_err() {
+            debug!("No event subscriber, event dropped");
+        }
+    }
+
+    /// Receive the events published from now on
+    pub fn subscribe(&self) -> broadcast::Receiver<Event> {
+        self.sender.subscribe()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_topic_round_trip() {
+        for topic in [
+            EventTopic::Network,
+            EventTopic::ProfitToken,
+            EventTopic::Indexer,
+            EventTopic::Opportunity,
+        ] {
+            assert_eq!(EventTopic::from_str(topic.as_str()).unwrap(), topic);
+        }
+        assert!(EventTopic::from_str("api_key").is_err());
+    }
+
+    #[test]
+    fn test_event_normalizes_id() {
+        let data = json!({ "_id": { "$oid": "507f1f77bcf86cd799439011" }, "chain_id": 1 });
+        let event = Event::new(EventTopic::Network, "update", "1", vec![1], data);
+        assert_eq!(
+            event.data,
+            json!({ "id": "507f1f77bcf86cd799439011", "chain_id": 1 })
+        );
+    }
+
+    #[test]
+    fn test_event_from_change() {
+        let before = json!({ "network_id": 1, "address": "0xabc", "decimals": null });
+        let event = Event::from_change(
+            AuditAction::HardDelete,
+            AuditEntity::Token,
+            "1:0xabc",
+            &before,
+            &Value::Null,
+        );
+        assert_eq!(event.topic, EventTopic::Token);
+        assert_eq!(event.action, "hard_delete");
+        assert_eq!(event.network_ids, vec![1]);
+        assert!(event.data.is_null());
+
+        let path =
+            json!({ "paths": [{ "chain_id": 8453 }, { "chain_id": 1 }, { "chain_id": 8453 }] });
+        let event = Event::from_change(
+            AuditAction::Create,
+            AuditEntity::Path,
+            "id",
+            &Value::Null,
+            &path,
+        );
+        assert_eq!(event.network_ids, vec![1, 8453]);
+    }
+
+    #[test]
+    fn test_event_matches_networks() {
+        let event = Event::new(
+            EventTopic::Opportunity,
+            "create",
+            "id",
+            vec![1, 8453],
+            Value::Null,
+        );
+        assert!(event.matches_networks(None));
+        assert!(event.matches_networks(Some(&[8453])));
+        assert!(!event.matches_networks(Some(&[10])));
+
+        let event = Event::new(EventTopic::Config, "update", "id", vec![], Value::Null);
+        assert!(event.matches_networks(Some(&[10])));
+    }
+}
@@ -0,0 +1,170 @@
+use anyhow::{anyhow, Result};
+use log::{error, info};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use crate::database::MongoDbService;
+use crate::services::{IndexerState, NotificationHandler, PoolIndexer};
+
+/// Maximum number of blocks a single backfill request may cover
+pub const MAX_BACKFILL_BLOCKS: u64 = 1_000_000;
+
+/// Keeps track of the background pool indexers, one per network
+///
+/// Cheap to clone, all clones share the same set of indexers.
+#[derive(Clone)]
+pub struct IndexerManager {
+    db_service: MongoDbService,
+    notification_handler: Arc<NotificationHandler>,
+    indexers: Arc<RwLock<HashMap<u64, Arc<IndexerState>>>>,
+}
+
+impl IndexerManager {
+    pub fn new(db_service: MongoDbService, notification_handler: Arc<NotificationHandler>) -> Self {
+        Self {
+            db_service,
+            notification_handler,
+            indexers: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Start a pool indexer for every network that is not deleted
+    pub async fn start_all(&self) -> Result<()> {
+        let networks = self.db_service.get_network_repo().find_all().await?;
+        for network in networks.into_iter().filter(|n| n.deleted_at.is_none()) {
+            if let Err(e) = self.start_network(network.chain_id).await {
+                error!(
+                    "Failed to start pool indexer for network {}: {}",
+                    network.chain_id, e
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Start (or resume) the pool indexer of a network
+    ///
+    /// The run is marked as started before the indexer task is spawned, so a pause sent right
+    /// after stops it instead of being refused as already paused.
+    pub async fn start_network(&self, chain_id: u64) -> Result<()> {
+        let indexer = self.create_indexer(chain_id).await?;
+        let state = self.get_or_insert_state(chain_id);
+        let generation = state
+            .try_start_run()
+            .ok_or_else(|| anyhow!("Indexer for network {} is already running", chain_id))?;
+        let mut indexer = indexer.with_state(state);
+        tokio::spawn(async move {
+            indexer.run(generation).await;
+        });
+
+        info!("Pool indexer for network {} started", chain_id);
+        Ok(())
+    }
+
+    /// Pause the pool indexer of a network, it stops after the batch in progress
+    pub fn pause_network(&self, chain_id: u64) -> Result<()> {
+        let state = self
+            .get_state(chain_id)
+            .ok_or_else(|| anyhow!("Indexer for network {} not found", chain_id))?;
+        if !state.is_running() {
+            return Err(anyhow!(
+                "Indexer for network {} is already paused",
+                chain_id
+            ));
+        }
+
+        state.stop();
+        info!("Pool indexer for network {} paused", chain_id);
+        Ok(())
+    }
+
+    /// Index `from_block..=to_block` of a network in the background
+    ///
+    /// Runs next to the regular indexer and does not move its checkpoint.
+    pub async fn backfill(&self, chain_id: u64, from_block: u64, to_block: u64) -> Result<()> {
+        if from_block > to_block {
+            return Err(anyhow!(
+                "Invalid block range: from_block {} is greater than to_block {}",
+                from_block,
+                to_block
+            ));
+        }
+        if to_block - from_block + 1 > MAX_BACKFILL_BLOCKS {
+            return Err(anyhow!(
+                "Invalid block range: at most {} blocks can be backfilled at once",
+                MAX_BACKFILL_BLOCKS
+            ));
+        }
+
+        let indexer = self.create_indexer(chain_id).await?;
+        let chain_head = indexer.chain_head().await?;
+        if to_block > chain_head {
+            return Err(anyhow!(
+                "Invalid block range: to_block {} is beyond the chain head {}",
+                to_block,
+                chain_head
+            ));
+        }
+
+        let state = self.get_or_insert_state(chain_id);
+        if !state.try_start_backfill(from_block, to_block) {
+            return Err(anyhow!(
+                "A backfill is already in progress for network {}",
+                chain_id
+            ));
+        }
+
+        let indexer = indexer.with_state(state);
+        tokio::spawn(async move {
+            // Outcome is recorded in the backfill status
+            let _ = indexer.backfill(from_block, to_block).await;
+        });
+
+        Ok(())
+    }
+
+    /// Runtime state of the indexer of a network, if one was ever started
+    pub fn get_state(&self, chain_id: u64) -> Option<Arc<IndexerState>> {
+        self.indexers.read().unwrap().get(&chain_id).cloned()
+    }
+
+    /// Runtime state of all known indexers, keyed by network chain_id
+    pub fn get_states(&self) -> Vec<(u64, Arc<IndexerState>)> {
+        let mut states: Vec<(u64, Arc<IndexerState>)> = self
+            .indexers
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(chain_id, state)| (*chain_id, state.clone()))
+            .collect();
+        states.sort_by_key(|(chain_id, _)| *chain_id);
+        states
+    }
+
+    fn get_or_insert_state(&self, chain_id: u64) -> Arc<IndexerState> {
+        self.indexers
+            .write()
+            .unwrap()
+            .entry(chain_id)
+            .or_insert_with(|| Arc::new(IndexerState::new()))
+            .clone()
+    }
+
+    async fn create_indexer(&self, chain_id: u64) -> Result<PoolIndexer> {
+        let network = self
+            .db_service
+            .find_network(chain_id)
+            .await?
+            .ok_or_else(|| anyhow!("Network with chain_id {} not found", chain_id))?;
+        if network.deleted_at.is_some() {
+            return Err(anyhow!("Network with chain_id {} is deleted", chain_id));
+        }
+
+        PoolIndexer::new(
+            network,
+            self.db_service.clone(),
+            self.notification_handler.clone(),
+        )
+    }
+}
@@ -1,5 +1,11 @@
 pub mod indexer;
+pub mod manager;
+pub mod pool_indexer;
 pub mod simple_indexer;
+pub mod status;
 
 pub use indexer::*;
+pub use manager::*;
+pub use pool_indexer::*;
 pub use simple_indexer::*;
+pub use status::*;
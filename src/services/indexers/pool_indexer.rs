@@ -1,332 +1,521 @@
 use alloy::eips::BlockNumberOrTag;
 use alloy::primitives::Address;
-use alloy::providers::{Provider, ProviderBuilder};
+use alloy::providers::{DynProvider, Provider};
+use alloy::rpc::types::Log;
 use alloy::sol_types::SolEvent;
-use alloy::transports::http::reqwest::Url;
-use futures::StreamExt;
+use anyhow::{anyhow, Result};
 use log::{debug, error, info, warn};
-use mongodb::bson::doc;
-use mongodb::Database;
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::time;
 
-use crate::contract::{IAlgebraFactory, IUniswapV2Factory, IUniswapV3Factory};
-use crate::models::Network;
-use crate::notification_handler::{NotificationHandler, NotificationType};
-use crate::utils::{address_to_string, fetch_events};
+use crate::bot::contracts::{
+    IAlgebraFactory, IUniswapV2Factory, IUniswapV3Factory, IVeloPoolFactory,
+};
+use crate::bot::models::pool::base::POOL_CREATED_TOPICS;
+use crate::bot::providers::{fetch_events, try_create_provider};
+use crate::database::models::checkpoint::POOL_INDEXER;
+use crate::database::models::{address_to_string, Network, Pool};
+use crate::database::MongoDbService;
+use crate::handlers::token::service::TokenService;
+use crate::services::{
+    Event, EventBus, EventTopic, Indexer, IndexerState, NotificationHandler, NotificationType,
+};
 
 const DEFAULT_BLOCKS_PER_BATCH: u64 = 1000;
-const DEFAULT_INDEXING_INTERVAL_SECONDS: u64 = 300; // 5 minutes
+const DEFAULT_WAIT_TIME_FETCH_MS: u64 = 1000;
 
+/// Pool announced by a pool creation log, with its two tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreatedPool {
+    pub pool: Address,
+    pub token0: Address,
+    pub token1: Address,
+}
+
+/// Background indexer that discovers newly created pools on a single network
+///
+/// Scans PairCreated/PoolCreated logs (see `POOL_CREATED_TOPICS`) in batches of
+/// `max_blocks_per_batch` blocks and persists every discovered pool address.
+/// Progress is checkpointed after each batch so a restart resumes where it left off.
 pub struct PoolIndexer {
-    db: Arc<Database>,
+    network: Network,
+    db_service: MongoDbService,
     notification_handler: Arc<NotificationHandler>,
-    indexing_interval_seconds: u64,
-    blocks_per_batch: u64,
+    provider: Arc<DynProvider>,
+    state: Arc<IndexerState>,
+    generation: u64,
+    last_indexed_block: Option<u64>,
 }
 
 impl PoolIndexer {
+    /// Create a pool indexer for the given network
     pub fn new(
-        db: Arc<Database>,
+        network: Network,
+        db_service: MongoDbService,
         notification_handler: Arc<NotificationHandler>,
-        indexing_interval_seconds: Option<u64>,
-        blocks_per_batch: Option<u64>,
-    ) -> Self {
-        Self {
-            db,
+    ) -> Result<Self> {
+        let provider = Arc::new(try_create_provider(&network.rpcs).map_err(|e| {
+            anyhow!(
+                "Network {} ({}) has no usable RPC: {}",
+                network.name,
+                network.chain_id,
+                e
+            )
+        })?);
+
+        Ok(Self {
+            network,
+            db_service,
             notification_handler,
-            indexing_interval_seconds: indexing_interval_seconds
-                .unwrap_or(DEFAULT_INDEXING_INTERVAL_SECONDS),
-            blocks_per_batch: blocks_per_batch.unwrap_or(DEFAULT_BLOCKS_PER_BATCH),
+            provider,
+            state: Arc::new(IndexerState::new()),
+            generation: 0,
+            last_indexed_block: None,
+        })
+    }
+
+    /// Report progress into an existing shared state instead of a fresh one
+    pub fn with_state(mut self, state: Arc<IndexerState>) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Index until the run of the given generation is stopped or replaced by a newer one
+    /// The run must have been started on the shared state, see `IndexerState::try_start_run`
+    pub async fn run(&mut self, generation: u64) {
+        info!(
+            "Starting pool indexer for network {} ({})",
+            self.network.name, self.network.chain_id
+        );
+        self.generation = generation;
+
+        while self.state.should_run(self.generation) {
+            match self.run_once().await {
+                Ok(()) => self.state.record_error(None),
+                Err(e) => {
+                    error!(
+                        "Error indexing pools on network {}: {}",
+                        self.network.chain_id, e
+                    );
+                    self.state.record_error(Some(e.to_string()));
+                }
+            }
+            tokio::time::sleep(self.wait_time()).await;
         }
+
+        info!("Pool indexer for network {} stopped", self.network.chain_id);
     }
 
-    pub fn from_config(
-        db: Arc<Database>,
-        notification_handler: Arc<NotificationHandler>,
-        config: &crate::config::Config,
-    ) -> Self {
-        Self::new(
-            db,
-            notification_handler,
-            Some(config.pool_indexer.interval_seconds),
-            Some(config.pool_indexer.blocks_per_batch),
-        )
+    /// Chain ID of the network this indexer scans
+    pub fn network_id(&self) -> u64 {
+        self.network.chain_id
     }
 
-    pub async fn start(&self) {
-        info!("Starting pool indexer service...");
+    fn blocks_per_batch(&self) -> u64 {
+        if self.network.max_blocks_per_batch == 0 {
+            DEFAULT_BLOCKS_PER_BATCH
+        } else {
+            self.network.max_blocks_per_batch
+        }
+    }
 
-        let db = self.db.clone();
-        let notification_handler = self.notification_handler.clone();
-        let indexing_interval = self.indexing_interval_seconds;
-        let blocks_per_batch = self.blocks_per_batch;
+    fn wait_time(&self) -> Duration {
+        if self.network.wait_time_fetch == 0 {
+            Duration::from_millis(DEFAULT_WAIT_TIME_FETCH_MS)
+        } else {
+            Duration::from_millis(self.network.wait_time_fetch)
+        }
+    }
 
-        tokio::spawn(async move {
-            let mut interval = time::interval(Duration::from_secs(indexing_interval));
+    /// Reload the network so edits to `max_blocks_per_batch` / `wait_time_fetch` are honored
+    async fn refresh_network(&mut self) -> Result<()> {
+        match self.db_service.find_network(self.network.chain_id).await? {
+            Some(network) => {
+                self.network = network;
+                Ok(())
+            }
+            None => Err(anyhow!(
+                "Network with chain_id {} not found",
+                self.network.chain_id
+            )),
+        }
+    }
 
-            loop {
-                interval.tick().await;
-                info!("Running pool indexing task...");
+    /// Resolve the block to resume from: in-memory progress, then the stored checkpoint,
+    /// then the current head (which becomes the initial checkpoint)
+    async fn resume_block(&mut self, latest_block: u64) -> Result<u64> {
+        if let Some(block) = self.last_indexed_block {
+            return Ok(block);
+        }
 
-                // Get all networks
-                let networks = match get_all_networks(&db).await {
-                    Ok(networks) => networks,
-                    Err(e) => {
-                        error!("Failed to get networks: {}", e);
-                        continue;
-                    }
-                };
-
-                for mut network in networks {
-                    if network.enable_pool_indexer.is_none()
-                        || !network.enable_pool_indexer.unwrap()
-                    {
-                        info!(
-                            "Pool indexer is not enabled for network: {} ({})",
-                            network.name, network.chain_id
-                        );
-                        continue;
-                    }
-                    if let Some(rpc) = &network.rpc {
-                        info!(
-                            "Indexing pools for network: {} ({})",
-                            network.name, network.chain_id
-                        );
-                        // Create provider
-                        let provider =
-                            ProviderBuilder::new().connect_http(Url::parse(rpc).unwrap());
-
-                        // Get latest block number
-                        let latest_block = match provider.get_block_number().await {
-                            Ok(block) => block,
-                            Err(e) => {
-                                error!(
-                                    "Failed to get latest block for network {}: {}",
-                                    network.chain_id, e
-                                );
-                                continue;
-                            }
-                        };
-                        if network.last_pool_index_block.is_none() {
-                            network
-                                .update_last_pool_index_block(&db, latest_block)
-                                .await;
-                        }
-                        // Determine start block
-                        let start_block = network.last_pool_index_block.unwrap_or(latest_block);
-
-                        // If we're already at the latest block, skip
-                        if start_block >= latest_block {
-                            debug!(
-                                "Network {} already at latest block ({}), skipping",
-                                network.chain_id, latest_block
-                            );
-                            continue;
-                        }
-
-                        let mut pool_addresses: Vec<Address> = Vec::new();
-
-                        // Process blocks in batches
-                        let mut current_block = start_block;
-                        while current_block < latest_block {
-                            let end_block =
-                                std::cmp::min(current_block + blocks_per_batch, latest_block);
-
-                            info!(
-                                "Indexing pools for network {} from block {} to {}",
-                                network.chain_id, current_block, end_block
-                            );
-
-                            // Process all pool creation events
-                            match index_all_pools(
-                                &notification_handler,
-                                &provider,
-                                &network,
-                                current_block,
-                                end_block,
-                            )
-                            .await
-                            {
-                                Ok(pool_addresses_result) => {
-                                    pool_addresses.extend(pool_addresses_result);
-                                }
-                                Err(e) => {
-                                    error!(
-                                        "Error indexing pools on network {}: {}",
-                                        network.chain_id, e
-                                    );
-                                    // retry in 1 second
-                                    tokio::time::sleep(Duration::from_secs(1)).await;
-                                    continue;
-                                }
-                            }
-
-                            // Update last indexed block
-                            match update_last_pool_index_block(&db, network.chain_id, end_block)
-                                .await
-                            {
-                                Ok(_) => {
-                                    debug!(
-                                        "Updated last_pool_index_block for network {} to {}",
-                                        network.chain_id, end_block
-                                    );
-                                }
-                                Err(e) => {
-                                    error!(
-                                        "Failed to update last_pool_index_block for network {}: {}",
-                                        network.chain_id, e
-                                    );
-                                }
-                            }
-
-                            current_block = end_block;
-                        }
-                        if !pool_addresses.is_empty() {
-                            info!(
-                                "New {} pools detected on {} ({})",
-                                pool_addresses.len(),
-                                network.name,
-                                network.chain_id,
-                            );
-                            send_new_pool_notification(
-                                &notification_handler,
-                                &network.name,
-                                network.chain_id,
-                                pool_addresses,
-                            )
-                            .await;
-                        } else {
-                            info!(
-                                "No new pools detected on {} ({})",
-                                network.name, network.chain_id
-                            );
-                        }
-                        network
-                            .update_last_pool_index_block(&db, latest_block)
-                            .await;
-                    } else {
-                        warn!("Network {} has no RPC URL configured", network.chain_id);
-                    }
-                }
+        let checkpoint_repo = self.db_service.get_checkpoint_repo();
+        let block = match checkpoint_repo
+            .get_last_block(self.network.chain_id, POOL_INDEXER)
+            .await?
+        {
+            Some(block) => {
+                info!(
+                    "Resuming pool indexer for network {} from block {}",
+                    self.network.chain_id, block
+                );
+                block
+            }
+            None => {
+                checkpoint_repo
+                    .upsert(self.network.chain_id, POOL_INDEXER, latest_block)
+                    .await?;
+                latest_block
+            }
+        };
+
+        self.last_indexed_block = Some(block);
+        Ok(block)
+    }
+
+    /// Scan all blocks between the last indexed block and the chain head
+    async fn run_once(&mut self) -> Result<()> {
+        self.refresh_network().await?;
+        if self.network.deleted_at.is_some() {
+            debug!(
+                "Network {} is deleted, skipping pool indexing",
+                self.network.chain_id
+            );
+            return Ok(());
+        }
+
+        let latest_block = self.provider.get_block_number().await?;
+        let start_block = self.resume_block(latest_block).await?;
+        self.state.record_progress(start_block, latest_block);
+        if start_block >= latest_block {
+            debug!(
+                "Network {} already at latest block ({}), skipping",
+                self.network.chain_id, latest_block
+            );
+            return Ok(());
+        }
 
-                info!("Pool indexing task completed");
+        let blocks_per_batch = self.blocks_per_batch();
+        let mut current_block = start_block;
+        while current_block < latest_block && self.state.should_run(self.generation) {
+            let from_block = current_block + 1;
+            let to_block = std::cmp::min(current_block + blocks_per_batch, latest_block);
+
+            debug!(
+                "Indexing pools for network {} from block {} to {}",
+                self.network.chain_id, from_block, to_block
+            );
+
+            let created_pools = self.index_range(from_block, to_block).await?;
+            let new_pools = self.save_pools(&created_pools).await?;
+            if !new_pools.is_empty() {
+                self.notification_handler
+                    .send_notification(NotificationType::NewPool {
+                        network_name: self.network.name.clone(),
+                        network_id: self.network.chain_id,
+                        pool_addresses: new_pools,
+                    })
+                    .await;
             }
-        });
 
-        info!("Pool indexer service started");
+            self.db_service
+                .get_checkpoint_repo()
+                .upsert(self.network.chain_id, POOL_INDEXER, to_block)
+                .await?;
+
+            current_block = to_block;
+            self.last_indexed_block = Some(current_block);
+            self.state.record_progress(current_block, latest_block);
+            self.publish_progress("progress", current_block, latest_block);
+        }
+
+        Ok(())
+    }
+
+    /// Latest block number of the network
+    pub async fn chain_head(&self) -> Result<u64> {
+        self.provider.get_block_number().await.map_err(|e| {
+            anyhow!(
+                "RPC request failed on network {}: {}",
+                self.network.chain_id,
+                e
+            )
+        })
     }
-}
 
-async fn get_all_networks(
-    db: &Database,
-) -> Result<Vec<Network>, Box<dyn std::error::Error + Send + Sync>> {
-    let networks_collection = db.collection::<Network>("networks");
-    let mut cursor = networks_collection.find(None, None).await?;
+    /// Index a historical block range without touching the checkpoint
+    ///
+    /// The caller marks the backfill as started (`IndexerState::try_start_backfill`),
+    /// progress and outcome are then reported through the shared state.
+    pub async fn backfill(&self, from_block: u64, to_block: u64) -> Result<()> {
+        info!(
+            "Backfilling pools for network {} from block {} to {}",
+            self.network.chain_id, from_block, to_block
+        );
 
-    let mut networks = Vec::new();
-    while let Some(network_result) = cursor.next().await {
-        if let Ok(network) = network_result {
-            networks.push(network);
+        let result = self.backfill_range(from_block, to_block).await;
+        self.state
+            .finish_backfill(result.as_ref().err().map(|e| e.to_string()));
+
+        match &result {
+            Ok(()) => info!(
+                "Backfill for network {} finished ({} to {})",
+                self.network.chain_id, from_block, to_block
+            ),
+            Err(e) => error!(
+                "Backfill for network {} failed: {}",
+                self.network.chain_id, e
+            ),
         }
+        result
     }
 
-    Ok(networks)
-}
+    async fn backfill_range(&self, from_block: u64, to_block: u64) -> Result<()> {
+        let blocks_per_batch = self.blocks_per_batch();
+        let mut batch_start = from_block;
+        while batch_start <= to_block {
+            let batch_end = std::cmp::min(batch_start + blocks_per_batch - 1, to_block);
 
-async fn update_last_pool_index_block(
-    db: &Database,
-    network_id: u64,
-    block_number: u64,
-) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    let networks_collection = db.collection::<Network>("networks");
-    networks_collection
-        .update_one(
-            doc! { "chain_id": network_id as i64 },
-            doc! { "$set": { "last_pool_index_block": block_number as i64 } },
-            None,
+            let created_pools = self.index_range(batch_start, batch_end).await?;
+            self.save_pools(&created_pools).await?;
+
+            self.state.record_backfill_progress(batch_end);
+            self.publish_progress("backfill_progress", batch_end, to_block);
+            batch_start = batch_end + 1;
+        }
+
+        Ok(())
+    }
+
+    /// Persist discovered pools, skipping the ones already known, and fetch the metadata of
+    /// the tokens of the new pools in the background
+    /// Returns the addresses of the pools that were not known yet
+    async fn save_pools(&self, created_pools: &[CreatedPool]) -> Result<Vec<String>> {
+        if created_pools.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let inserted = self
+            .db_service
+            .get_pool_repo()
+            .insert_new(
+                created_pools
+                    .iter()
+                    .map(|created| {
+                        Pool::new(self.network.chain_id, address_to_string(&created.pool))
+                    })
+                    .collect(),
+            )
+            .await?;
+
+        info!(
+            "Indexed {} new pools on {} ({}), {} already known",
+            inserted.len(),
+            self.network.name,
+            self.network.chain_id,
+            created_pools.len() - inserted.len()
+        );
+        if !inserted.is_empty() {
+            let mut tokens: Vec<Address> = Vec::new();
+            for created in created_pools
+                .iter()
+                .filter(|created| inserted.contains(&address_to_string(&created.pool)))
+            {
+                for token in [created.token0, created.token1] {
+                    if !tokens.contains(&token) {
+                        tokens.push(token);
+                    }
+                }
+            }
+            TokenService::spawn_enrich_tokens(
+                self.db_service.clone(),
+                self.network.chain_id,
+                tokens,
+            );
+
+            EventBus::global().publish(Event::new(
+                EventTopic::Pool,
+                "index",
+                &self.network.chain_id.to_string(),
+                vec![self.network.chain_id],
+                serde_json::json!({
+                    "network_id": self.network.chain_id,
+                    "addresses": inserted,
+                }),
+            ));
+        }
+        Ok(inserted)
+    }
+
+    /// Publish the progress of the indexer to the WebSocket clients
+    fn publish_progress(&self, action: &str, last_block: u64, to_block: u64) {
+        EventBus::global().publish(Event::new(
+            EventTopic::Indexer,
+            action,
+            POOL_INDEXER,
+            vec![self.network.chain_id],
+            serde_json::json!({
+                "network_id": self.network.chain_id,
+                "indexer": POOL_INDEXER,
+                "last_block": last_block,
+                "to_block": to_block,
+            }),
+        ));
+    }
+
+    /// Fetch pool creation events in the given block range and return the created pools
+    async fn index_range(&self, from_block: u64, to_block: u64) -> Result<Vec<CreatedPool>> {
+        let logs = fetch_events(
+            self.provider.clone(),
+            vec![],
+            POOL_CREATED_TOPICS.to_vec(),
+            BlockNumberOrTag::Number(from_block),
+            BlockNumberOrTag::Number(to_block),
         )
         .await?;
-    Ok(())
+
+        let mut created_pools: Vec<CreatedPool> = Vec::new();
+        for log in logs {
+            match decode_pool_created(&log) {
+                Ok(Some(created)) => {
+                    if !created_pools.iter().any(|known| known.pool == created.pool) {
+                        created_pools.push(created);
+                    }
+                }
+                Ok(None) => continue,
+                Err(e) => warn!(
+                    "Failed to decode pool creation log on network {}: {}",
+                    self.network.chain_id, e
+                ),
+            }
+        }
+
+        Ok(created_pools)
+    }
 }
 
-async fn index_all_pools(
-    notification_handler: &NotificationHandler,
-    provider: &impl Provider,
-    network: &Network,
-    from_block: u64,
-    to_block: u64,
-) -> Result<Vec<Address>, Box<dyn std::error::Error + Send + Sync>> {
-    // Define all the known pool creation event signatures
-    let event_signatures = vec![
-        // UniswapV2 PairCreated(address indexed token0, address indexed token1, address pair, uint256)
-        IUniswapV2Factory::PairCreated::SIGNATURE_HASH,
-        // UniswapV3 PoolCreated(address indexed token0, address indexed token1, uint24 indexed fee, int24 tickSpacing, address pool)
-        IUniswapV3Factory::PoolCreated::SIGNATURE_HASH,
-        // Algebra Pool(address indexed token0, address indexed token1, address pool)
-        IAlgebraFactory::Pool::SIGNATURE_HASH,
-    ];
-
-    let logs = fetch_events(
-        provider,
-        vec![],
-        event_signatures,
-        BlockNumberOrTag::Number(from_block as u64),
-        BlockNumberOrTag::Number(to_block as u64),
-    )
-    .await?;
-
-    let mut pool_addresses = Vec::new();
-    for log in logs {
-        let topic0 = log.topic0().unwrap();
-        match topic0 {
-            &IUniswapV2Factory::PairCreated::SIGNATURE_HASH => {
-                let swap_data: IUniswapV2Factory::PairCreated = log.log_decode()?.inner.data;
-                pool_addresses.push(swap_data.pair);
+/// Decode a pool creation log into the created pool and its tokens
+///
+/// Returns `Ok(None)` if the log is not one of `POOL_CREATED_TOPICS`.
+pub fn decode_pool_created(log: &Log) -> Result<Option<CreatedPool>> {
+    let Some(topic0) = log.topic0() else {
+        return Ok(None);
+    };
+
+    let created = match *topic0 {
+        IUniswapV2Factory::PairCreated::SIGNATURE_HASH => {
+            let event = log
+                .log_decode::<IUniswapV2Factory::PairCreated>()?
+                .inner
+                .data;
+            CreatedPool {
+                pool: event.pair,
+                token0: event.token0,
+                token1: event.token1,
             }
-            &IUniswapV3Factory::PoolCreated::SIGNATURE_HASH => {
-                let swap_data: IUniswapV3Factory::PoolCreated = log.log_decode()?.inner.data;
-                pool_addresses.push(swap_data.pool);
+        }
+        IUniswapV3Factory::PoolCreated::SIGNATURE_HASH => {
+            let event = log
+                .log_decode::<IUniswapV3Factory::PoolCreated>()?
+                .inner
+                .data;
+            CreatedPool {
+                pool: event.pool,
+                token0: event.token0,
+                token1: event.token1,
             }
-            &IAlgebraFactory::Pool::SIGNATURE_HASH => {
-                let swap_data: IAlgebraFactory::Pool = log.log_decode()?.inner.data;
-                pool_addresses.push(swap_data.pool);
+        }
+        IAlgebraFactory::Pool::SIGNATURE_HASH => {
+            let event = log.log_decode::<IAlgebraFactory::Pool>()?.inner.data;
+            CreatedPool {
+                pool: event.pool,
+                token0: event.token0,
+                token1: event.token1,
             }
-            _ => continue,
         }
+        IVeloPoolFactory::PoolCreated::SIGNATURE_HASH => {
+            let event = log
+                .log_decode::<IVeloPoolFactory::PoolCreated>()?
+                .inner
+                .data;
+            CreatedPool {
+                pool: event.pool,
+                token0: event.token0,
+                token1: event.token1,
+            }
+        }
+        _ => return Ok(None),
+    };
+
+    Ok(Some(created))
+}
+
+#[async_trait::async_trait]
+impl Indexer for PoolIndexer {
+    async fn start(&mut self) {
+        let generation = self.state.start_run();
+        self.run(generation).await;
+    }
+
+    async fn stop(&mut self) {
+        self.state.stop();
     }
 
-    Ok(pool_addresses)
+    async fn is_running(&self) -> bool {
+        self.state.is_running()
+    }
 }
 
-async fn send_new_pool_notification(
-    notification_handler: &NotificationHandler,
-    network_name: &str,
-    network_id: u64,
-    pool_addresses: Vec<Address>,
-) {
-    // Log the new pool
-    info!(
-        "New {} pools detected on {} ({}): {}",
-        pool_addresses.len(),
-        network_name,
-        network_id,
-        pool_addresses
-            .iter()
-            .map(address_to_string)
-            .collect::<Vec<String>>()
-            .join(", "),
-    );
-
-    // Send notification via Telegram
-    if notification_handler.is_configured() {
-        notification_handler
-            .send_notification(NotificationType::NewPool {
-                network_name: network_name.to_string(),
-                network_id,
-                pool_addresses: pool_addresses.iter().map(address_to_string).collect(),
-            })
-            .await;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{address, aliases::U24, Log as PrimitiveLog, U256};
+
+    fn rpc_log(inner: PrimitiveLog) -> Log {
+        Log {
+            inner,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_decode_pool_created() {
+        let factory = address!("0x1F98431c8aD98523631AE4a59f267346ea31F984");
+        let token0 = address!("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+        let token1 = address!("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+        let pool = address!("0x88e6A0c2dDD26FEEb64F039a2c41296FcB3f5640");
+        let created = CreatedPool {
+            pool,
+            token0,
+            token1,
+        };
+
+        let v3_event = IUniswapV3Factory::PoolCreated {
+            token0,
+            token1,
+            fee: U24::from(500),
+            tickSpacing: alloy::primitives::aliases::I24::try_from(10).unwrap(),
+            pool,
+        };
+        let log = rpc_log(PrimitiveLog {
+            address: factory,
+            data: v3_event.encode_log_data(),
+        });
+        assert_eq!(decode_pool_created(&log).unwrap(), Some(created));
+
+        let v2_event = IUniswapV2Factory::PairCreated {
+            token0,
+            token1,
+            pair: pool,
+            _3: U256::from(1),
+        };
+        let log = rpc_log(PrimitiveLog {
+            address: factory,
+            data: v2_event.encode_log_data(),
+        });
+        assert_eq!(decode_pool_created(&log).unwrap(), Some(created));
+
+        // Unrelated events are ignored
+        let log = rpc_log(PrimitiveLog::empty());
+        assert_eq!(decode_pool_created(&log).unwrap(), None);
     }
 }
@@ -0,0 +1,178 @@
+use chrono::Utc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::RwLock;
+
+/// Progress of a historical backfill
+#[derive(Debug, Clone)]
+pub struct BackfillStatus {
+    pub from_block: u64,
+    pub to_block: u64,
+    pub current_block: u64,
+    pub finished: bool,
+    pub error: Option<String>,
+    pub started_at: u64,
+    pub finished_at: Option<u64>,
+}
+
+/// Snapshot of what an indexer has done so far
+#[derive(Debug, Clone, Default)]
+pub struct IndexerStatus {
+    pub current_block: Option<u64>,
+    pub chain_head: Option<u64>,
+    pub last_error: Option<String>,
+    pub last_run_at: Option<u64>,
+    pub backfill: Option<BackfillStatus>,
+}
+
+impl IndexerStatus {
+    /// Number of blocks the indexer is behind the last seen chain head
+    pub fn lag(&self) -> Option<u64> {
+        match (self.current_block, self.chain_head) {
+            (Some(current), Some(head)) => Some(head.saturating_sub(current)),
+            _ => None,
+        }
+    }
+}
+
+/// Runtime state shared between a running indexer and whoever controls it
+///
+/// Every `start_run` bumps a generation counter so a loop that was stopped and is still
+/// sleeping does not pick up again when the indexer is resumed by a newer loop.
+#[derive(Debug, Default)]
+pub struct IndexerState {
+    running: AtomicBool,
+    generation: AtomicU64,
+    status: RwLock<IndexerStatus>,
+}
+
+impl IndexerState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running.load(Ordering::SeqCst)
+    }
+
+    /// Mark the indexer as running and return the generation of the new run loop
+    pub fn start_run(&self) -> u64 {
+        self.running.store(true, Ordering::SeqCst);
+        self.generation.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    /// Mark the indexer as running unless it already is
+    /// Returns the generation of the new run loop, None when a loop is already running
+    pub fn try_start_run(&self) -> Option<u64> {
+        self.running
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .ok()?;
+        Some(self.generation.fetch_add(1, Ordering::SeqCst) + 1)
+    }
+
+    /// Whether the run loop of the given generation should keep going
+    pub fn should_run(&self, generation: u64) -> bool {
+        self.is_running() && self.generation.load(Ordering::SeqCst) == generation
+    }
+
+    pub fn stop(&self) {
+        self.running.store(false, Ordering::SeqCst);
+    }
+
+    /// Copy of the current status
+    pub fn status(&self) -> IndexerStatus {
+        self.status.read().unwrap().clone()
+    }
+
+    /// Record the progress of a completed batch
+    pub fn record_progress(&self, current_block: u64, chain_head: u64) {
+        let mut status = self.status.write().unwrap();
+        status.current_block = Some(current_block);
+        status.chain_head = Some(chain_head);
+        status.last_run_at = Some(Utc::now().timestamp() as u64);
+    }
+
+    /// Record the outcome of an indexing run (`None` clears the last error)
+    pub fn record_error(&self, error: Option<String>) {
+        let mut status = self.status.write().unwrap();
+        status.last_error = error;
+        status.last_run_at = Some(Utc::now().timestamp() as u64);
+    }
+
+    /// Mark a backfill as started, returns false if another one is still in progress
+    pub fn try_start_backfill(&self, from_block: u64, to_block: u64) -> bool {
+        let mut status = self.status.write().unwrap();
+        if status
+            .backfill
+            .as_ref()
+            .is_some_and(|backfill| !backfill.finished)
+        {
+            return false;
+        }
+
+        status.backfill = Some(BackfillStatus {
+            from_block,
+            to_block,
+            current_block: from_block,
+            finished: false,
+            error: None,
+            started_at: Utc::now().timestamp() as u64,
+            finished_at: None,
+        });
+        true
+    }
+
+    pub fn record_backfill_progress(&self, current_block: u64) {
+        if let Some(backfill) = self.status.write().unwrap().backfill.as_mut() {
+            backfill.current_block = current_block;
+        }
+    }
+
+    pub fn finish_backfill(&self, error: Option<String>) {
+        if let Some(backfill) = self.status.write().unwrap().backfill.as_mut() {
+            backfill.finished = true;
+            backfill.error = error;
+            backfill.finished_at = Some(Utc::now().timestamp() as u64);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_indexer_state() {
+        let state = IndexerState::new();
+        assert!(!state.is_running());
+        assert_eq!(state.status().lag(), None);
+
+        // A stopped loop must not resume once a newer loop has started
+        let first = state.start_run();
+        state.stop();
+        let second = state.start_run();
+        assert!(!state.should_run(first));
+        assert!(state.should_run(second));
+
+        // Starting a running indexer is refused until it is paused
+        assert_eq!(state.try_start_run(), None);
+        state.stop();
+        let third = state.try_start_run().unwrap();
+        assert!(state.should_run(third));
+
+        state.record_progress(90, 100);
+        assert_eq!(state.status().lag(), Some(10));
+
+        assert!(state.try_start_backfill(10, 20));
+        assert!(!state.try_start_backfill(30, 40));
+        state.record_backfill_progress(15);
+        state.finish_backfill(Some("rpc error".to_string()));
+
+        let backfill = state.status().backfill.unwrap();
+        assert!(backfill.finished);
+        assert_eq!(backfill.current_block, 15);
+        assert_eq!(backfill.error.as_deref(), Some("rpc error"));
+
+        // A new backfill can start once the previous one finished
+        assert!(state.try_start_backfill(30, 40));
+    }
+}
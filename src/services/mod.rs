@@ -1,3 +1,15 @@
+pub mod events;
 pub mod indexers;
+pub mod notification_handler;
+pub mod notifiers;
+pub mod price_refresher;
+pub mod rpc_health_monitor;
+#[cfg(test)]
+pub(crate) mod test_http;
 
+pub use events::*;
 pub use indexers::*;
+pub use notification_handler::*;
+pub use notifiers::*;
+pub use price_refresher::*;
+pub use rpc_health_monitor::*;
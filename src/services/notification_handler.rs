@@ -1,45 +1,29 @@
 use alloy::primitives::utils::format_units;
-use alloy::primitives::U256;
-use log::info;
-use mongodb::Database;
-use std::fs::OpenOptions;
-use std::io::Write;
-use std::sync::Arc;
-use teloxide::{
-    adaptors::throttle::{Limits, Throttle},
-    prelude::*,
-    sugar::request::RequestLinkPreviewExt,
-    types::{LinkPreviewOptions, MessageId, ThreadId},
-    Bot,
-};
-
-use crate::database;
-use crate::models::{Network, Opportunity, Token};
-
-/// Escape special characters for MarkdownV2
-fn escape_markdownv2(text: &str) -> String {
-    text.chars()
-        .map(|c| match c {
-            '_' | '[' | ']' | '(' | ')' | '~' | '`' | '>' | '#' | '+' | '-' | '=' | '|' | '{'
-            | '}' | '.' | '!' => {
-                format!("\\{}", c)
-            }
-            _ => c.to_string(),
-        })
-        .collect()
-}
-
+use alloy::primitives::{Address, U256};
+use bson::oid::ObjectId;
+use futures::future::join_all;
+use log::{debug, error, info};
+use std::str::FromStr;
+
+use crate::config::Config;
+use crate::database::models::{Network, Opportunity, OpportunityStatus, Token};
+use crate::database::MongoDbService;
+use crate::services::notifiers::{Link, Notification, Notifier, TelegramNotifier, WebhookNotifier};
+
+/// Fans notification events out to every configured sink (Telegram, webhooks)
+///
+/// Events are resolved into a `Notification` once (network names, token symbols,
+/// links), then delivered to all sinks concurrently. Without any sink configured
+/// every notification is silently dropped, so callers never need to check.
 pub struct NotificationHandler {
-    bot: Throttle<Bot>,
-    chat_id: String,
-    big_opp_thread_id: u64,
-    failed_opp_thread_id: u64,
-    new_pool_thread_id: u64,
-    db: Option<Arc<Database>>,
+    notifiers: Vec<Box<dyn Notifier>>,
+    min_profit_usd: f64,
+    dashboard_url: Option<String>,
+    db_service: MongoDbService,
 }
 
 pub enum NotificationType {
-    HighOpportunity(Opportunity),
+    HighOpportunity(Box<Opportunity>),
     NewPool {
         network_name: String,
         network_id: u64,
@@ -48,370 +32,253 @@ pub enum NotificationType {
 }
 
 impl NotificationHandler {
-    pub fn _new(
-        token: String,
-        chat_id: String,
-        big_opp_thread_id: u64,
-        failed_opp_thread_id: u64,
-        new_pool_thread_id: u64,
+    pub fn new(
+        notifiers: Vec<Box<dyn Notifier>>,
+        min_profit_usd: f64,
+        dashboard_url: Option<String>,
+        db_service: MongoDbService,
     ) -> Self {
-        let bot = Bot::new(token).throttle(Limits::default());
         Self {
-            bot,
-            chat_id,
-            big_opp_thread_id,
-            failed_opp_thread_id,
-            new_pool_thread_id,
-            db: None,
+            notifiers,
+            min_profit_usd,
+            dashboard_url: dashboard_url.map(|url| url.trim_end_matches('/').to_string()),
+            db_service,
         }
     }
 
-    pub fn with_database(
-        token: String,
-        chat_id: String,
-        big_opp_thread_id: u64,
-        failed_opp_thread_id: u64,
-        new_pool_thread_id: u64,
-        db: Arc<Database>,
-    ) -> Self {
-        let bot = Bot::new(token).throttle(Limits::default());
-        Self {
-            bot,
-            chat_id,
-            big_opp_thread_id,
-            failed_opp_thread_id,
-            new_pool_thread_id,
-            db: Some(db),
+    /// Create the handler with the Telegram bot and webhooks of the configuration
+    /// Webhooks with an invalid URL are skipped
+    pub fn from_config(config: &Config, db_service: MongoDbService) -> Self {
+        let mut notifiers: Vec<Box<dyn Notifier>> = Vec::new();
+        if let Some(telegram) = TelegramNotifier::from_config(&config.telegram) {
+            notifiers.push(Box::new(telegram));
         }
-    }
-
-    pub fn from_config(config: &crate::config::Config, db: Arc<Database>) -> Option<Self> {
-        if let (
-            Some(token),
-            Some(chat_id),
-            Some(big_opp_thread_id),
-            Some(failed_opp_thread_id),
-            Some(new_pool_thread_id),
-        ) = (
-            &config.telegram.token,
-            &config.telegram.chat_id,
-            &config.telegram.big_opp_thread_id,
-            &config.telegram.failed_opp_thread_id,
-            &config.telegram.new_pool_thread_id,
-        ) {
-            if !token.is_empty() && !chat_id.is_empty() {
-                return Some(Self::with_database(
-                    token.clone(),
-                    chat_id.clone(),
-                    big_opp_thread_id.clone(),
-                    failed_opp_thread_id.clone(),
-                    new_pool_thread_id.clone(),
-                    db,
-                ));
+        for webhook in &config.notifications.webhooks {
+            match WebhookNotifier::from_config(webhook) {
+                Ok(notifier) => notifiers.push(Box::new(notifier)),
+                Err(e) => error!("Skipping webhook notifier: {}", e),
             }
         }
-        None
+
+        Self::new(
+            notifiers,
+            config.notifications.min_profit_usd.unwrap_or(0.0),
+            config.notifications.dashboard_url.clone(),
+            db_service,
+        )
     }
 
-    // Check if the notification handler is properly configured
+    // Check if at least one notification sink is configured
     pub fn is_configured(&self) -> bool {
-        self.db.is_some()
+        !self.notifiers.is_empty()
     }
 
-    async fn get_network(&self, network_id: u64) -> Option<Network> {
-        if let Some(db) = &self.db {
-            let repo = database::NetworkRepository::new(db);
-            match repo.find_by_chain_id(network_id).await {
-                Ok(Some(network)) => return Some(network),
-                Ok(None) => {
-                    log::debug!("Network with chain_id {} not found", network_id);
-                    return None;
-                }
-                Err(err) => {
-                    log::error!("Error fetching network: {}", err);
-                    return None;
+    /// Names of the configured sinks
+    pub fn notifier_names(&self) -> Vec<&str> {
+        self.notifiers.iter().map(|n| n.name()).collect()
+    }
+
+    /// Whether an opportunity has a final outcome worth notifying
+    ///
+    /// Failed opportunities are judged on their estimated profit, the others on the
+    /// realized profit (falling back to the estimate while it is unknown).
+    fn is_high_opportunity(opportunity: &Opportunity, min_profit_usd: f64) -> bool {
+        let profit_usd = match opportunity.status {
+            OpportunityStatus::Succeeded | OpportunityStatus::PartiallySucceeded => {
+                opportunity.profit_usd.or(opportunity.estimate_profit_usd)
+            }
+            OpportunityStatus::Reverted | OpportunityStatus::Error => {
+                opportunity.estimate_profit_usd
+            }
+            OpportunityStatus::Detected | OpportunityStatus::Pending => return false,
+        };
+
+        profit_usd.unwrap_or(0.0) >= min_profit_usd
+    }
+
+    /// Notify the outcome of a stored opportunity if it is profitable enough
+    pub async fn notify_opportunity(&self, id: &ObjectId) {
+        if !self.is_configured() {
+            return;
+        }
+
+        match self.db_service.get_opportunity_repo().find_by_id(id).await {
+            Ok(Some(opportunity)) => {
+                if Self::is_high_opportunity(&opportunity, self.min_profit_usd) {
+                    self.send_notification(NotificationType::HighOpportunity(Box::new(
+                        opportunity,
+                    )))
+                    .await;
                 }
             }
+            Ok(None) => debug!("Opportunity {} not found, not notifying", id),
+            Err(e) => error!("Error fetching opportunity {}: {}", id, e),
+        }
+    }
+
+    async fn get_network(&self, network_id: u64) -> Option<Network> {
+        match self.db_service.find_network(network_id).await {
+            Ok(Some(network)) => Some(network),
+            Ok(None) => {
+                debug!("Network with chain_id {} not found", network_id);
+                None
+            }
+            Err(err) => {
+                error!("Error fetching network: {}", err);
+                None
+            }
         }
-        None
     }
 
     async fn get_token(&self, network_id: u64, token_address: &str) -> Option<Token> {
-        if let Some(db) = &self.db {
-            let repo = database::TokenRepository::new(db);
-            match repo.find_by_address(network_id, token_address).await {
-                Ok(Some(token)) => return Some(token),
-                Ok(None) => {
-                    log::debug!(
-                        "Token {} not found on network {}",
-                        token_address,
-                        network_id
-                    );
-                    return None;
-                }
-                Err(err) => {
-                    log::error!("Error fetching token: {}", err);
-                    return None;
-                }
+        let address = Address::from_str(token_address).ok()?;
+        match self.db_service.find_token(network_id, &address).await {
+            Ok(Some(token)) => Some(token),
+            Ok(None) => {
+                debug!(
+                    "Token {} not found on network {}",
+                    token_address, network_id
+                );
+                None
+            }
+            Err(err) => {
+                error!("Error fetching token: {}", err);
+                None
             }
         }
-        None
     }
 
-    pub async fn send_notification(&self, notification_type: NotificationType) {
+    /// Resolve an event into a notification the sinks can render on their own
+    async fn resolve(&self, notification_type: NotificationType) -> Notification {
         match notification_type {
             NotificationType::NewPool {
                 network_name,
                 network_id,
                 pool_addresses,
-            } => {
-                // Create message for new pool
-                let message = format!(
-                    "🆕 *New {} Pool Detected*\n\nNetwork: *{}* ({})\nPools: \n`{}`",
-                    pool_addresses.len(),
-                    network_name,
-                    network_id,
-                    pool_addresses.join("`\n`")
-                );
-
-                // Escape the message content for MarkdownV2
-                let escaped_message = escape_markdownv2(&message);
-
-                // Send the message with Markdown parsing for clickable links
-                if let Err(e) = self
-                    .bot
-                    .send_message(self.chat_id.clone(), escaped_message)
-                    .message_thread_id(ThreadId(MessageId(self.new_pool_thread_id as i32)))
-                    .disable_link_preview(true)
-                    .parse_mode(teloxide::types::ParseMode::MarkdownV2)
-                    .send()
-                    .await
-                {
-                    let error_msg = format!("Failed to send pool notification: {}", e);
-                    eprintln!("{}", &error_msg);
-
-                    // Log error to file
-                    if let Err(log_err) = log_error_to_file(&error_msg) {
-                        eprintln!("Failed to write to log file: {}", log_err);
-                    }
-
-                    if let Err(e) = self
-                        .bot
-                        .send_message(self.chat_id.clone(), "Error sending new pool notification")
-                        .message_thread_id(ThreadId(MessageId(self.failed_opp_thread_id as i32)))
-                        .disable_link_preview(true)
-                        .parse_mode(teloxide::types::ParseMode::MarkdownV2)
-                        .send()
-                        .await
-                    {
-                        let error_msg =
-                            format!("Failed to send failed new pool notification: {}", e);
-                        eprintln!("{}", &error_msg);
-
-                        // Log error to file
-                        if let Err(log_err) = log_error_to_file(&error_msg) {
-                            eprintln!("Failed to write to log file: {}", log_err);
-                        }
-                    }
-                }
-
-                info!("New pool notification sent successfully");
-            }
+            } => Notification::NewPool {
+                network_name,
+                network_id,
+                pool_addresses,
+            },
             NotificationType::HighOpportunity(opportunity) => {
-                // Get network and token information
-                let chain = self.get_network(opportunity.network_id).await;
-
-                // Get block explorer URL from network if available
-                let block_explorer = chain.as_ref().and_then(|n| n.block_explorer.clone());
-
-                let chain_name = chain
-                    .map(|n| n.name)
-                    .unwrap_or_else(|| format!("Chain #{}", opportunity.network_id));
-
-                let token_object = self
-                    .get_token(opportunity.network_id, &opportunity.profit_token)
+                let source_chain = self.get_network(opportunity.source_chain_id).await;
+                let target_chain = self.get_network(opportunity.target_chain_id).await;
+                let source_explorer = source_chain.as_ref().and_then(|n| n.block_explorer.clone());
+                let target_explorer = target_chain.as_ref().and_then(|n| n.block_explorer.clone());
+
+                // The anchor token amount is raw, in the decimals of the source chain token
+                let token = self
+                    .get_token(opportunity.source_chain_id, &opportunity.anchor_token)
                     .await;
-                let token = token_object
-                    .clone()
-                    .and_then(|t| t.symbol)
-                    .unwrap_or_else(|| "Unknown".to_string());
-                let token_decimals = token_object.and_then(|t| t.decimals).unwrap_or_else(|| 18);
-
-                // Get opportunity ID
-                let id = opportunity.id.map(|id| id.to_hex()).unwrap_or_default();
-                let error_msg = opportunity.error.as_deref().unwrap_or("Unknown error");
-
-                // Create dashboard and explorer links
-                let dashboard_url = format!("http://188.245.98.132:8080/opportunities/{}", id);
-                let explorer_url = if let Some(explorer) = block_explorer {
-                    if let Some(tx) = &opportunity.execute_tx {
-                        format!("{}/tx/{}", explorer, tx)
-                    } else if let Some(tx) = &opportunity.source_tx {
-                        format!("{}/tx/{}", explorer, tx)
-                    } else {
-                        "".to_string()
-                    }
-                } else {
-                    "".to_string()
-                };
-
-                let profit = format_units(
-                    U256::from_str_radix(
-                        opportunity.profit.unwrap_or(String::from("0")).as_ref(),
-                        10,
-                    )
-                    .unwrap_or(U256::ZERO),
-                    token_decimals,
-                )
-                .unwrap_or("0.0".to_string())
-                .parse::<f64>()
-                .unwrap_or(0.0);
-
-                let estimate_profit = opportunity.estimate_profit.unwrap_or("Unknown".to_string());
-                let estimate_profit = format_units(
-                    U256::from_str_radix(&estimate_profit, 10).unwrap_or(U256::ZERO),
+                let token_decimals = token.as_ref().and_then(|t| t.decimals).unwrap_or(18);
+                let amount_in = format_units(
+                    U256::from_str_radix(&opportunity.amount_in, 10).unwrap_or(U256::ZERO),
                     token_decimals,
                 )
                 .unwrap_or("0.0".to_string())
                 .parse::<f64>()
                 .unwrap_or(0.0);
-                let mut thread_id = self.big_opp_thread_id;
 
-                let message = match opportunity.status.as_str().to_lowercase().as_str() {
-                    "succeeded" => {
-                        format!(
-                            "🟢 *{:.4}* {} ~ $*{:.2}*\nStatus: *SUCCESS*\nNetwork: *{}*\nEstimated: *{:.4}* {} ~ $*{:.2}*\nGas: $*{:.2}*",
-                            profit,
-                            token,
-                            opportunity.profit_usd.unwrap_or(0.0),
-                            chain_name,
-                            estimate_profit,
-                            token,
-                            opportunity.estimate_profit_usd.unwrap_or(0.0),
-                            opportunity.gas_usd.unwrap_or(0.0)
-                        )
-                    }
-                    "partially_succeeded" | "partiallysucceeded" => {
-                        format!(
-                            "🟡 *{:.4}* {} ~ $*{:.2}*\nStatus: *PARTIAL*\nNetwork: *{}*\nEstimated: *{:.4}* {} ~ $*{:.2}*\nGas: $*{:.2}*",
-                            profit,
-                            token,
-                            opportunity.profit_usd.unwrap_or(0.0),
-                            chain_name,
-                            estimate_profit,
-                            token,
-                            opportunity.estimate_profit_usd.unwrap_or(0.0),
-                            opportunity.gas_usd.unwrap_or(0.0)
-                        )
-                    }
-                    "reverted" => {
-                        thread_id = self.failed_opp_thread_id;
-                        format!(
-                            "🔴*{:.4}* {} ~ $*{:.2}*\nStatus: *REVERTED*\nNetwork: *{}*\nGas: $*{:.2}*\nBlock delay: *{}* blocks",
-                            estimate_profit,
-                            token,
-                            opportunity.estimate_profit_usd.unwrap_or(0.0),
-                            chain_name,
-                            opportunity.gas_usd.unwrap_or(0.0),
-                            opportunity.execute_block_number.unwrap_or(0) - opportunity.source_block_number.unwrap_or(0)
-                        )
-                    }
-                    "error" => {
-                        thread_id = self.failed_opp_thread_id;
-                        format!(
-                            "🔴*{:.4}* {} ~ $*{:.2}*\nStatus: *ERROR*\nNetwork: *{}*",
-                            estimate_profit,
-                            token,
-                            opportunity.estimate_profit_usd.unwrap_or(0.0),
-                            chain_name,
-                        )
-                    }
-                    _ => {
-                        return;
-                    }
-                };
-
-                // Escape the message content for MarkdownV2
-                let escaped_message = escape_markdownv2(&message);
-
-                // Add error message if present
-                let mut final_message = if !error_msg.contains("Unknown error") {
-                    let escaped_error = escape_markdownv2(error_msg);
-                    escaped_message + "\n" + &escaped_error
-                } else {
-                    escaped_message
-                };
-
-                // Escape URLs for MarkdownV2 format
-                let dashboard_url_escaped = escape_markdownv2(&dashboard_url);
-                let explorer_url_escaped = if !explorer_url.is_empty() {
-                    escape_markdownv2(&explorer_url)
-                } else {
-                    String::new()
-                };
-
-                // Add links to dashboard and explorer using Telegram's MarkdownV2 link format
-                final_message += "\n[View on Dashboard](";
-                final_message += &dashboard_url_escaped;
-                final_message += ")";
-
-                if !explorer_url.is_empty() {
-                    final_message += "\n[View on Explorer](";
-                    final_message += &explorer_url_escaped;
-                    final_message += ")";
+                let mut links = Vec::new();
+                if let (Some(dashboard_url), Some(id)) = (&self.dashboard_url, opportunity.id) {
+                    links.push(Link {
+                        label: "View on Dashboard".to_string(),
+                        url: format!("{}/opportunities/{}", dashboard_url, id),
+                    });
                 }
-
-                // Send the message with Markdown parsing for clickable links
-                if let Err(e) = self
-                    .bot
-                    .send_message(self.chat_id.clone(), final_message)
-                    .message_thread_id(ThreadId(MessageId(thread_id as i32)))
-                    .disable_link_preview(true)
-                    .parse_mode(teloxide::types::ParseMode::MarkdownV2)
-                    .send()
-                    .await
-                {
-                    let error_msg = format!("Failed to send notification: {}", e);
-                    eprintln!("{}", &error_msg);
-
-                    // Log error to file
-                    if let Err(log_err) = log_error_to_file(&error_msg) {
-                        eprintln!("Failed to write to log file: {}", log_err);
+                for (label, explorer, tx) in [
+                    ("Source tx", source_explorer, &opportunity.source_tx),
+                    ("Target tx", target_explorer, &opportunity.target_tx),
+                ] {
+                    if let (Some(explorer), Some(tx)) = (explorer, tx) {
+                        links.push(Link {
+                            label: label.to_string(),
+                            url: format!("{}/tx/{}", explorer.trim_end_matches('/'), tx),
+                        });
                     }
+                }
 
-                    if let Err(e) = self
-                        .bot
-                        .send_message(self.chat_id.clone(), "Error sending notification")
-                        .message_thread_id(ThreadId(MessageId(self.failed_opp_thread_id as i32)))
-                        .disable_link_preview(true)
-                        .parse_mode(teloxide::types::ParseMode::MarkdownV2)
-                        .send()
-                        .await
-                    {
-                        let error_msg = format!("Failed to send failed notification: {}", e);
-                        eprintln!("{}", &error_msg);
-
-                        // Log error to file
-                        if let Err(log_err) = log_error_to_file(&error_msg) {
-                            eprintln!("Failed to write to log file: {}", log_err);
-                        }
-                    }
+                Notification::Opportunity {
+                    id: opportunity.id.map(|id| id.to_hex()),
+                    status: opportunity.status,
+                    source_chain_id: opportunity.source_chain_id,
+                    source_chain_name: source_chain
+                        .map(|n| n.name)
+                        .unwrap_or_else(|| format!("Chain #{}", opportunity.source_chain_id)),
+                    target_chain_id: opportunity.target_chain_id,
+                    target_chain_name: target_chain
+                        .map(|n| n.name)
+                        .unwrap_or_else(|| format!("Chain #{}", opportunity.target_chain_id)),
+                    anchor_token: opportunity.anchor_token,
+                    anchor_token_symbol: token
+                        .and_then(|t| t.symbol)
+                        .unwrap_or_else(|| "Unknown".to_string()),
+                    amount_in,
+                    profit_usd: opportunity.profit_usd,
+                    estimate_profit_usd: opportunity.estimate_profit_usd,
+                    gas_usd: opportunity.gas_usd,
+                    error: opportunity.error,
+                    links,
                 }
             }
         }
-        info!("Notification sent successfully");
     }
-}
 
-/// Log error message to a log file
-fn log_error_to_file(error_msg: &str) -> std::io::Result<()> {
-    let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
-    let log_entry = format!("[{}] {}\n", timestamp, error_msg);
+    pub async fn send_notification(&self, notification_type: NotificationType) {
+        if !self.is_configured() {
+            return;
+        }
 
-    let mut file = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open("log.txt")?;
+        let notification = self.resolve(notification_type).await;
+        let results = join_all(
+            self.notifiers
+                .iter()
+                .map(|notifier| notifier.notify(&notification)),
+        )
+        .await;
+
+        for (notifier, result) in self.notifiers.iter().zip(results) {
+            match result {
+                Ok(()) => info!("Notification sent to {}", notifier.name()),
+                Err(e) => error!("Failed to send notification to {}: {}", notifier.name(), e),
+            }
+        }
+    }
+}
 
-    file.write_all(log_entry.as_bytes())
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_high_opportunity() {
+        let mut opportunity = Opportunity::new(
+            1,
+            10,
+            None,
+            "0x0000000000000000000000000000000000000001".to_string(),
+            "1000".to_string(),
+            Some(150.0),
+            None,
+        );
+        assert!(!NotificationHandler::is_high_opportunity(&opportunity, 0.0));
+
+        opportunity.status = OpportunityStatus::Reverted;
+        assert!(NotificationHandler::is_high_opportunity(
+            &opportunity,
+            100.0
+        ));
+        assert!(!NotificationHandler::is_high_opportunity(
+            &opportunity,
+            200.0
+        ));
+
+        opportunity.status = OpportunityStatus::Succeeded;
+        opportunity.profit_usd = Some(50.0);
+        assert!(!NotificationHandler::is_high_opportunity(
+            &opportunity,
+            100.0
+        ));
+        assert!(NotificationHandler::is_high_opportunity(&opportunity, 50.0));
+    }
 }
@@ -0,0 +1,7 @@
+pub mod notifier;
+pub mod telegram;
+pub mod webhook;
+
+pub use notifier::*;
+pub use telegram::*;
+pub use webhook::*;
@@ -0,0 +1,149 @@
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::database::models::OpportunityStatus;
+
+/// A link attached to a notification
+#[derive(Debug, Clone, Serialize)]
+pub struct Link {
+    pub label: String,
+    pub url: String,
+}
+
+/// A notification with everything a sink needs to render it, no further lookups required
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Notification {
+    NewPool {
+        network_name: String,
+        network_id: u64,
+        pool_addresses: Vec<String>,
+    },
+    Opportunity {
+        id: Option<String>,
+        status: OpportunityStatus,
+        source_chain_id: u64,
+        source_chain_name: String,
+        target_chain_id: u64,
+        target_chain_name: String,
+        anchor_token: String,
+        anchor_token_symbol: String,
+        amount_in: f64, // human amount of the source anchor token
+        profit_usd: Option<f64>,
+        estimate_profit_usd: Option<f64>,
+        gas_usd: Option<f64>,
+        error: Option<String>,
+        links: Vec<Link>,
+    },
+}
+
+impl Notification {
+    /// Whether the notification reports a failure, sinks may route those separately
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Self::Opportunity {
+                status: OpportunityStatus::Reverted | OpportunityStatus::Error,
+                ..
+            }
+        )
+    }
+
+    /// One line summary
+    pub fn title(&self) -> String {
+        match self {
+            Self::NewPool { pool_addresses, .. } => {
+                format!("🆕 New {} Pool Detected", pool_addresses.len())
+            }
+            Self::Opportunity {
+                status,
+                profit_usd,
+                estimate_profit_usd,
+                ..
+            } => match status {
+                OpportunityStatus::Succeeded => {
+                    format!("🟢 ${:.2} SUCCESS", profit_usd.unwrap_or(0.0))
+                }
+                OpportunityStatus::PartiallySucceeded => {
+                    format!("🟡 ${:.2} PARTIAL", profit_usd.unwrap_or(0.0))
+                }
+                OpportunityStatus::Reverted => {
+                    format!("🔴 ${:.2} REVERTED", estimate_profit_usd.unwrap_or(0.0))
+                }
+                OpportunityStatus::Error => {
+                    format!("🔴 ${:.2} ERROR", estimate_profit_usd.unwrap_or(0.0))
+                }
+                OpportunityStatus::Detected | OpportunityStatus::Pending => {
+                    format!("${:.2} {}", estimate_profit_usd.unwrap_or(0.0), status)
+                }
+            },
+        }
+    }
+
+    /// Named values shown below the title, in order
+    pub fn fields(&self) -> Vec<(&'static str, String)> {
+        match self {
+            Self::NewPool {
+                network_name,
+                network_id,
+                pool_addresses,
+            } => vec![
+                ("Network", format!("{} ({})", network_name, network_id)),
+                ("Pools", pool_addresses.join("\n")),
+            ],
+            Self::Opportunity {
+                status,
+                source_chain_name,
+                target_chain_name,
+                anchor_token_symbol,
+                amount_in,
+                estimate_profit_usd,
+                gas_usd,
+                error,
+                ..
+            } => {
+                let mut fields = vec![
+                    (
+                        "Route",
+                        format!("{} → {}", source_chain_name, target_chain_name),
+                    ),
+                    (
+                        "Amount in",
+                        format!("{:.4} {}", amount_in, anchor_token_symbol),
+                    ),
+                ];
+                if matches!(
+                    status,
+                    OpportunityStatus::Succeeded | OpportunityStatus::PartiallySucceeded
+                ) {
+                    fields.push((
+                        "Estimated",
+                        format!("${:.2}", estimate_profit_usd.unwrap_or(0.0)),
+                    ));
+                }
+                if let Some(gas_usd) = gas_usd {
+                    fields.push(("Gas", format!("${:.2}", gas_usd)));
+                }
+                if let Some(error) = error {
+                    fields.push(("Error", error.clone()));
+                }
+                fields
+            }
+        }
+    }
+
+    pub fn links(&self) -> &[Link] {
+        match self {
+            Self::NewPool { .. } => &[],
+            Self::Opportunity { links, .. } => links,
+        }
+    }
+}
+
+/// A destination notifications are delivered to
+#[async_trait::async_trait]
+pub trait Notifier: Send + Sync {
+    /// Short name of the sink, used in logs
+    fn name(&self) -> &str;
+    async fn notify(&self, notification: &Notification) -> Result<()>;
+}
@@ -0,0 +1,143 @@
+use anyhow::{anyhow, Result};
+use log::error;
+use teloxide::{
+    adaptors::throttle::{Limits, Throttle},
+    prelude::*,
+    sugar::request::RequestLinkPreviewExt,
+    types::{MessageId, ParseMode, ThreadId},
+    Bot,
+};
+
+use crate::config::TelegramConfig;
+use crate::services::notifiers::{Notification, Notifier};
+
+/// Escape special characters for MarkdownV2
+fn escape_markdownv2(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '_' | '*' | '[' | ']' | '(' | ')' | '~' | '`' | '>' | '#' | '+' | '-' | '=' | '|'
+            | '{' | '}' | '.' | '!' | '\\' => {
+                format!("\\{}", c)
+            }
+            _ => c.to_string(),
+        })
+        .collect()
+}
+
+/// Render a notification as a Telegram MarkdownV2 message
+fn render_markdownv2(notification: &Notification) -> String {
+    let mut message = format!("*{}*", escape_markdownv2(&notification.title()));
+    for (name, value) in notification.fields() {
+        message += &format!(
+            "\n{}: *{}*",
+            escape_markdownv2(name),
+            escape_markdownv2(&value)
+        );
+    }
+    for link in notification.links() {
+        // Inside the URL part only `)` and `\` need escaping
+        let url = link.url.replace('\\', "\\\\").replace(')', "\\)");
+        message += &format!("\n[{}]({})", escape_markdownv2(&link.label), url);
+    }
+    message
+}
+
+/// Sends notifications to a Telegram chat through a bot
+///
+/// Failures go to `error_thread_id`, everything else to `opp_thread_id`.
+pub struct TelegramNotifier {
+    bot: Throttle<Bot>,
+    chat_id: String,
+    opp_thread_id: Option<u64>,
+    error_thread_id: Option<u64>,
+}
+
+impl TelegramNotifier {
+    /// Create a Telegram notifier, None if no bot token and chat ID are configured
+    pub fn from_config(config: &TelegramConfig) -> Option<Self> {
+        match (&config.token, &config.chat_id) {
+            (Some(token), Some(chat_id)) if !token.is_empty() && !chat_id.is_empty() => {
+                Some(Self {
+                    bot: Bot::new(token).throttle(Limits::default()),
+                    chat_id: chat_id.clone(),
+                    opp_thread_id: config.opp_thread_id,
+                    error_thread_id: config.error_thread_id,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Send an already escaped MarkdownV2 message
+    async fn send_message(&self, message: String, thread_id: Option<u64>) -> Result<()> {
+        let mut request = self
+            .bot
+            .send_message(self.chat_id.clone(), message)
+            .disable_link_preview(true)
+            .parse_mode(ParseMode::MarkdownV2);
+        if let Some(thread_id) = thread_id {
+            request = request.message_thread_id(ThreadId(MessageId(thread_id as i32)));
+        }
+
+        request.send().await?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Notifier for TelegramNotifier {
+    fn name(&self) -> &str {
+        "telegram"
+    }
+
+    async fn notify(&self, notification: &Notification) -> Result<()> {
+        let thread_id = if notification.is_failure() {
+            self.error_thread_id
+        } else {
+            self.opp_thread_id
+        };
+
+        if let Err(e) = self
+            .send_message(render_markdownv2(notification), thread_id)
+            .await
+        {
+            // Let the chat know something was lost
+            if let Err(e) = self
+                .send_message(
+                    escape_markdownv2("Error sending notification"),
+                    self.error_thread_id,
+                )
+                .await
+            {
+                error!("Failed to send failed notification: {}", e);
+            }
+            return Err(anyhow!("Failed to send Telegram message: {}", e));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_markdownv2() {
+        assert_eq!(escape_markdownv2("1.5 (ok)!"), "1\\.5 \\(ok\\)\\!");
+        assert_eq!(escape_markdownv2("*bold*"), "\\*bold\\*");
+    }
+
+    #[test]
+    fn test_render_markdownv2() {
+        let notification = Notification::NewPool {
+            network_name: "Base".to_string(),
+            network_id: 8453,
+            pool_addresses: vec!["0xabc".to_string()],
+        };
+        assert_eq!(
+            render_markdownv2(&notification),
+            "*🆕 New 1 Pool Detected*\nNetwork: *Base \\(8453\\)*\nPools: *0xabc*"
+        );
+    }
+}
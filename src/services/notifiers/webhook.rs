@@ -0,0 +1,202 @@
+use anyhow::{anyhow, Result};
+use reqwest::Client;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::config::{WebhookConfig, WebhookKind};
+use crate::services::notifiers::{Notification, Notifier};
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Escape the characters Slack reserves for its own markup
+fn escape_slack(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Render a notification as the body expected by the given kind of webhook
+fn render_payload(kind: WebhookKind, notification: &Notification) -> Result<Value> {
+    let payload = match kind {
+        WebhookKind::Json => serde_json::to_value(notification)?,
+        WebhookKind::Slack => {
+            let mut text = format!("*{}*", escape_slack(&notification.title()));
+            for (name, value) in notification.fields() {
+                text += &format!("\n{}: *{}*", name, escape_slack(&value));
+            }
+            for link in notification.links() {
+                text += &format!("\n<{}|{}>", link.url, escape_slack(&link.label));
+            }
+            json!({ "text": text })
+        }
+        WebhookKind::Discord => {
+            let mut content = format!("**{}**", notification.title());
+            for (name, value) in notification.fields() {
+                content += &format!("\n{}: **{}**", name, value);
+            }
+            for link in notification.links() {
+                // Angle brackets keep Discord from embedding a preview
+                content += &format!("\n[{}](<{}>)", link.label, link.url);
+            }
+            json!({ "content": content })
+        }
+    };
+
+    Ok(payload)
+}
+
+/// Posts notifications to an HTTP webhook (Slack, Discord or a generic JSON endpoint)
+pub struct WebhookNotifier {
+    client: Client,
+    kind: WebhookKind,
+    url: String,
+    redacted_url: String, // for errors, which are logged
+    headers: HashMap<String, String>,
+}
+
+impl WebhookNotifier {
+    pub fn from_config(config: &WebhookConfig) -> Result<Self> {
+        let url = url::Url::parse(&config.url)
+            .map_err(|e| anyhow!("Invalid {:?} webhook URL: {}", config.kind, e))?;
+
+        Ok(Self {
+            client: Client::builder().timeout(WEBHOOK_TIMEOUT).build()?,
+            kind: config.kind,
+            url: url.to_string(),
+            redacted_url: config.redacted_url(),
+            headers: config.headers.clone(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Notifier for WebhookNotifier {
+    fn name(&self) -> &str {
+        match self.kind {
+            WebhookKind::Json => "json webhook",
+            WebhookKind::Slack => "slack webhook",
+            WebhookKind::Discord => "discord webhook",
+        }
+    }
+
+    async fn notify(&self, notification: &Notification) -> Result<()> {
+        let mut request = self
+            .client
+            .post(&self.url)
+            .json(&render_payload(self.kind, notification)?);
+        for (name, value) in &self.headers {
+            request = request.header(name, value);
+        }
+
+        let response = request.send().await?;
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "{} {} answered with status {}",
+                self.name(),
+                self.redacted_url,
+                response.status()
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::test_http::{body_of, serve};
+    use tokio::sync::mpsc::UnboundedReceiver;
+
+    fn new_pool_notification() -> Notification {
+        Notification::NewPool {
+            network_name: "Base".to_string(),
+            network_id: 8453,
+            pool_addresses: vec!["0xabc".to_string(), "0xdef".to_string()],
+        }
+    }
+
+    /// Local webhook answering with `status`, returns its URL and the requests it received
+    async fn serve_webhook(status: u16) -> (String, UnboundedReceiver<String>) {
+        let (url, requests) = serve(move |_| (status, String::new())).await;
+        (format!("{}/hook", url), requests)
+    }
+
+    fn json_body(request: &str) -> Value {
+        serde_json::from_str(body_of(request)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_json_webhook() {
+        let (url, mut requests) = serve_webhook(200).await;
+        let notifier = WebhookNotifier::from_config(&WebhookConfig {
+            kind: WebhookKind::Json,
+            url,
+            headers: HashMap::from([("Authorization".to_string(), "Bearer secret".to_string())]),
+        })
+        .unwrap();
+
+        notifier.notify(&new_pool_notification()).await.unwrap();
+
+        let request = requests.recv().await.unwrap();
+        assert!(request.starts_with("POST /hook"));
+        assert!(request
+            .to_lowercase()
+            .contains("authorization: bearer secret"));
+        let body = json_body(&request);
+        assert_eq!(body["event"], "new_pool");
+        assert_eq!(body["network_id"], 8453);
+        assert_eq!(body["pool_addresses"][1], "0xdef");
+    }
+
+    #[tokio::test]
+    async fn test_slack_and_discord_webhooks() {
+        for (kind, key, expected) in [
+            (
+                WebhookKind::Slack,
+                "text",
+                "*🆕 New 2 Pool Detected*\nNetwork: *Base (8453)*\nPools: *0xabc\n0xdef*",
+            ),
+            (
+                WebhookKind::Discord,
+                "content",
+                "**🆕 New 2 Pool Detected**\nNetwork: **Base (8453)**\nPools: **0xabc\n0xdef**",
+            ),
+        ] {
+            let (url, mut requests) = serve_webhook(200).await;
+            let notifier = WebhookNotifier::from_config(&WebhookConfig {
+                kind,
+                url,
+                headers: HashMap::new(),
+            })
+            .unwrap();
+
+            notifier.notify(&new_pool_notification()).await.unwrap();
+
+            let body = json_body(&requests.recv().await.unwrap());
+            assert_eq!(body[key], expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_webhook_error_status() {
+        let (url, mut requests) = serve_webhook(500).await;
+        let notifier = WebhookNotifier::from_config(&WebhookConfig {
+            kind: WebhookKind::Json,
+            url,
+            headers: HashMap::new(),
+        })
+        .unwrap();
+
+        let error = notifier
+            .notify(&new_pool_notification())
+            .await
+            .unwrap_err()
+            .to_string();
+        // The path of a webhook URL is its secret
+        assert!(error.starts_with("json webhook http://127.0.0.1/…"));
+        assert!(!error.contains("/hook"));
+        requests.recv().await.unwrap();
+    }
+}
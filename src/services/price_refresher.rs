@@ -0,0 +1,110 @@
+use alloy::primitives::Address;
+use anyhow::Result;
+use futures::future::join_all;
+use log::{debug, error, info, warn};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::bot::models::profit_token::price_updater::{PriceSourceType, PriceUpdater};
+use crate::database::models::{address_to_string, Network, PriceSnapshot};
+use crate::database::MongoDbService;
+
+/// Fetches the prices of the profit tokens of every network in the background
+/// and stores them as snapshots in `token_prices`
+///
+/// Each refresh runs one price request per network, concurrently, so a network whose price
+/// source fails keeps its last snapshots without holding up the others.
+#[derive(Clone)]
+pub struct PriceRefresher {
+    db_service: MongoDbService,
+    interval: Duration,
+}
+
+impl PriceRefresher {
+    pub fn new(db_service: MongoDbService, refresh_interval_secs: u64) -> Self {
+        Self {
+            db_service,
+            interval: Duration::from_secs(refresh_interval_secs.max(1)),
+        }
+    }
+
+    /// Refresh the prices now and then every interval, until the process exits
+    pub fn start(&self) {
+        let refresher = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(refresher.interval);
+            loop {
+                interval.tick().await;
+                if let Err(e) = refresher.refresh_all().await {
+                    error!("Failed to refresh token prices: {}", e);
+                }
+            }
+        });
+        info!(
+            "Price refresher started with a {}s interval",
+            self.interval.as_secs()
+        );
+    }
+
+    /// Refresh the prices of the profit tokens of every network that is not deleted
+    async fn refresh_all(&self) -> Result<()> {
+        let networks = self.db_service.get_network_repo().find_all().await?;
+        let refreshes = networks
+            .iter()
+            .filter(|network| network.deleted_at.is_none())
+            .map(|network| async move {
+                if let Err(e) = self.refresh_network(network).await {
+                    warn!(
+                        "Failed to refresh token prices on network {}, keeping the last snapshots: {}",
+                        network.chain_id, e
+                    );
+                }
+            });
+        join_all(refreshes).await;
+        Ok(())
+    }
+
+    /// Fetch and store the prices of the profit tokens of a network that have a price source
+    /// Returns the number of prices stored, 0 when the network has no `price_network`
+    async fn refresh_network(&self, network: &Network) -> Result<usize> {
+        let Some(price_network) = &network.price_network else {
+            debug!(
+                "Network {} has no price_network, skipping its token prices",
+                network.chain_id
+            );
+            return Ok(0);
+        };
+        let configs = self
+            .db_service
+            .get_profit_token_repo()
+            .find_by_network_id(network.chain_id)
+            .await?;
+        let tokens: Vec<Address> = configs
+            .iter()
+            .filter(|config| config.price_source == Some(PriceSourceType::GeckoTerminal))
+            .filter_map(|config| Address::from_str(&config.address).ok())
+            .collect();
+        if tokens.is_empty() {
+            return Ok(0);
+        }
+
+        let price_updater = PriceUpdater::new(price_network.clone(), tokens).await;
+        let prices = price_updater.update_prices().await?;
+        for (address, price) in &prices {
+            let snapshot = PriceSnapshot::new(
+                network.chain_id,
+                address_to_string(address),
+                price.price,
+                PriceSourceType::GeckoTerminal,
+            );
+            self.db_service.get_price_repo().upsert(&snapshot).await?;
+        }
+
+        debug!(
+            "Stored {} token prices on network {}",
+            prices.len(),
+            network.chain_id
+        );
+        Ok(prices.len())
+    }
+}
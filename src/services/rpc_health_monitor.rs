@@ -0,0 +1,327 @@
+use alloy::network::Ethereum;
+use alloy::providers::{Provider, RootProvider};
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use futures::future::join_all;
+use log::{debug, error, info, warn};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::config::RpcHealthConfig;
+use crate::database::models::{Network, RpcHealth, RpcKind};
+use crate::database::MongoDbService;
+
+/// Answer of an endpoint to a health check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Probe {
+    pub head_block: u64,
+    pub latency_ms: u64, // connecting included, for WebSocket endpoints
+}
+
+/// Checks the RPC and WebSocket endpoints of every network in the background
+/// and stores their health in `rpc_health`
+///
+/// An endpoint is healthy when it answers `eth_chainId` with the network's chain id and then
+/// `eth_blockNumber` within the timeout. Its lag is counted from the highest head block
+/// reported by the network's endpoints in the same check.
+#[derive(Clone)]
+pub struct RpcHealthMonitor {
+    db_service: MongoDbService,
+    interval: Duration,
+    timeout: Duration,
+    window: usize,
+}
+
+impl RpcHealthMonitor {
+    pub fn new(db_service: MongoDbService, config: &RpcHealthConfig) -> Self {
+        Self {
+            db_service,
+            interval: Duration::from_secs(config.check_interval_secs.max(1)),
+            timeout: Duration::from_secs(config.timeout_secs.max(1)),
+            window: config.window.max(1),
+        }
+    }
+
+    /// Check the endpoints now and then every interval, until the process exits
+    pub fn start(&self) {
+        let monitor = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(monitor.interval);
+            loop {
+                interval.tick().await;
+                if let Err(e) = monitor.check_all().await {
+                    error!("Failed to check RPC endpoints: {}", e);
+                }
+            }
+        });
+        info!(
+            "RPC health monitor started with a {}s interval",
+            self.interval.as_secs()
+        );
+    }
+
+    /// Check the endpoints of every network that is not deleted
+    async fn check_all(&self) -> Result<()> {
+        let networks = self.db_service.get_network_repo().find_all().await?;
+        let checks = networks
+            .iter()
+            .filter(|network| network.deleted_at.is_none())
+            .map(|network| async move {
+                if let Err(e) = self.check_network(network).await {
+                    warn!(
+                        "Failed to store the RPC health of network {}: {}",
+                        network.chain_id, e
+                    );
+                }
+            });
+        join_all(checks).await;
+        Ok(())
+    }
+
+    /// Check the endpoints of a network concurrently and store their health
+    /// Returns the number of healthy endpoints
+    async fn check_network(&self, network: &Network) -> Result<usize> {
+        let endpoints = network.rpc_endpoints();
+        let probes = join_all(
+            endpoints
+                .iter()
+                .map(|(url, _)| Self::probe(url, network.chain_id, self.timeout)),
+        )
+        .await;
+
+        let repo = self.db_service.get_rpc_health_repo();
+        let previous: HashMap<String, RpcHealth> = repo
+            .find_by_network_id(network.chain_id)
+            .await?
+            .into_iter()
+            .map(|health| (health.url.clone(), health))
+            .collect();
+        let healths = Self::assess(
+            network.chain_id,
+            endpoints,
+            probes,
+            &previous,
+            self.window,
+            Utc::now().timestamp() as u64,
+        );
+
+        for health in &healths {
+            if !health.healthy && previous.get(&health.url).is_none_or(|p| p.healthy) {
+                warn!(
+                    "RPC endpoint {} of network {} is down: {}",
+                    health.url,
+                    network.chain_id,
+                    health.error.as_deref().unwrap_or_default()
+                );
+            }
+            repo.upsert(health).await?;
+        }
+        let urls: Vec<String> = healths.iter().map(|health| health.url.clone()).collect();
+        repo.delete_other_urls(network.chain_id, &urls).await?;
+
+        let healthy = healths.iter().filter(|health| health.healthy).count();
+        debug!(
+            "{} of {} RPC endpoints of network {} are healthy",
+            healthy,
+            healths.len(),
+            network.chain_id
+        );
+        Ok(healthy)
+    }
+
+    /// Call `eth_chainId` and `eth_blockNumber` on an endpoint
+    /// Fails when the endpoint is unreachable, answers with another chain id or is too slow
+    pub async fn probe(url: &str, chain_id: u64, timeout: Duration) -> Result<Probe> {
+        let started = Instant::now();
+        let check = async {
+            let provider = RootProvider::<Ethereum>::connect(url).await?;
+            let reported = provider.get_chain_id().await?;
+            if reported != chain_id {
+                return Err(anyhow!(
+                    "Endpoint reports chain id {}, expected {}",
+                    reported,
+                    chain_id
+                ));
+            }
+            Ok(provider.get_block_number().await?)
+        };
+
+        let head_block = tokio::time::timeout(timeout, check)
+            .await
+            .map_err(|_| anyhow!("No answer within {:?}", timeout))??;
+        Ok(Probe {
+            head_block,
+            latency_ms: started.elapsed().as_millis() as u64,
+        })
+    }
+
+    /// Health of the endpoints of a network from the results of a check and their previous
+    /// health, keeping the outcome of the last `window` checks for the error rate
+    fn assess(
+        network_id: u64,
+        endpoints: Vec<(String, RpcKind)>,
+        probes: Vec<Result<Probe>>,
+        previous: &HashMap<String, RpcHealth>,
+        window: usize,
+        checked_at: u64,
+    ) -> Vec<RpcHealth> {
+        let best_head = probes
+            .iter()
+            .filter_map(|probe| probe.as_ref().ok())
+            .map(|probe| probe.head_block)
+            .max();
+
+        endpoints
+            .into_iter()
+            .zip(probes)
+            .map(|((url, kind), probe)| {
+                let previous = previous.get(&url);
+                let mut recent = previous
+                    .map(|health| health.recent.clone())
+                    .unwrap_or_default();
+                recent.push(probe.is_ok());
+                recent.drain(..recent.len().saturating_sub(window));
+                let failures = recent.iter().filter(|ok| !**ok).count();
+
+                let mut health = RpcHealth {
+                    id: None,
+                    network_id,
+                    url,
+                    kind,
+                    healthy: probe.is_ok(),
+                    head_block: None,
+                    lag: None,
+                    latency_ms: None,
+                    error: None,
+                    error_rate: failures as f64 / recent.len() as f64,
+                    recent,
+                    last_ok_at: previous.and_then(|health| health.last_ok_at),
+                    checked_at,
+                };
+                match probe {
+                    Ok(probe) => {
+                        health.head_block = Some(probe.head_block);
+                        health.lag = best_head.map(|best| best - probe.head_block);
+                        health.latency_ms = Some(probe.latency_ms);
+                        health.last_ok_at = Some(checked_at);
+                    }
+                    Err(e) => health.error = Some(e.to_string()),
+                }
+                health
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::test_http::{body_of, serve};
+    use serde_json::{json, Value};
+    use tokio::net::TcpListener;
+
+    /// Local JSON-RPC stand-in answering `eth_chainId` and `eth_blockNumber` until the test ends
+    async fn serve_rpc(chain_id: u64, head_block: u64) -> String {
+        let (url, _) = serve(move |request| {
+            let call: Value = serde_json::from_str(body_of(request)).unwrap();
+            let result = match call["method"].as_str() {
+                Some("eth_chainId") => format!("{:#x}", chain_id),
+                Some("eth_blockNumber") => format!("{:#x}", head_block),
+                method => panic!("unexpected method {:?}", method),
+            };
+            let answer = json!({ "jsonrpc": "2.0", "id": call["id"], "result": result });
+            (200, answer.to_string())
+        })
+        .await;
+
+        url
+    }
+
+    fn probe(head_block: u64) -> Result<Probe> {
+        Ok(Probe {
+            head_block,
+            latency_ms: 10,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_probe() {
+        let timeout = Duration::from_secs(5);
+
+        let url = serve_rpc(8453, 1_000_120).await;
+        let answer = RpcHealthMonitor::probe(&url, 8453, timeout).await.unwrap();
+        assert_eq!(answer.head_block, 1_000_120);
+
+        let url = serve_rpc(1, 1_000_120).await;
+        let error = RpcHealthMonitor::probe(&url, 8453, timeout)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("chain id 1, expected 8453"));
+
+        // Nothing listens on the port once the listener is dropped
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+        assert!(RpcHealthMonitor::probe(&url, 8453, timeout).await.is_err());
+    }
+
+    #[test]
+    fn test_assess() {
+        let endpoints = vec![
+            ("https://a".to_string(), RpcKind::Http),
+            ("https://b".to_string(), RpcKind::Http),
+            ("wss://c".to_string(), RpcKind::Websocket),
+        ];
+
+        let first = RpcHealthMonitor::assess(
+            8453,
+            endpoints.clone(),
+            vec![probe(120), probe(100), Err(anyhow!("connection refused"))],
+            &HashMap::new(),
+            2,
+            1_000,
+        );
+        assert_eq!(
+            first.iter().map(|h| h.lag).collect::<Vec<_>>(),
+            vec![Some(0), Some(20), None]
+        );
+        assert!(first[0].healthy && first[1].healthy && !first[2].healthy);
+        assert_eq!(first[2].error.as_deref(), Some("connection refused"));
+        assert_eq!(first[2].error_rate, 1.0);
+        assert_eq!(first[2].last_ok_at, None);
+
+        let previous: HashMap<String, RpcHealth> = first
+            .into_iter()
+            .map(|health| (health.url.clone(), health))
+            .collect();
+        let second = RpcHealthMonitor::assess(
+            8453,
+            endpoints.clone(),
+            vec![Err(anyhow!("timeout")), probe(130), probe(130)],
+            &previous,
+            2,
+            2_000,
+        );
+        assert_eq!(second[0].error_rate, 0.5);
+        assert_eq!(second[0].last_ok_at, Some(1_000));
+        assert_eq!(second[1].lag, Some(0));
+        assert_eq!(second[2].error_rate, 0.5);
+
+        // Only the last `window` checks count
+        let previous: HashMap<String, RpcHealth> = second
+            .into_iter()
+            .map(|health| (health.url.clone(), health))
+            .collect();
+        let third = RpcHealthMonitor::assess(
+            8453,
+            endpoints,
+            vec![probe(140), probe(140), probe(140)],
+            &previous,
+            2,
+            3_000,
+        );
+        assert_eq!(third[0].recent, vec![false, true]);
+        assert_eq!(third[2].recent, vec![true, true]);
+        assert_eq!(third[2].error_rate, 0.0);
+    }
+}